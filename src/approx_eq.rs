@@ -0,0 +1,190 @@
+//! Comparison of geometry and profile types with an absolute tolerance for their floating point
+//! values. Writing and parsing a document again may perturb the last bits of floating point
+//! values, which makes the exact [`PartialEq`] comparison brittle.
+
+use crate::core::additional_data::AdditionalData;
+use crate::lane::border::Border;
+use crate::lane::lane_choice::LaneChoice;
+use crate::lane::offset::Offset;
+use crate::lane::width::Width;
+use crate::road::geometry::arc::Arc;
+use crate::road::geometry::geometry_type::GeometryType;
+use crate::road::geometry::line::Line;
+use crate::road::geometry::param_poly_3::ParamPoly3;
+use crate::road::geometry::param_poly_3_p_range::ParamPoly3pRange;
+use crate::road::geometry::plan_view::PlanView;
+use crate::road::geometry::poly_3::Poly3;
+use crate::road::geometry::spiral::Spiral;
+use crate::road::geometry::Geometry;
+use crate::road::profile::elevation::Elevation;
+use crate::road::profile::lateral_profile::LateralProfile;
+use crate::road::profile::shape::Shape;
+use crate::road::profile::super_elevation::SuperElevation;
+use crate::road::profile::ElevationProfile;
+use vec1::Vec1;
+
+pub trait ApproxEq {
+    /// Whether all floating point values differ by at most `tolerance` (in their SI unit) and
+    /// all other values are equal
+    fn approx_eq(&self, other: &Self, tolerance: f64) -> bool;
+}
+
+impl ApproxEq for f64 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        self == other || (self - other).abs() <= tolerance
+    }
+}
+
+impl<D, U> ApproxEq for uom::si::Quantity<D, U, f64>
+where
+    D: uom::si::Dimension + ?Sized,
+    U: uom::si::Units<f64> + ?Sized,
+{
+    #[inline]
+    fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        self.value.approx_eq(&other.value, tolerance)
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for Option<T> {
+    fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        match (self, other) {
+            (Some(a), Some(b)) => a.approx_eq(b, tolerance),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for [T] {
+    fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|(a, b)| a.approx_eq(b, tolerance))
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for Vec<T> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        self.as_slice().approx_eq(other.as_slice(), tolerance)
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for Vec1<T> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        self.as_slice().approx_eq(other.as_slice(), tolerance)
+    }
+}
+
+/// Implements [`ApproxEq`] by comparing all given fields with [`ApproxEq::approx_eq`]
+macro_rules! impl_approx_eq {
+    ($ty:ty { $($field:ident),+ $(,)? }) => {
+        impl ApproxEq for $ty {
+            fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+                $(self.$field.approx_eq(&other.$field, tolerance))&&+
+            }
+        }
+    };
+}
+
+/// Implements [`ApproxEq`] by [`PartialEq::eq`] for types without floating point values
+macro_rules! impl_approx_eq_exact {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl ApproxEq for $ty {
+                #[inline]
+                fn approx_eq(&self, other: &Self, _tolerance: f64) -> bool {
+                    self == other
+                }
+            }
+        )*
+    };
+}
+
+impl_approx_eq_exact!(AdditionalData, Line, ParamPoly3pRange);
+
+impl_approx_eq!(PlanView {
+    geometry,
+    additional_data
+});
+impl_approx_eq!(Geometry {
+    hdg,
+    length,
+    s,
+    x,
+    y,
+    r#type,
+    additional_data
+});
+impl_approx_eq!(Arc { curvature });
+impl_approx_eq!(Spiral {
+    curvature_start,
+    curvature_end
+});
+impl_approx_eq!(Poly3 { a, b, c, d });
+impl_approx_eq!(ParamPoly3 {
+    a_u,
+    a_v,
+    b_u,
+    b_v,
+    c_u,
+    c_v,
+    d_u,
+    d_v,
+    p_range
+});
+impl_approx_eq!(ElevationProfile {
+    elevation,
+    additional_data
+});
+impl_approx_eq!(Elevation { a, b, c, d, s });
+impl_approx_eq!(LateralProfile {
+    super_elevation,
+    shape,
+    additional_data
+});
+impl_approx_eq!(SuperElevation { a, b, c, d, s });
+impl_approx_eq!(Shape { a, b, c, d, s, t });
+impl_approx_eq!(Offset { a, b, c, d, s });
+impl_approx_eq!(Width {
+    a,
+    b,
+    c,
+    d,
+    s_offset
+});
+impl_approx_eq!(Border {
+    a,
+    b,
+    c,
+    d,
+    s_offset
+});
+
+impl ApproxEq for GeometryType {
+    fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        match (self, other) {
+            (Self::Line(a), Self::Line(b)) => a.approx_eq(b, tolerance),
+            (Self::Spiral(a), Self::Spiral(b)) => a.approx_eq(b, tolerance),
+            (Self::Arc(a), Self::Arc(b)) => a.approx_eq(b, tolerance),
+            (Self::Poly3(a), Self::Poly3(b)) => a.approx_eq(b, tolerance),
+            (Self::ParamPoly3(a), Self::ParamPoly3(b)) => a.approx_eq(b, tolerance),
+            _ => false,
+        }
+    }
+}
+
+impl ApproxEq for LaneChoice {
+    fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        match (self, other) {
+            (Self::Border(a), Self::Border(b)) => a.approx_eq(b, tolerance),
+            (Self::Width(a), Self::Width(b)) => a.approx_eq(b, tolerance),
+            _ => false,
+        }
+    }
+}
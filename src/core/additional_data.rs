@@ -1,3 +1,4 @@
+use crate::core::additional_data_choice::AdditionalDataChoice;
 use crate::core::data_quality::DataQuality;
 use crate::core::include::Include;
 use crate::core::user_data::UserData;
@@ -6,14 +7,31 @@ use crate::core::user_data::UserData;
 /// depends on the application.
 /// Additional data may be placed at any position in ASAM OpenDRIVE.
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct AdditionalData {
     pub data_quality: Option<DataQuality>,
-    pub include: Vec<Include>,
-    pub user_data: Vec<UserData>,
+    /// The `<include>` and `<userData>` elements in the order of their appearance
+    pub choice: Vec<AdditionalDataChoice>,
 }
 
 impl AdditionalData {
+    /// The `<include>` elements in the order of their appearance
+    pub fn include(&self) -> impl Iterator<Item = &Include> {
+        self.choice.iter().filter_map(|choice| match choice {
+            AdditionalDataChoice::Include(include) => Some(include),
+            AdditionalDataChoice::UserData(_) => None,
+        })
+    }
+
+    /// The `<userData>` elements in the order of their appearance
+    pub fn user_data(&self) -> impl Iterator<Item = &UserData> {
+        self.choice.iter().filter_map(|choice| match choice {
+            AdditionalDataChoice::Include(_) => None,
+            AdditionalDataChoice::UserData(user_data) => Some(user_data),
+        })
+    }
+
     pub fn append_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
@@ -22,12 +40,15 @@ impl AdditionalData {
             visit_children!(visitor, "dataQuality" => data_quality);
         }
 
-        for include in &self.include {
-            visit_children!(visitor, "include" => include);
-        }
-
-        for user_data in &self.user_data {
-            visit_children!(visitor, "userData" => user_data);
+        for choice in &self.choice {
+            match choice {
+                AdditionalDataChoice::Include(include) => {
+                    visit_children!(visitor, "include" => include)
+                }
+                AdditionalDataChoice::UserData(user_data) => {
+                    visit_children!(visitor, "userData" => user_data)
+                }
+            }
         }
 
         Ok(())
@@ -41,12 +62,12 @@ impl AdditionalData {
             name if name.eq_ignore_ascii_case("dataQuality") => {
                 self.data_quality = Some(DataQuality::try_from(read)?)
             }
-            name if name.eq_ignore_ascii_case("include") => {
-                self.include.push(Include::try_from(read)?)
-            }
-            name if name.eq_ignore_ascii_case("userData") => {
-                self.user_data.push(UserData::try_from(read)?)
-            }
+            name if name.eq_ignore_ascii_case("include") => self
+                .choice
+                .push(AdditionalDataChoice::Include(Include::try_from(read)?)),
+            name if name.eq_ignore_ascii_case("userData") => self
+                .choice
+                .push(AdditionalDataChoice::UserData(UserData::try_from(read)?)),
             name => {
                 return Err(Box::new(crate::parser::Error::InvalidValueFor {
                     name: core::any::type_name::<Self>().to_string(),
@@ -0,0 +1,10 @@
+use crate::core::include::Include;
+use crate::core::user_data::UserData;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
+pub enum AdditionalDataChoice {
+    Include(Include),
+    UserData(UserData),
+}
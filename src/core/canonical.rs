@@ -0,0 +1,62 @@
+use crate::core::additional_data::AdditionalData;
+use crate::core::OpenDrive;
+use crate::road::Road;
+
+/// Rewrites `drive` into its canonical form, see [`OpenDrive::to_canonical`]
+pub(crate) fn canonicalize(drive: &mut OpenDrive) {
+    for road in &mut drive.road {
+        replace_deprecated_object_types(road);
+        drop_empty_containers(road);
+        sort_by_s(road);
+    }
+}
+
+fn replace_deprecated_object_types(road: &mut Road) {
+    let objects = road.objects.iter_mut().flat_map(|o| o.object.iter_mut());
+    for r#type in objects.filter_map(|object| object.r#type.as_mut()) {
+        *r#type = r#type.modernized();
+    }
+}
+
+fn drop_empty_containers(road: &mut Road) {
+    fn no_data(additional_data: &AdditionalData) -> bool {
+        *additional_data == AdditionalData::default()
+    }
+
+    road.objects.take_if(|o| {
+        o.object.is_empty()
+            && o.object_reference.is_empty()
+            && o.tunnel.is_empty()
+            && o.bridge.is_empty()
+            && no_data(&o.additional_data)
+    });
+    road.signals.take_if(|s| {
+        s.signal.is_empty() && s.signal_reference.is_empty() && no_data(&s.additional_data)
+    });
+    road.surface
+        .take_if(|s| s.crg.is_empty() && no_data(&s.additional_data));
+    road.railroad
+        .take_if(|r| r.switch.is_empty() && no_data(&r.additional_data));
+    road.elevation_profile
+        .take_if(|e| e.elevation.is_empty() && no_data(&e.additional_data));
+    road.lateral_profile.take_if(|l| {
+        l.super_elevation.is_empty() && l.shape.is_empty() && no_data(&l.additional_data)
+    });
+}
+
+fn sort_by_s(road: &mut Road) {
+    road.plan_view
+        .geometry
+        .sort_by(|a, b| a.s.value.total_cmp(&b.s.value));
+    if let Some(profile) = &mut road.elevation_profile {
+        profile.elevation.sort_by(|a, b| a.s.total_cmp(&b.s));
+    }
+    if let Some(profile) = &mut road.lateral_profile {
+        profile.super_elevation.sort_by(|a, b| a.s.total_cmp(&b.s));
+        profile
+            .shape
+            .sort_by(|a, b| a.s.total_cmp(&b.s).then(a.t.total_cmp(&b.t)));
+    }
+    road.lanes.lane_offset.sort_by(|a, b| a.s.total_cmp(&b.s));
+    road.lanes.lane_section.sort_by(|a, b| a.s.total_cmp(&b.s));
+}
@@ -9,6 +9,7 @@ use std::borrow::Cow;
 /// Measurement data derived from external sources like GPS that is integrated in ASAM OpenDRIVE may
 /// be inaccurate. The error range, given in `m`, may be listed in the application.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct DataQuality {
     pub error: Option<Error>,
@@ -0,0 +1,142 @@
+use crate::core::OpenDrive;
+use crate::junction::Junction;
+use crate::road::Road;
+use std::collections::HashMap;
+use uom::si::length::meter;
+
+/// A difference between two [`OpenDrive`] documents, see [`OpenDrive::diff`]
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display)]
+pub enum DiffEntry {
+    /// The element with the given id only exists in the other document
+    #[display(fmt = "{} {} added", element, id)]
+    Added { element: &'static str, id: String },
+    /// The element with the given id only exists in this document
+    #[display(fmt = "{} {} removed", element, id)]
+    Removed { element: &'static str, id: String },
+    /// The summarized `field` of the element with the given id differs
+    #[display(
+        fmt = "{} {}: {} changed from {} to {}",
+        element,
+        id,
+        field,
+        before,
+        after
+    )]
+    Changed {
+        element: &'static str,
+        id: String,
+        field: &'static str,
+        before: String,
+        after: String,
+    },
+    /// The element with the given id differs, but none of the summarized fields does
+    #[display(fmt = "{} {} modified", element, id)]
+    Modified { element: &'static str, id: String },
+}
+
+impl DiffEntry {
+    pub(crate) fn between(before: &OpenDrive, after: &OpenDrive) -> Vec<Self> {
+        let mut entries = Vec::new();
+        diff_by_id(
+            "road",
+            &before.road,
+            &after.road,
+            |road| &road.id,
+            road_fields,
+            &mut entries,
+        );
+        diff_by_id(
+            "junction",
+            &before.junction,
+            &after.junction,
+            |junction| &junction.id,
+            junction_fields,
+            &mut entries,
+        );
+        entries
+    }
+}
+
+fn road_fields(road: &Road) -> [(&'static str, String); 6] {
+    [
+        ("name", road.name.clone().unwrap_or_default()),
+        ("junction", road.junction.to_string()),
+        ("length", road.length.get::<meter>().to_string()),
+        (
+            "rule",
+            road.rule
+                .as_ref()
+                .map(|r| r.as_str().to_string())
+                .unwrap_or_default(),
+        ),
+        ("geometries", road.plan_view.geometry.len().to_string()),
+        ("laneSections", road.lanes.lane_section.len().to_string()),
+    ]
+}
+
+fn junction_fields(junction: &Junction) -> [(&'static str, String); 3] {
+    [
+        ("name", junction.name.clone().unwrap_or_default()),
+        ("connections", junction.connection.len().to_string()),
+        ("priorities", junction.priority.len().to_string()),
+    ]
+}
+
+fn diff_by_id<T: PartialEq, const N: usize>(
+    element: &'static str,
+    before: &[T],
+    after: &[T],
+    id: impl Fn(&T) -> &String,
+    fields: impl Fn(&T) -> [(&'static str, String); N],
+    entries: &mut Vec<DiffEntry>,
+) {
+    let after_by_id = after
+        .iter()
+        .rev()
+        .map(|value| (id(value), value))
+        .collect::<HashMap<_, _>>();
+    let before_by_id = before
+        .iter()
+        .rev()
+        .map(|value| (id(value), value))
+        .collect::<HashMap<_, _>>();
+
+    for value in before {
+        match after_by_id.get(id(value)) {
+            None => entries.push(DiffEntry::Removed {
+                element,
+                id: id(value).clone(),
+            }),
+            Some(other) if value != *other => {
+                let len = entries.len();
+                for ((field, before), (_, after)) in fields(value).into_iter().zip(fields(other)) {
+                    if before != after {
+                        entries.push(DiffEntry::Changed {
+                            element,
+                            id: id(value).clone(),
+                            field,
+                            before,
+                            after,
+                        });
+                    }
+                }
+                if entries.len() == len {
+                    entries.push(DiffEntry::Modified {
+                        element,
+                        id: id(value).clone(),
+                    });
+                }
+            }
+            Some(_) => {}
+        }
+    }
+
+    for value in after {
+        if !before_by_id.contains_key(id(value)) {
+            entries.push(DiffEntry::Added {
+                element,
+                id: id(value).clone(),
+            });
+        }
+    }
+}
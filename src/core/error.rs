@@ -5,6 +5,7 @@ use uom::si::length::meter;
 /// The absolute or relative errors of road data are described by `<error>` elements within the
 /// `<dataQuality>` element.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Error {
     /// Absolute error of the road data in x/y direct
     pub xy_absolute: Length,
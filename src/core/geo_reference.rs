@@ -11,6 +11,7 @@ use std::borrow::Cow;
 /// In ASAM OpenDRIVE, the information about the geographic reference of an ASAM OpenDRIVE dataset
 /// is represented by the `<geoReference>` element within the `<header>` element.
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct GeoReference {
     /// https://proj.org/usage/projections.html
@@ -1,7 +1,8 @@
 use crate::core::additional_data::AdditionalData;
 use crate::core::geo_reference::GeoReference;
 use crate::core::offset::Offset;
-use chrono::{DateTime, NaiveDateTime, Utc};
+use crate::core::version::Version;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 use std::borrow::Cow;
 use std::str::FromStr;
 use uom::si::f64::Length;
@@ -9,6 +10,7 @@ use uom::si::length::meter;
 
 /// The `<header>` element is the very first element within the `<OpenDRIVE>` element.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Header {
     pub rev_major: u16,
     pub rev_minor: u16,
@@ -26,6 +28,13 @@ pub struct Header {
 }
 
 impl Header {
+    /// The declared revision of the ASAM OpenDRIVE format (`revMajor`.`revMinor`). Not to be
+    /// confused with [`Header::version`], which is the version of the document itself.
+    #[inline]
+    pub fn revision(&self) -> Version {
+        Version::new(self.rev_major, self.rev_minor)
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
@@ -179,7 +188,40 @@ impl Header {
         }
     }
 
+    /// [`Header::date`] as timestamp, if it is given in the format of the ASAM examples or in
+    /// RFC 3339
+    #[deprecated(note = "use `Header::parsed_date`, which attempts more formats")]
     pub fn date_parsed(&self) -> Option<DateTime<Utc>> {
         self.date.as_deref().and_then(Self::parse_date)
     }
+
+    /// [`Header::date`] as timestamp, which remains the source of truth. The date is free text,
+    /// so the format used in the ASAM examples (`Tue Feb 25 13:02:27 2020`), ISO 8601 with or
+    /// without a time zone, RFC 2822 and `25.02.2020 13:02:27` are attempted. Dates with a time
+    /// zone are converted to UTC, those without one are assumed to be in UTC.
+    pub fn parsed_date(&self) -> Option<NaiveDateTime> {
+        const FORMATS: [&str; 4] = [
+            // this is the format used in all the ASAM examples ...
+            "%a %h %e %H:%M:%S %Y",
+            "%Y-%m-%dT%H:%M:%S%.f",
+            "%Y-%m-%d %H:%M:%S%.f",
+            "%d.%m.%Y %H:%M:%S",
+        ];
+
+        let date = self.date.as_deref()?.trim();
+        FORMATS
+            .iter()
+            .find_map(|format| NaiveDateTime::parse_from_str(date, format).ok())
+            .or_else(|| {
+                NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                    .ok()
+                    .and_then(|date| date.and_hms_opt(0, 0, 0))
+            })
+            .or_else(|| {
+                DateTime::<Utc>::from_str(date)
+                    .ok()
+                    .or_else(|| DateTime::parse_from_rfc2822(date).ok().map(Into::into))
+                    .map(|date| date.naive_utc())
+            })
+    }
 }
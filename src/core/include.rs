@@ -5,6 +5,7 @@ use std::borrow::Cow;
 /// Included data is represented by `<include>` elements. They may be stored at any position in ASAM
 /// OpenDRIVE.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Include {
     /// Location of the file that is to be included
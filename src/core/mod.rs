@@ -1,26 +1,54 @@
 use crate::core::additional_data::AdditionalData;
+use crate::core::diff::DiffEntry;
+use crate::core::geo_reference::GeoReference;
 use crate::core::header::Header;
+use crate::core::statistics::Statistics;
+use crate::core::version::Version;
 use crate::junction::junction_group::JunctionGroup;
 use crate::junction::Junction;
+use crate::lane::lane_ref::LaneRef;
+use crate::lane::lane_side::LaneSide;
+use crate::lane::lane_type::LaneType;
+use crate::parser::{ParseOptions, Warning};
 use crate::railroad::station::Station;
+use crate::road::patch::Patch;
 use crate::road::Road;
 use crate::signal::controller::Controller;
+use crate::signal::dependency::Dependency;
+use crate::writer::WriteOptions;
 use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 use xml::{EventReader, EventWriter};
 
 pub mod additional_data;
+pub mod additional_data_choice;
+pub mod canonical;
 pub mod data_quality;
+pub mod diff;
 pub mod error;
 pub mod geo_reference;
 pub mod header;
 pub mod include;
+pub mod network_index;
 pub mod offset;
+#[cfg(feature = "parallel")]
+mod parallel;
 pub mod post_processing;
 pub mod raw_data;
+pub mod renumber;
+#[cfg(feature = "proj")]
+pub mod reproject;
+pub mod s_range;
 pub mod source;
+#[cfg(feature = "rtree")]
+pub mod spatial_index;
+pub mod statistics;
 pub mod user_data;
+pub mod validation;
+pub mod version;
 
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct OpenDrive {
     pub header: Header,
@@ -29,25 +57,141 @@ pub struct OpenDrive {
     pub junction: Vec<Junction>,
     pub junction_group: Vec<JunctionGroup>,
     pub station: Vec<Station>,
+    /// The namespace of the `<OpenDRIVE>` root element, if it is in one, either as default
+    /// namespace (`xmlns="..."`) or with a prefix (`<odr:OpenDRIVE xmlns:odr="...">`). It is
+    /// written back as the default namespace of the document.
+    pub namespace: Option<String>,
     pub additional_data: AdditionalData,
 }
 
 impl OpenDrive {
     #[inline]
     pub fn from_xml_str(s: &str) -> crate::parser::Result<Self> {
-        Self::from_reader(EventReader::from_str(s.trim()))
+        Self::from_xml_str_with_options(s, ParseOptions::default()).map(|(drive, _)| drive)
+    }
+
+    #[inline]
+    pub fn from_xml_str_with_options(
+        s: &str,
+        options: ParseOptions,
+    ) -> crate::parser::Result<(Self, Vec<Warning>)> {
+        Self::from_reader_with_options(EventReader::from_str(s.trim()), options)
     }
 
     #[inline]
     pub fn from_xml_read<T: std::io::Read>(r: T) -> crate::parser::Result<Self> {
-        Self::from_reader(EventReader::new(r))
+        Self::from_xml_read_with_options(r, ParseOptions::default()).map(|(drive, _)| drive)
+    }
+
+    #[inline]
+    pub fn from_xml_read_with_options<T: std::io::Read>(
+        r: T,
+        options: ParseOptions,
+    ) -> crate::parser::Result<(Self, Vec<Warning>)> {
+        Self::from_reader_with_options(EventReader::new(r), options)
+    }
+
+    /// Reads the whole document from the async reader and parses it afterwards. The document is
+    /// currently buffered fully in memory before parsing starts.
+    #[cfg(feature = "tokio")]
+    #[inline]
+    pub async fn from_async_read<T: tokio::io::AsyncRead + Unpin>(
+        r: T,
+    ) -> crate::parser::Result<Self> {
+        Self::from_async_read_with_options(r, ParseOptions::default())
+            .await
+            .map(|(drive, _)| drive)
+    }
+
+    /// See [`OpenDrive::from_async_read`].
+    #[cfg(feature = "tokio")]
+    pub async fn from_async_read_with_options<T: tokio::io::AsyncRead + Unpin>(
+        mut r: T,
+        options: ParseOptions,
+    ) -> crate::parser::Result<(Self, Vec<Warning>)> {
+        use tokio::io::AsyncReadExt;
+        let mut buffer = Vec::new();
+        r.read_to_end(&mut buffer)
+            .await
+            .map_err(xml::reader::Error::from)
+            .map_err(crate::parser::Error::from)
+            .map_err(Box::new)?;
+        Self::from_xml_read_with_options(buffer.as_slice(), options)
     }
 
+    #[inline]
     pub fn from_reader<T: std::io::Read>(reader: EventReader<T>) -> crate::parser::Result<Self> {
-        let mut events = reader.into_iter();
+        Self::from_reader_with_options(reader, ParseOptions::default()).map(|(drive, _)| drive)
+    }
+
+    /// Parses the document and checks it for the issues enabled in the [`ParseOptions`]. Issues
+    /// are returned as [`Warning`]s or, in [`ParseOptions::strict`] mode, the first issue is
+    /// returned as [`crate::parser::Error::Rejected`].
+    pub fn from_reader_with_options<T: std::io::Read>(
+        reader: EventReader<T>,
+        options: ParseOptions,
+    ) -> crate::parser::Result<(Self, Vec<Warning>)> {
+        Self::read_document(reader, options)?.checked(options)
+    }
+
+    /// Parses the `<road>` elements of the document in parallel, which considerably speeds up
+    /// loading large maps. The document is scanned once to split it into the individual roads
+    /// before the roads and the remaining document are parsed.
+    #[cfg(feature = "parallel")]
+    #[inline]
+    pub fn from_xml_str_parallel(s: &str) -> crate::parser::Result<Self> {
+        Self::from_xml_str_parallel_with_options(s, ParseOptions::default()).map(|(drive, _)| drive)
+    }
+
+    /// See [`OpenDrive::from_xml_str_parallel`] and [`OpenDrive::from_reader_with_options`]
+    #[cfg(feature = "parallel")]
+    pub fn from_xml_str_parallel_with_options(
+        s: &str,
+        options: ParseOptions,
+    ) -> crate::parser::Result<(Self, Vec<Warning>)> {
+        use rayon::prelude::*;
+
+        let s = s.trim();
+        let (root, ranges) = parallel::road_ranges(s)?;
+
+        // the roads are blanked out of the remainder to keep the positions in errors intact
+        let mut remainder = String::with_capacity(s.len());
+        let mut end = 0;
+        for (range, _) in &ranges {
+            remainder.push_str(&s[end..range.start]);
+            remainder.push_str(&parallel::blank(&s[range.clone()]));
+            end = range.end;
+        }
+        remainder.push_str(&s[end..]);
+
+        let (drive, roads) = rayon::join(
+            || Self::read_document(EventReader::from_str(&remainder), options),
+            || {
+                ranges
+                    .par_iter()
+                    .map(|(range, start)| {
+                        parallel::parse_road(&root, &s[range.clone()], *start, options)
+                    })
+                    .collect::<crate::parser::Result<Vec<_>>>()
+            },
+        );
+
+        let mut drive = drive?;
+        drive.road = roads?;
+        drive.checked(options)
+    }
+
+    fn read_document<T: std::io::Read>(
+        reader: EventReader<T>,
+        options: ParseOptions,
+    ) -> crate::parser::Result<Self> {
+        let position = std::cell::Cell::new(xml::common::TextPosition::new());
+        let mut events = crate::parser::PositionedEvents::new(reader, &position);
         let mut drive = None;
 
-        let mut read = crate::parser::ReadContext::from(&mut events);
+        let mut read = crate::parser::ReadContext::from(&mut events)
+            .with_options(options)
+            .with_position_source(Some(&position));
 
         match_child_eq_ignore_ascii_case!(
             read,
@@ -57,36 +201,592 @@ impl OpenDrive {
         Ok(drive.unwrap())
     }
 
+    fn checked(self, options: ParseOptions) -> crate::parser::Result<(Self, Vec<Warning>)> {
+        let mut warnings = self.warnings(&options);
+
+        #[cfg(feature = "tracing")]
+        for warning in &warnings {
+            tracing::warn!(%warning, "recoverable parse issue");
+        }
+
+        if options.strict && !warnings.is_empty() {
+            Err(Box::new(crate::parser::Error::Rejected(warnings.remove(0))))
+        } else {
+            Ok((self, warnings))
+        }
+    }
+
+    /// Runs every available check and reports all issues found: the coverage and continuity of
+    /// the plan views, the order and sides of lane sections, negative lane widths, objects that
+    /// are both angular and circular, road links to missing roads or junctions, the attributes of
+    /// (virtual) junctions and all [`Warning`]s, see [`OpenDrive::warnings`]. Issues are listed
+    /// per road in document order, followed by junctions and warnings.
+    pub fn validate_all(&self) -> Vec<validation::ValidationError> {
+        validation::validate_all(self)
+    }
+
+    /// Checks the document for the issues enabled in the given [`ParseOptions`]
+    pub fn warnings(&self, options: &ParseOptions) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        if options.check_duplicate_ids {
+            warnings.extend(self.duplicate_ids());
+        }
+
+        if options.check_version {
+            warnings.extend(self.version_mismatches());
+        }
+
+        if options.check_center_lane {
+            warnings.extend(self.invalid_center_lanes());
+        }
+
+        if options.check_road_length {
+            warnings.extend(self.invalid_road_lengths());
+        }
+
+        if options.check_junction_backrefs {
+            warnings.extend(self.validate_junction_backrefs());
+        }
+
+        if options.check_deprecated {
+            warnings.extend(self.deprecated_object_types());
+        }
+
+        warnings
+    }
+
+    /// Reports road, junction and object ids that are used more than once. Each duplicate is
+    /// reported once per additional occurrence.
+    pub fn duplicate_ids(&self) -> Vec<Warning> {
+        fn duplicates<'a>(
+            element: &'static str,
+            ids: impl Iterator<Item = &'a str>,
+            warnings: &mut Vec<Warning>,
+        ) {
+            let mut seen = HashSet::new();
+            for id in ids {
+                if !seen.insert(id) {
+                    warnings.push(Warning::DuplicateId {
+                        element,
+                        id: id.to_string(),
+                    });
+                }
+            }
+        }
+
+        let mut warnings = Vec::new();
+        let objects = self
+            .road
+            .iter()
+            .filter_map(|r| r.objects.as_ref())
+            .flat_map(|o| o.object.iter().map(|o| o.id.as_str()));
+
+        duplicates(
+            "road",
+            self.road.iter().map(|r| r.id.as_str()),
+            &mut warnings,
+        );
+        duplicates(
+            "junction",
+            self.junction.iter().map(|j| j.id.as_str()),
+            &mut warnings,
+        );
+        duplicates("object", objects, &mut warnings);
+        warnings
+    }
+
+    /// Reports features which were introduced in a later revision of ASAM OpenDRIVE than the one
+    /// declared by [`Header::revision`]. Each feature is reported once. Only a few features that
+    /// are distinct to newer revisions are detected, so this check does not prove conformance.
+    pub fn version_mismatches(&self) -> Vec<Warning> {
+        let declared = self.header.revision();
+        let road_marks = || {
+            self.road
+                .iter()
+                .flat_map(|r| r.lanes.lane_section.iter())
+                .flat_map(|s| s.lanes())
+                .flat_map(|(_, lane)| lane.road_mark.iter())
+        };
+        let features: [(&'static str, Version, bool); 2] = [
+            (
+                "the road attribute `rule`",
+                Version::V1_5,
+                self.road.iter().any(|r| r.rule.is_some()),
+            ),
+            (
+                "the element `<sway>`",
+                Version::V1_6,
+                road_marks().any(|m| !m.sway.is_empty()),
+            ),
+        ];
+
+        features
+            .into_iter()
+            .filter(|(_, since, used)| *used && declared < *since)
+            .map(|(feature, since, _)| Warning::VersionMismatch {
+                feature,
+                since,
+                declared,
+            })
+            .collect()
+    }
+
+    /// Reports center lanes whose id is not 0
+    pub fn invalid_center_lanes(&self) -> Vec<Warning> {
+        self.lanes()
+            .filter(|lane| lane.side == LaneSide::Center && lane.lane_id != 0)
+            .map(|lane| Warning::InvalidCenterLaneId {
+                road: lane.road_id.to_string(),
+                section_index: lane.section_index,
+                id: lane.lane_id,
+            })
+            .collect()
+    }
+
+    /// Reports connecting roads whose `junction` attribute does not name the junction that uses
+    /// them as `connectingRoad`, such as `-1`. Each road is reported once per junction. References
+    /// to roads that do not exist are not reported.
+    pub fn validate_junction_backrefs(&self) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        for junction in &self.junction {
+            let mut seen = HashSet::new();
+            let roads = junction
+                .connection
+                .iter()
+                .filter_map(|connection| connection.connecting_road.as_deref());
+            for id in roads {
+                let Some(road) = self.road_by_id(id) else {
+                    continue;
+                };
+                if road.junction != junction.id.as_str() && seen.insert(id) {
+                    warnings.push(Warning::JunctionBackReference {
+                        road: road.id.clone(),
+                        junction: junction.id.clone(),
+                        declared: road.junction.to_string(),
+                    });
+                }
+            }
+        }
+        warnings
+    }
+
+    /// Reports objects whose type is deprecated, see [`ObjectType::is_deprecated`]
+    ///
+    /// [`ObjectType::is_deprecated`]: crate::object::orientation::ObjectType::is_deprecated
+    pub fn deprecated_object_types(&self) -> Vec<Warning> {
+        self.road
+            .iter()
+            .flat_map(|road| {
+                road.objects
+                    .iter()
+                    .flat_map(|objects| &objects.object)
+                    .map(move |object| (road, object))
+            })
+            .filter_map(|(road, object)| {
+                let r#type = object.r#type.as_ref().filter(|t| t.is_deprecated())?;
+                Some(Warning::DeprecatedObjectType {
+                    road: road.id.clone(),
+                    object: object.id.clone(),
+                    r#type: r#type.as_str(),
+                })
+            })
+            .collect()
+    }
+
+    /// Reports roads whose length is not positive, see [`Road::validate_length`]
+    pub fn invalid_road_lengths(&self) -> Vec<Warning> {
+        self.road
+            .iter()
+            .filter(|road| road.validate_length().is_err())
+            .map(|road| Warning::NonPositiveRoadLength {
+                road: road.id.clone(),
+            })
+            .collect()
+    }
+
+    /// The first road with the given id. Ids are compared as plain strings.
+    pub fn road_by_id(&self, id: &str) -> Option<&Road> {
+        self.road.iter().find(|road| road.id == id)
+    }
+
+    /// The first road with the given id, for modification, see [`OpenDrive::road_by_id`]
+    pub fn road_by_id_mut(&mut self, id: &str) -> Option<&mut Road> {
+        self.road.iter_mut().find(|road| road.id == id)
+    }
+
+    /// The first junction with the given id. Ids are compared as plain strings.
+    pub fn junction_by_id(&self, id: &str) -> Option<&Junction> {
+        self.junction.iter().find(|junction| junction.id == id)
+    }
+
+    /// Lazily iterates over all lanes of all lane sections of all roads
+    pub fn all_lanes(&self) -> impl Iterator<Item = LaneRef<'_>> {
+        self.road.iter().flat_map(|road| {
+            road.lanes
+                .lane_section
+                .iter()
+                .enumerate()
+                .flat_map(move |(section_index, section)| {
+                    section
+                        .lanes_by_side()
+                        .map(move |(side, lane_id, lane)| LaneRef {
+                            road_id: &road.id,
+                            section_index,
+                            side,
+                            lane_id,
+                            lane,
+                        })
+                })
+        })
+    }
+
+    /// Iterates over all lanes of the network, same as [`OpenDrive::all_lanes`]
+    #[inline]
+    pub fn lanes(&self) -> impl Iterator<Item = LaneRef<'_>> {
+        self.all_lanes()
+    }
+
+    /// Indexes the roads, junctions and signals by their id for constant time lookups. Fails with
+    /// a [`NetworkIndexError::DuplicateId`] for each id used by more than one element of a kind.
+    ///
+    /// [`NetworkIndexError::DuplicateId`]: network_index::NetworkIndexError::DuplicateId
+    pub fn build_index(
+        &self,
+    ) -> Result<network_index::NetworkIndex<'_>, Vec<network_index::NetworkIndexError>> {
+        network_index::NetworkIndex::new(self)
+    }
+
+    /// Maps the ids of all signals, that depend on other signals, to their [`Dependency`]s
+    pub fn signal_dependencies(&self) -> HashMap<&str, Vec<&Dependency>> {
+        self.road
+            .iter()
+            .filter_map(|road| road.signals.as_ref())
+            .flat_map(|signals| &signals.signal)
+            .filter(|signal| !signal.dependency.is_empty())
+            .map(|signal| (signal.id.as_str(), signal.dependency.iter().collect()))
+            .collect()
+    }
+
+    /// Builds an R-tree over the reference lines of all roads for nearest road queries
+    #[cfg(feature = "rtree")]
+    pub fn build_spatial_index(&self) -> spatial_index::SpatialIndex<'_> {
+        spatial_index::SpatialIndex::new(&self.road)
+    }
+
+    /// Finds the road closest to the inertial point `(x, y)` and returns its id together with the
+    /// road coordinates `(s, t)` of the point. This builds a [`spatial_index::SpatialIndex`] for
+    /// every call, build it once and use [`spatial_index::SpatialIndex::localize`] for repeated
+    /// queries instead.
+    #[cfg(feature = "rtree")]
+    pub fn localize(
+        &self,
+        x: uom::si::f64::Length,
+        y: uom::si::f64::Length,
+    ) -> Option<(String, uom::si::f64::Length, uom::si::f64::Length)> {
+        self.build_spatial_index()
+            .localize(x, y)
+            .map(|(road, s, t)| (road.id.clone(), s, t))
+    }
+
+    /// Sets the PROJ string of the `<geoReference>` in the header, without changing any
+    /// coordinate. See [`OpenDrive::reproject_with`] to also transform the coordinates.
+    pub fn set_geo_reference(&mut self, proj: &str) {
+        self.header
+            .geo_reference
+            .get_or_insert_with(GeoReference::default)
+            .proj = Some(proj.to_string());
+    }
+
+    /// Transforms the start point of every geometry element with `transform` and sets the
+    /// `<geoReference>` to `to`. Headings are recomputed from the transformed direction of a step
+    /// of one meter along the original heading, while lengths and curvatures are kept. The result
+    /// is therefore exact for translations and rotations only, reprojecting curved geometry is an
+    /// approximation that degrades with the distortion of the projection, e.g. near datum edges.
+    /// The `<offset>` of the header is not applied.
+    pub fn reproject_with<E>(
+        &mut self,
+        to: &str,
+        mut transform: impl FnMut(f64, f64) -> Result<(f64, f64), E>,
+    ) -> Result<(), E> {
+        use uom::si::angle::radian;
+        use uom::si::f64::{Angle, Length};
+        use uom::si::length::meter;
+
+        for road in &mut self.road {
+            for geometry in road.plan_view.geometry.iter_mut() {
+                let (x, y) = (geometry.x_m(), geometry.y_m());
+                let (sin, cos) = geometry.hdg_rad().sin_cos();
+                let (tx, ty) = transform(x, y)?;
+                let (hx, hy) = transform(x + cos, y + sin)?;
+                geometry.x = Length::new::<meter>(tx);
+                geometry.y = Length::new::<meter>(ty);
+                geometry.hdg = Angle::new::<radian>((hy - ty).atan2(hx - tx));
+            }
+        }
+
+        self.set_geo_reference(to);
+        Ok(())
+    }
+
+    /// Replaces every road and junction id with the result of `mapping` and updates all references
+    /// to them: road links, the junction of roads, junction connections and priorities, junction
+    /// groups, signal positions and railroad tracks and platforms. The ids of signals and objects
+    /// are not changed. Fails without changing anything if two distinct road ids, or two distinct
+    /// junction ids, are mapped to the same id.
+    pub fn renumber_ids(
+        &mut self,
+        mapping: impl Fn(&str) -> String,
+    ) -> Result<(), renumber::RenumberError> {
+        renumber::renumber(self, mapping)
+    }
+
+    /// A normalized copy of this document, as it may be preferred for ingestion. Compared to
+    /// this document, the copy
+    ///  - replaces deprecated object types by their modern equivalent, which is
+    ///    [`ObjectType::Obstacle`] for all of them, see [`ObjectType::modernized`]
+    ///  - drops the optional containers of roads that neither hold elements nor additional
+    ///    data: `<objects>`, `<signals>`, `<surface>`, `<railroad>`, `<elevationProfile>` and
+    ///    `<lateralProfile>`
+    ///  - sorts the geometries, elevations, superelevations, shapes, lane offsets and lane
+    ///    sections of each road in ascending order of s, keeping the order of equal ones
+    ///
+    /// [`ObjectType::Obstacle`]: crate::object::orientation::ObjectType::Obstacle
+    /// [`ObjectType::modernized`]: crate::object::orientation::ObjectType::modernized
+    pub fn to_canonical(&self) -> OpenDrive {
+        let mut drive = self.clone();
+        canonical::canonicalize(&mut drive);
+        drive
+    }
+
+    /// The [`Road::drivable_patches`] of all roads, in document order
+    pub fn drivable_patches(
+        &self,
+        step: uom::si::f64::Length,
+        filter: impl Fn(&LaneType) -> bool,
+    ) -> Vec<Patch<'_>> {
+        self.road
+            .iter()
+            .flat_map(|road| road.drivable_patches(step, &filter))
+            .collect()
+    }
+
+    /// Reprojects the geometry from the projection of the current `<geoReference>` to the
+    /// projection `to`, both given as PROJ strings of projected coordinate systems in meters. See
+    /// [`OpenDrive::reproject_with`] for the limitations of the transformation.
+    #[cfg(feature = "proj")]
+    pub fn reproject(&mut self, to: &str) -> Result<(), reproject::ReprojectError> {
+        use proj4rs::proj::Proj;
+        use reproject::ReprojectError;
+
+        let from = self
+            .header
+            .geo_reference
+            .as_ref()
+            .and_then(|geo_reference| geo_reference.proj.as_deref())
+            .ok_or(ReprojectError::MissingGeoReference)?;
+        let projection = |proj: &str| {
+            let projection = Proj::from_proj_string(proj.trim())?;
+            if projection.is_latlong() {
+                Err(ReprojectError::Geographic(proj.to_string()))
+            } else {
+                Ok(projection)
+            }
+        };
+        let (source, target) = (projection(from)?, projection(to)?);
+
+        self.reproject_with(to, |x, y| {
+            let mut point = (x, y, 0.0);
+            proj4rs::transform::transform(&source, &target, &mut point)?;
+            Ok((point.0, point.1))
+        })
+    }
+
+    /// Moves the start of roads onto the end of their predecessor, if the gap between them is
+    /// within `tolerance` but not zero, and returns the number of snapped roads. For every road
+    /// with a `<successor>` road linked at its start, the first geometry element of the successor
+    /// is moved to the end of the reference line of the road and rotated to its heading. Links to
+    /// the end of a successor and links through junctions are not considered.
+    pub fn snap_endpoints(&mut self, tolerance: uom::si::f64::Length) -> usize {
+        use crate::junction::contact_point::ContactPoint;
+        use crate::road::element_type::ElementType;
+
+        let indices = self
+            .road
+            .iter()
+            .enumerate()
+            .map(|(index, road)| (road.id.as_str(), index))
+            .collect::<HashMap<_, _>>();
+
+        let snaps = self
+            .road
+            .iter()
+            .filter_map(|road| {
+                let successor = road.link.as_ref()?.successor.as_ref()?;
+                if !matches!(successor.element_type, Some(ElementType::Road) | None)
+                    || matches!(successor.contact_point, Some(ContactPoint::End))
+                {
+                    return None;
+                }
+                let index = *indices.get(successor.element_id.as_str())?;
+                let last = road.plan_view.geometry.last();
+                let (x, y, hdg) = last.position_at(last.s + last.length);
+                let start = self.road[index].plan_view.geometry.first();
+                let distance = (start.x - x).hypot(start.y - y);
+                let snapped = start.x == x && start.y == y && start.hdg == hdg;
+                (distance <= tolerance && !snapped).then_some((index, x, y, hdg))
+            })
+            .collect::<Vec<_>>();
+
+        for (index, x, y, hdg) in &snaps {
+            let start = self.road[*index].plan_view.geometry.first_mut();
+            start.x = *x;
+            start.y = *y;
+            start.hdg = *hdg;
+        }
+
+        snaps.len()
+    }
+
+    /// Counts the roads, junctions, lanes, signals and objects of this document
+    pub fn statistics(&self) -> Statistics {
+        Statistics::from(self)
+    }
+
+    /// Compares the roads and junctions of this document to those of `other` by their ids. Roads
+    /// are summarized by their name, junction, length, rule and the number of geometries and lane
+    /// sections, junctions by their name and the number of connections and priorities. Any other
+    /// difference is reported as [`DiffEntry::Modified`].
+    pub fn diff(&self, other: &OpenDrive) -> Vec<DiffEntry> {
+        DiffEntry::between(self, other)
+    }
+
+    /// Encodes this document in a compact binary format, which is considerably faster to load
+    /// than XML. The encoding is **not** a stable interchange format: it may change with any
+    /// version of this crate and should only be used for caching preprocessed documents.
+    #[cfg(feature = "bincode")]
+    pub fn to_binary(&self) -> Vec<u8> {
+        bincode::serialize(self).expect("the document model has no unsupported serde types")
+    }
+
+    /// Decodes a document previously encoded by [`OpenDrive::to_binary`] with the same version
+    /// of this crate.
+    #[cfg(feature = "bincode")]
+    pub fn from_binary(bytes: &[u8]) -> bincode::Result<Self> {
+        bincode::deserialize(bytes)
+    }
+
     pub fn to_writer(&self) -> xml::writer::Result<EventWriter<Vec<u8>>> {
         let mut writer = EventWriter::new(Vec::new());
         self.append_to_writer(&mut writer)?;
         Ok(writer)
     }
 
+    /// Serializes the document to an XML string. The attributes of `<road>`, `<geometry>`,
+    /// `<paramPoly3>`, `<elevation>`, `<superelevation>`, `<shape>`, `<laneOffset>`, `<lane>`,
+    /// `<width>` and `<border>` are emitted in the order they are declared in the XSD. All other
+    /// elements emit their attributes in a fixed order, which is not necessarily that of the XSD.
     #[inline]
     pub fn to_xml_string(&self) -> crate::writer::Result<String> {
-        String::from_utf8(
-            self.to_writer()
-                .map_err(crate::writer::Error::from)
-                .map_err(Box::new)?
-                .into_inner(),
-        )
-        .map_err(crate::writer::Error::from)
-        .map_err(Box::new)
+        self.to_xml_string_with_options(&WriteOptions::default())
+    }
+
+    /// See [`OpenDrive::to_xml_string`]
+    pub fn to_xml_string_with_options(
+        &self,
+        options: &WriteOptions,
+    ) -> crate::writer::Result<String> {
+        let mut writer = EventWriter::new(Vec::new());
+        self.append_to_writer_with_options(&mut writer, options)
+            .map_err(crate::writer::Error::from)
+            .map_err(Box::new)?;
+        String::from_utf8(writer.into_inner())
+            .map_err(crate::writer::Error::from)
+            .map_err(Box::new)
     }
 
+    /// Writes the document to `w` and returns it after it has been flushed, so that no buffered
+    /// output is lost silently.
     #[inline]
-    pub fn to_xml_write(&self, w: impl std::io::Write) -> crate::writer::Result<()> {
+    pub fn to_xml_write<W: std::io::Write>(&self, w: W) -> crate::writer::Result<W> {
         let mut writer = EventWriter::new(w);
         self.append_to_writer(&mut writer)
             .map_err(crate::writer::Error::from)
             .map_err(Box::new)?;
-        Ok(())
+        Ok(writer.into_inner())
     }
 
+    #[inline]
     pub fn append_to_writer<'b, T: std::io::Write + 'b>(
         &self,
         writer: &'b mut EventWriter<T>,
+    ) -> xml::writer::Result<()> {
+        self.append_to_writer_with_options(writer, &WriteOptions::default())
+    }
+
+    pub fn append_to_writer_with_options<'b, T: std::io::Write + 'b>(
+        &self,
+        writer: &'b mut EventWriter<T>,
+        options: &WriteOptions,
+    ) -> xml::writer::Result<()> {
+        self.append_filtered_to_writer(writer, options, |_| true, |_| true)
+    }
+
+    /// Writes a document to `w` that contains only the roads matching `predicate`, besides the
+    /// header and all other elements. Junctions are written unless
+    /// [`WriteOptions::prune_junctions`] is set, in which case only the junctions referenced by
+    /// a written road or with a connection referencing a written road are kept.
+    #[inline]
+    pub fn write_filtered<W: std::io::Write>(
+        &self,
+        w: W,
+        predicate: impl Fn(&Road) -> bool,
+    ) -> crate::writer::Result<W> {
+        self.write_filtered_with_options(w, predicate, &WriteOptions::default())
+    }
+
+    /// See [`OpenDrive::write_filtered`]
+    pub fn write_filtered_with_options<W: std::io::Write>(
+        &self,
+        w: W,
+        predicate: impl Fn(&Road) -> bool,
+        options: &WriteOptions,
+    ) -> crate::writer::Result<W> {
+        let roads = self
+            .road
+            .iter()
+            .filter(|road| predicate(road))
+            .map(|road| road.id.as_str())
+            .collect::<HashSet<_>>();
+        let junctions = self
+            .road
+            .iter()
+            .filter(|road| roads.contains(road.id.as_str()))
+            .flat_map(Road::referenced_junctions)
+            .collect::<HashSet<_>>();
+
+        let mut writer = EventWriter::new(w);
+        self.append_filtered_to_writer(
+            &mut writer,
+            options,
+            |road| roads.contains(road.id.as_str()),
+            |junction| {
+                !options.prune_junctions
+                    || junctions.contains(junction.id.as_str())
+                    || junction.road_ids().any(|id| roads.contains(id))
+            },
+        )
+        .map_err(crate::writer::Error::from)
+        .map_err(Box::new)?;
+        Ok(writer.into_inner())
+    }
+
+    fn append_filtered_to_writer<'b, T: std::io::Write + 'b>(
+        &self,
+        writer: &'b mut EventWriter<T>,
+        options: &WriteOptions,
+        road_filter: impl Fn(&Road) -> bool,
+        junction_filter: impl Fn(&Junction) -> bool,
     ) -> xml::writer::Result<()> {
         writer.write(xml::writer::XmlEvent::StartDocument {
             version: xml::common::XmlVersion::Version10,
@@ -96,12 +796,15 @@ impl OpenDrive {
         self.visit_attributes(|attributes| {
             writer.write(xml::writer::XmlEvent::StartElement {
                 name: xml::name::Name::local("OpenDRIVE"),
-                attributes,
-                namespace: std::borrow::Cow::Owned(xml::namespace::Namespace::empty()),
+                attributes: options.root_attributes(&attributes),
+                namespace: std::borrow::Cow::Owned(
+                    options.root_namespace(self.namespace.as_deref()),
+                ),
             })
         })?;
-        self.visit_children(|event| writer.write(event))?;
+        self.visit_filtered_children(|event| writer.write(event), road_filter, junction_filter)?;
         writer.write(xml::writer::XmlEvent::EndElement { name: None })?;
+        writer.inner_mut().flush()?;
         Ok(())
     }
 
@@ -114,13 +817,23 @@ impl OpenDrive {
         visit_attributes!(visitor)
     }
 
+    #[inline]
     pub fn visit_children(
+        &self,
+        visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+    ) -> xml::writer::Result<()> {
+        self.visit_filtered_children(visitor, |_| true, |_| true)
+    }
+
+    fn visit_filtered_children(
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        road_filter: impl Fn(&Road) -> bool,
+        junction_filter: impl Fn(&Junction) -> bool,
     ) -> xml::writer::Result<()> {
         visit_children!(visitor, "header" => self.header);
 
-        for road in &self.road {
+        for road in self.road.iter().filter(|road| road_filter(road)) {
             visit_children!(visitor, "road" => road);
         }
 
@@ -128,7 +841,11 @@ impl OpenDrive {
             visit_children!(visitor, "controller" => controller);
         }
 
-        for junction in &self.junction {
+        for junction in self
+            .junction
+            .iter()
+            .filter(|junction| junction_filter(junction))
+        {
             visit_children!(visitor, "junction" => junction);
         }
 
@@ -176,6 +893,7 @@ where
             junction,
             junction_group,
             station,
+            namespace: read.namespace().map(str::to_string),
             additional_data,
         })
     }
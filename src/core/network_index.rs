@@ -0,0 +1,130 @@
+use crate::core::OpenDrive;
+use crate::junction::Junction;
+use crate::road::Road;
+use crate::signal::Signal;
+use std::collections::HashMap;
+
+/// Constant time lookups of roads, junctions and signals by their id, see
+/// [`OpenDrive::build_index`]. The ids are borrowed from the indexed document.
+#[derive(Debug, Clone)]
+pub struct NetworkIndex<'a> {
+    drive: &'a OpenDrive,
+    roads: HashMap<&'a str, usize>,
+    junctions: HashMap<&'a str, usize>,
+    signals: HashMap<&'a str, (usize, usize)>,
+}
+
+impl<'a> NetworkIndex<'a> {
+    /// Indexes the roads, junctions and signals of `drive`. Fails with one
+    /// [`NetworkIndexError::DuplicateId`] for each additional occurrence of an id, as an index
+    /// can only refer to one element per id.
+    pub fn new(drive: &'a OpenDrive) -> Result<Self, Vec<NetworkIndexError>> {
+        fn insert<'a, T>(
+            element: &'static str,
+            entries: impl Iterator<Item = (&'a str, T)>,
+            errors: &mut Vec<NetworkIndexError>,
+        ) -> HashMap<&'a str, T> {
+            let mut map = HashMap::new();
+            for (id, position) in entries {
+                if map.contains_key(id) {
+                    errors.push(NetworkIndexError::DuplicateId {
+                        element,
+                        id: id.to_string(),
+                    });
+                } else {
+                    map.insert(id, position);
+                }
+            }
+            map
+        }
+
+        let mut errors = Vec::new();
+        let roads = insert(
+            "road",
+            drive
+                .road
+                .iter()
+                .enumerate()
+                .map(|(i, r)| (r.id.as_str(), i)),
+            &mut errors,
+        );
+        let junctions = insert(
+            "junction",
+            drive
+                .junction
+                .iter()
+                .enumerate()
+                .map(|(i, j)| (j.id.as_str(), i)),
+            &mut errors,
+        );
+        let signals = drive
+            .road
+            .iter()
+            .enumerate()
+            .flat_map(|(road_index, road)| {
+                road.signals.iter().flat_map(move |signals| {
+                    signals
+                        .signal
+                        .iter()
+                        .enumerate()
+                        .map(move |(i, s)| (s.id.as_str(), (road_index, i)))
+                })
+            });
+        let signals = insert("signal", signals, &mut errors);
+
+        if errors.is_empty() {
+            Ok(Self {
+                drive,
+                roads,
+                junctions,
+                signals,
+            })
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// The index of the road with the given id within [`OpenDrive::road`]
+    pub fn road_position(&self, id: &str) -> Option<usize> {
+        self.roads.get(id).copied()
+    }
+
+    /// The index of the junction with the given id within [`OpenDrive::junction`]
+    pub fn junction_position(&self, id: &str) -> Option<usize> {
+        self.junctions.get(id).copied()
+    }
+
+    /// The index of the road within [`OpenDrive::road`] and of the signal within its
+    /// [`Signals::signal`] of the signal with the given id
+    ///
+    /// [`Signals::signal`]: crate::signal::signals::Signals::signal
+    pub fn signal_position(&self, id: &str) -> Option<(usize, usize)> {
+        self.signals.get(id).copied()
+    }
+
+    /// The road with the given id
+    pub fn road(&self, id: &str) -> Option<&'a Road> {
+        self.road_position(id).map(|index| &self.drive.road[index])
+    }
+
+    /// The junction with the given id
+    pub fn junction(&self, id: &str) -> Option<&'a Junction> {
+        self.junction_position(id)
+            .map(|index| &self.drive.junction[index])
+    }
+
+    /// The signal with the given id together with the road it is placed on
+    pub fn signal(&self, id: &str) -> Option<(&'a Road, &'a Signal)> {
+        let (road_index, signal_index) = self.signal_position(id)?;
+        let road = &self.drive.road[road_index];
+        let signal = &road.signals.as_ref()?.signal[signal_index];
+        Some((road, signal))
+    }
+}
+
+/// Errors of [`OpenDrive::build_index`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum NetworkIndexError {
+    #[error("The id `{id}` is used by more than one `{element}`")]
+    DuplicateId { element: &'static str, id: String },
+}
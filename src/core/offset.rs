@@ -10,6 +10,7 @@ use uom::si::length::meter;
 /// around the z-axis should be avoided. In ASAM OpenDRIVE, the offset of a database is represented
 /// by the `<offset>` element within the `<header>` element.
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Offset {
     /// Heading offset (rotation around resulting z-axis)
     pub hdg: Angle,
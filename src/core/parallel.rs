@@ -0,0 +1,161 @@
+use crate::parser::{FromXmlStr, ParseOptions, PositionedEvents};
+use crate::road::Road;
+use std::cell::Cell;
+use std::ops::Range;
+use xml::common::{Position, TextPosition};
+use xml::name::OwnedName;
+use xml::namespace::{Namespace, NS_XMLNS_PREFIX, NS_XML_PREFIX};
+use xml::reader::XmlEvent;
+use xml::EventReader;
+
+/// The start tag of the root element, without its attributes but with the namespace declarations
+/// in scope, to parse the `<road>` elements found by [`road_ranges`] as children of it
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Root {
+    name: String,
+    declarations: String,
+}
+
+impl Root {
+    fn new(name: &OwnedName, namespace: &Namespace) -> Self {
+        let name = match &name.prefix {
+            Some(prefix) => format!("{prefix}:{}", name.local_name),
+            None => name.local_name.clone(),
+        };
+        let mut declarations = String::new();
+        for (prefix, uri) in namespace {
+            let uri = xml::escape::escape_str_attribute(uri);
+            match prefix {
+                NS_XML_PREFIX | NS_XMLNS_PREFIX => {}
+                "" if uri.is_empty() => {}
+                "" => declarations.push_str(&format!(r#" xmlns="{uri}""#)),
+                prefix => declarations.push_str(&format!(r#" xmlns:{prefix}="{uri}""#)),
+            }
+        }
+        Self { name, declarations }
+    }
+
+    /// Encloses `road` in this root element. The start tag is on a line of its own, so `road`
+    /// starts in the second line.
+    fn wrap(&self, road: &str) -> String {
+        format!(
+            "<{name}{declarations}>\n{road}\n</{name}>",
+            name = self.name,
+            declarations = self.declarations,
+        )
+    }
+}
+
+/// The byte range of a `<road>` element within the document and the position of its start tag
+pub(crate) type RoadRange = (Range<usize>, TextPosition);
+
+/// Finds the byte ranges of all `<road>` elements that are direct children of the root element,
+/// including their start and end tags, together with the position of their start tags.
+pub(crate) fn road_ranges(s: &str) -> crate::parser::Result<(Root, Vec<RoadRange>)> {
+    let line_starts = std::iter::once(0)
+        .chain(s.match_indices('\n').map(|(index, _)| index + 1))
+        .collect::<Vec<_>>();
+    let offset = |position: TextPosition| {
+        let line = line_starts[position.row as usize];
+        line + s[line..]
+            .char_indices()
+            .nth(position.column as usize)
+            .map(|(index, _)| index)
+            .unwrap_or(s.len() - line)
+    };
+
+    let mut reader = EventReader::from_str(s);
+    let mut root = Root::default();
+    let mut ranges = Vec::new();
+    let mut depth = 0_usize;
+    let mut start = 0;
+    let mut start_position = TextPosition::new();
+
+    loop {
+        match reader.next().map_err(crate::parser::Error::from)? {
+            XmlEvent::StartElement {
+                name, namespace, ..
+            } => {
+                if depth == 0 {
+                    root = Root::new(&name, &namespace);
+                } else if depth == 1 && name.local_name.eq_ignore_ascii_case("road") {
+                    start_position = reader.position();
+                    start = offset(start_position);
+                }
+                depth += 1;
+            }
+            XmlEvent::EndElement { name } => {
+                depth -= 1;
+                if depth == 1 && name.local_name.eq_ignore_ascii_case("road") {
+                    let end_tag = offset(reader.position()).max(start);
+                    let end = s[end_tag..]
+                        .find('>')
+                        .map(|index| end_tag + index + 1)
+                        .unwrap_or(s.len());
+                    ranges.push((start..end, start_position));
+                }
+            }
+            XmlEvent::EndDocument => break,
+            _ => {}
+        }
+    }
+
+    Ok((root, ranges))
+}
+
+/// Parses a `<road>` element, as found by [`road_ranges`], as child of the `root` element. Paths
+/// and depths are therefore the same as when parsing the whole document. Positions in errors are
+/// relative to the document, given the position `start` of the road within it.
+pub(crate) fn parse_road(
+    root: &Root,
+    s: &str,
+    start: TextPosition,
+    options: ParseOptions,
+) -> crate::parser::Result<Road> {
+    let wrapped = root.wrap(s);
+    let position = Cell::new(TextPosition::new());
+    let mut events = PositionedEvents::new(EventReader::from_str(&wrapped), &position);
+    let mut road = None;
+
+    let mut read = crate::parser::ReadContext::from(&mut events)
+        .with_options(options)
+        .with_position_source(Some(&position));
+    let parsed = read
+        .children(|_, mut root| {
+            road = Some(Road::from_children(&mut root)?);
+            Ok(())
+        })
+        .and_then(|_| {
+            road.ok_or_else(|| {
+                Box::new(crate::parser::Error::missing_element(
+                    read.path().to_string(),
+                    Road::ELEMENT_NAME,
+                    core::any::type_name::<Road>(),
+                ))
+            })
+        });
+
+    parsed.map_err(|error| {
+        // the road starts in the second line of the wrapped fragment
+        let shifted = error.position().map(|position| TextPosition {
+            row: start.row + position.row.saturating_sub(1),
+            column: if position.row <= 1 {
+                start.column + position.column
+            } else {
+                position.column
+            },
+        });
+        match *error {
+            crate::parser::Error::XmlError(_) => error,
+            error => Box::new(error.with_position(shifted)),
+        }
+    })
+}
+
+/// Replaces the characters of `s`, except for line breaks, with spaces. This keeps the
+/// positions of the remaining document unchanged.
+pub(crate) fn blank(s: &str) -> String {
+    s.chars()
+        .map(|c| if c == '\n' { '\n' } else { ' ' })
+        .collect()
+}
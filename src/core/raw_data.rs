@@ -5,6 +5,7 @@ use std::borrow::Cow;
 /// Some basic metadata containing information about raw data included in ASAM OpenDRIVE is
 /// described by the `<rawData>` element within the `<dataQuality`> element.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct RawData {
     /// Date of the delivery of raw data, to be given in ISO 8601 notification
@@ -0,0 +1,130 @@
+use crate::core::OpenDrive;
+use crate::junction::junction_id::JunctionId;
+use crate::signal::position::Position;
+use std::collections::HashMap;
+
+/// Applies `mapping` to every road and junction id of `drive` and to every reference to them.
+/// Nothing is changed if the mapping produces a collision.
+pub(crate) fn renumber(
+    drive: &mut OpenDrive,
+    mapping: impl Fn(&str) -> String,
+) -> Result<(), RenumberError> {
+    fn mapped<'a>(
+        element: &'static str,
+        ids: impl Iterator<Item = &'a str>,
+        mapping: &impl Fn(&str) -> String,
+    ) -> Result<HashMap<String, String>, RenumberError> {
+        let mut mapped = HashMap::new();
+        let mut sources = HashMap::new();
+        for id in ids {
+            if mapped.contains_key(id) {
+                continue;
+            }
+            let new = mapping(id);
+            if let Some(other) = sources.insert(new.clone(), id) {
+                return Err(RenumberError::Collision {
+                    element,
+                    id: new,
+                    first: other.to_string(),
+                    second: id.to_string(),
+                });
+            }
+            mapped.insert(id.to_string(), new);
+        }
+        Ok(mapped)
+    }
+
+    let roads = mapped("road", drive.road.iter().map(|r| r.id.as_str()), &mapping)?;
+    let junctions = mapped(
+        "junction",
+        drive.junction.iter().map(|j| j.id.as_str()),
+        &mapping,
+    )?;
+
+    // references to ids that do not exist are mapped as well, to keep dangling references
+    // consistent with the existing ones
+    let road = |id: &mut String| *id = roads.get(id).cloned().unwrap_or_else(|| mapping(id));
+    let junction =
+        |id: &mut String| *id = junctions.get(id).cloned().unwrap_or_else(|| mapping(id));
+
+    for r in &mut drive.road {
+        road(&mut r.id);
+        if let Some(id) = r.junction.as_junction() {
+            let mut id = id.to_string();
+            junction(&mut id);
+            r.junction = JunctionId::new(id);
+        }
+        if let Some(link) = &mut r.link {
+            for link in link.predecessor.iter_mut().chain(link.successor.iter_mut()) {
+                match link.element_type {
+                    Some(crate::road::element_type::ElementType::Junction) => {
+                        junction(&mut link.element_id)
+                    }
+                    _ => road(&mut link.element_id),
+                }
+            }
+        }
+        let signals = r.signals.iter_mut().flat_map(|s| s.signal.iter_mut());
+        for signal in signals {
+            if let Some(Position::Road(position)) = &mut signal.choice {
+                road(&mut position.road_id);
+            }
+        }
+        for switch in r.railroad.iter_mut().flat_map(|r| r.switch.iter_mut()) {
+            road(&mut switch.main_track.id);
+            road(&mut switch.side_track.id);
+        }
+    }
+
+    for j in &mut drive.junction {
+        junction(&mut j.id);
+        j.main_road.iter_mut().for_each(road);
+        for connection in j.connection.iter_mut() {
+            connection.incoming_road.iter_mut().for_each(road);
+            connection.connecting_road.iter_mut().for_each(road);
+            connection.linked_road.iter_mut().for_each(road);
+            let links = connection
+                .predecessor
+                .iter_mut()
+                .chain(connection.successor.iter_mut());
+            for link in links {
+                road(&mut link.element_id);
+            }
+        }
+        for priority in &mut j.priority {
+            priority.high.iter_mut().for_each(road);
+            priority.low.iter_mut().for_each(road);
+        }
+    }
+
+    let references = drive
+        .junction_group
+        .iter_mut()
+        .flat_map(|g| g.junction_reference.iter_mut());
+    for reference in references {
+        junction(&mut reference.junction);
+    }
+
+    let segments = drive
+        .station
+        .iter_mut()
+        .flat_map(|s| s.platform.iter_mut())
+        .flat_map(|p| p.segment.iter_mut());
+    for segment in segments {
+        road(&mut segment.road_id);
+    }
+
+    Ok(())
+}
+
+/// Errors of [`OpenDrive::renumber_ids`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum RenumberError {
+    #[error("The {element} ids `{first}` and `{second}` are both mapped to `{id}`")]
+    Collision {
+        element: &'static str,
+        id: String,
+        first: String,
+        second: String,
+    },
+}
@@ -0,0 +1,10 @@
+/// Errors of [`crate::core::OpenDrive::reproject`]
+#[derive(Debug, thiserror::Error)]
+pub enum ReprojectError {
+    #[error("The header has no `<geoReference>` to reproject from")]
+    MissingGeoReference,
+    #[error("The projection `{0}` is geographic, but a projected coordinate system is required")]
+    Geographic(String),
+    #[error("Projection failed: {0}")]
+    Proj(#[from] proj4rs::errors::Error),
+}
@@ -0,0 +1,39 @@
+use uom::si::f64::Length;
+
+/// A half-open range `[start, end)` of s-coordinates along the reference line. The start is part
+/// of the range, the end is not, so that adjacent ranges sharing a boundary never overlap.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SRange {
+    pub start: Length,
+    pub end: Length,
+}
+
+impl SRange {
+    #[inline]
+    pub fn new(start: Length, end: Length) -> Self {
+        Self { start, end }
+    }
+
+    /// Whether `start <= s < end`
+    #[inline]
+    pub fn contains(&self, s: Length) -> bool {
+        self.start <= s && s < self.end
+    }
+
+    /// Limits `s` to `[start, end]`. Unlike [`SRange::contains`], the end is a valid result, so
+    /// that positions past the range map onto its last point.
+    #[inline]
+    pub fn clamp(&self, s: Length) -> Length {
+        s.max(self.start).min(self.end)
+    }
+
+    #[inline]
+    pub fn length(&self) -> Length {
+        self.end - self.start
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+}
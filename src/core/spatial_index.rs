@@ -0,0 +1,120 @@
+use crate::road::Road;
+use rstar::{PointDistance, RTree, RTreeObject, AABB};
+use uom::si::f64::Length;
+use uom::si::length::meter;
+
+/// An R-tree over straight segments sampled along the reference lines of all roads, see
+/// [`OpenDrive::build_spatial_index`]. The reference lines are sampled every
+/// [`SpatialIndex::SAMPLE_DISTANCE_M`] meters, except for `<line>` elements, which consist of a
+/// single segment. Distances are therefore approximations to the reference line.
+///
+/// [`OpenDrive::build_spatial_index`]: crate::core::OpenDrive::build_spatial_index
+pub struct SpatialIndex<'a> {
+    tree: RTree<RoadSegment<'a>>,
+}
+
+impl<'a> SpatialIndex<'a> {
+    pub const SAMPLE_DISTANCE_M: f64 = 1.0;
+
+    pub fn new(roads: impl IntoIterator<Item = &'a Road>) -> Self {
+        let segments = roads
+            .into_iter()
+            .flat_map(|road| {
+                Self::sample(road)
+                    .windows(2)
+                    .map(|w| RoadSegment {
+                        road,
+                        from: w[0],
+                        to: w[1],
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        Self {
+            tree: RTree::bulk_load(segments),
+        }
+    }
+
+    fn sample(road: &Road) -> Vec<[f64; 2]> {
+        road.plan_view
+            .sample_s(Length::new::<meter>(Self::SAMPLE_DISTANCE_M))
+            .into_iter()
+            .map(|s| {
+                let (x, y, _) = road.plan_view.geometry_at(s).position_at(s);
+                [x.get::<meter>(), y.get::<meter>()]
+            })
+            .collect()
+    }
+
+    /// The road with the reference line closest to the point `(x, y)`
+    pub fn nearest_road(&self, x: Length, y: Length) -> Option<&'a Road> {
+        self.tree
+            .nearest_neighbor(&[x.get::<meter>(), y.get::<meter>()])
+            .map(|segment| segment.road)
+    }
+
+    /// Projects the inertial point `(x, y)` onto the closest road and returns it together with
+    /// the road coordinates `(s, t)`, see [`Road::project_point`]. Only roads close to the
+    /// nearest sampled segment are considered as candidates.
+    pub fn localize(&self, x: Length, y: Length) -> Option<(&'a Road, Length, Length)> {
+        let point = [x.get::<meter>(), y.get::<meter>()];
+        let nearest = self
+            .tree
+            .nearest_neighbor(&point)?
+            .distance_2(&point)
+            .sqrt();
+        // the sampled segments deviate from the reference line by much less than their length
+        let radius = Length::new::<meter>(nearest + Self::SAMPLE_DISTANCE_M);
+
+        self.roads_in_radius(x, y, radius)
+            .into_iter()
+            .map(|road| (road, road.project_point(x, y)))
+            .min_by(|(_, (_, _, a)), (_, (_, _, b))| a.total_cmp(b))
+            .map(|(road, (s, t, _))| (road, s, t))
+    }
+
+    /// All roads with a reference line within `radius` of the point `(x, y)`, ordered by their
+    /// first appearance in the index
+    pub fn roads_in_radius(&self, x: Length, y: Length, radius: Length) -> Vec<&'a Road> {
+        let mut roads: Vec<&'a Road> = Vec::new();
+        let radius = radius.get::<meter>();
+        for segment in self
+            .tree
+            .locate_within_distance([x.get::<meter>(), y.get::<meter>()], radius * radius)
+        {
+            if !roads.iter().any(|road| std::ptr::eq(*road, segment.road)) {
+                roads.push(segment.road);
+            }
+        }
+        roads
+    }
+}
+
+struct RoadSegment<'a> {
+    road: &'a Road,
+    from: [f64; 2],
+    to: [f64; 2],
+}
+
+impl RTreeObject for RoadSegment<'_> {
+    type Envelope = AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        AABB::from_corners(self.from, self.to)
+    }
+}
+
+impl PointDistance for RoadSegment<'_> {
+    fn distance_2(&self, point: &[f64; 2]) -> f64 {
+        let (dx, dy) = (self.to[0] - self.from[0], self.to[1] - self.from[1]);
+        let (px, py) = (point[0] - self.from[0], point[1] - self.from[1]);
+        let length_2 = dx * dx + dy * dy;
+        let t = if length_2 > 0.0 {
+            ((px * dx + py * dy) / length_2).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let (ex, ey) = (px - t * dx, py - t * dy);
+        ex * ex + ey * ey
+    }
+}
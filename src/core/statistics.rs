@@ -0,0 +1,53 @@
+use crate::core::OpenDrive;
+use crate::lane::lane_type::LaneType;
+use std::collections::HashMap;
+use uom::si::f64::Length;
+
+/// Aggregated counts of the contents of an [`OpenDrive`] document, see [`OpenDrive::statistics`]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Statistics {
+    /// Number of `<road>` elements
+    pub roads: usize,
+    /// Sum of the reference line lengths of all roads
+    pub total_length: Length,
+    /// Number of `<junction>` elements
+    pub junctions: usize,
+    /// Number of lanes per type, summed up over all lane sections (including center lanes)
+    pub lanes_by_type: HashMap<LaneType, usize>,
+    /// Number of `<signal>` elements (not counting `<signalReference>`)
+    pub signals: usize,
+    /// Number of `<object>` elements (not counting `<objectReference>`)
+    pub objects: usize,
+}
+
+impl Statistics {
+    /// Total number of lanes of all types
+    pub fn lanes(&self) -> usize {
+        self.lanes_by_type.values().sum()
+    }
+}
+
+impl From<&OpenDrive> for Statistics {
+    fn from(drive: &OpenDrive) -> Self {
+        let mut statistics = Self {
+            roads: drive.road.len(),
+            junctions: drive.junction.len(),
+            ..Self::default()
+        };
+
+        for road in &drive.road {
+            statistics.total_length += road.length;
+            statistics.signals += road.signals.as_ref().map_or(0, |s| s.signal.len());
+            statistics.objects += road.objects.as_ref().map_or(0, |o| o.object.len());
+
+            for (_, lane) in road.lanes.lane_section.iter().flat_map(|s| s.lanes()) {
+                *statistics
+                    .lanes_by_type
+                    .entry(lane.r#type.clone())
+                    .or_default() += 1;
+            }
+        }
+
+        statistics
+    }
+}
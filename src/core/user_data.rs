@@ -7,6 +7,7 @@ use std::collections::HashMap;
 /// In ASAM OpenDRIVE, ancillary data is represented by `<userData>` elements. They may be stored at
 /// any element in ASAM OpenDRIVE.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct UserData {
     /// Code for the user data. Free text, depending on application.
@@ -64,6 +65,7 @@ where
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Element {
     pub name: String,
     pub attributes: HashMap<String, String>,
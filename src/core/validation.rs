@@ -0,0 +1,238 @@
+use crate::core::OpenDrive;
+use crate::junction::JunctionError;
+use crate::lane::lane_choice::LaneChoice;
+use crate::lane::lane_section::LaneSectionError;
+use crate::lane::width::Width;
+use crate::object::ShapeError;
+use crate::parser::{ParseOptions, Warning};
+use crate::road::element_type::ElementType;
+use crate::road::geometry::plan_view::CoverageError;
+use crate::road::Road;
+use std::f64::consts::{PI, TAU};
+use uom::si::angle::radian;
+use uom::si::f64::Length;
+use uom::si::length::meter;
+
+/// The tolerance for gaps and overlaps of geometry elements used by [`OpenDrive::validate_all`]
+pub const COVERAGE_TOLERANCE_M: f64 = 1e-3;
+
+/// The distance the start of a geometry element may lie off the end of the previous one, used by
+/// [`OpenDrive::validate_all`]
+pub const CONTINUITY_TOLERANCE_M: f64 = 1e-3;
+
+/// The difference between the heading at the start of a geometry element and the heading at the
+/// end of the previous one, which is tolerated by [`OpenDrive::validate_all`]
+pub const CONTINUITY_TOLERANCE_RAD: f64 = 1e-3;
+
+/// Runs all checks, see [`OpenDrive::validate_all`]
+pub(crate) fn validate_all(drive: &OpenDrive) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+
+    for road in &drive.road {
+        validate_road(drive, road, &mut errors);
+    }
+
+    errors.extend(
+        drive
+            .junction
+            .iter()
+            .filter_map(|junction| junction.validate().err())
+            .map(ValidationError::Junction),
+    );
+
+    let options = ParseOptions::default()
+        .with_check_duplicate_ids(true)
+        .with_check_version(true)
+        .with_check_center_lane(true)
+        .with_check_road_length(true)
+        .with_check_junction_backrefs(true)
+        .with_check_deprecated(true);
+    errors.extend(
+        drive
+            .warnings(&options)
+            .into_iter()
+            .map(ValidationError::Warning),
+    );
+
+    errors
+}
+
+fn validate_road(drive: &OpenDrive, road: &Road, errors: &mut Vec<ValidationError>) {
+    let tolerance = Length::new::<meter>(COVERAGE_TOLERANCE_M);
+    if let Err(error) = road.plan_view.validate_coverage(road.length, tolerance) {
+        errors.push(ValidationError::Coverage {
+            road: road.id.clone(),
+            error,
+        });
+    }
+
+    for (index, pair) in road.plan_view.geometry.windows(2).enumerate() {
+        let (previous, next) = (&pair[0], &pair[1]);
+        let (x, y, hdg) = previous.position_at(previous.s + previous.length);
+        let distance = (next.x - x)
+            .get::<meter>()
+            .hypot((next.y - y).get::<meter>());
+        let heading = ((next.hdg - hdg).get::<radian>() + PI).rem_euclid(TAU) - PI;
+        if distance > CONTINUITY_TOLERANCE_M || heading.abs() > CONTINUITY_TOLERANCE_RAD {
+            errors.push(ValidationError::Discontinuity {
+                road: road.id.clone(),
+                index: index + 1,
+                distance,
+                heading,
+            });
+        }
+    }
+
+    let sections = &road.lanes.lane_section;
+    for (section_index, pair) in sections.windows(2).enumerate() {
+        if pair[1].s < pair[0].s {
+            errors.push(ValidationError::UnsortedLaneSection {
+                road: road.id.clone(),
+                section_index: section_index + 1,
+                s: pair[1].s,
+            });
+        }
+    }
+
+    for (section_index, section) in sections.iter().enumerate() {
+        if let Err(error) = section.validate() {
+            errors.push(ValidationError::LaneSection {
+                road: road.id.clone(),
+                section_index,
+                error,
+            });
+        }
+    }
+
+    for (section_index, (range, section)) in road.lane_section_ranges().enumerate() {
+        let section_length = range.end - range.start;
+        for (lane_id, lane) in section.lanes() {
+            let widths = lane
+                .choice
+                .iter()
+                .filter_map(|choice| match choice {
+                    LaneChoice::Width(width) => Some(width),
+                    LaneChoice::Border(_) => None,
+                })
+                .collect::<Vec<_>>();
+            for (index, width) in widths.iter().enumerate() {
+                let end = widths
+                    .get(index + 1)
+                    .map(|next| next.s_offset)
+                    .unwrap_or(section_length)
+                    .max(width.s_offset);
+                if is_negative(width, end) {
+                    errors.push(ValidationError::NegativeWidth {
+                        road: road.id.clone(),
+                        section_index,
+                        lane_id,
+                        s_offset: width.s_offset.get::<meter>(),
+                    });
+                }
+            }
+        }
+    }
+
+    for object in road.objects.iter().flat_map(|objects| &objects.object) {
+        if let Err(error) = object.validate_shape() {
+            errors.push(ValidationError::Shape {
+                road: road.id.clone(),
+                error,
+            });
+        }
+    }
+
+    let links = road.link.iter().flat_map(|link| {
+        [
+            ("predecessor", link.predecessor.as_ref()),
+            ("successor", link.successor.as_ref()),
+        ]
+    });
+    for (element, target) in links {
+        let Some(target) = target else {
+            continue;
+        };
+        let exists = match target.element_type {
+            Some(ElementType::Junction) => drive.junction.iter().any(|j| j.id == target.element_id),
+            Some(ElementType::Road) | None => drive.road.iter().any(|r| r.id == target.element_id),
+        };
+        if !exists {
+            errors.push(ValidationError::DanglingLink {
+                road: road.id.clone(),
+                element,
+                target: target.element_id.clone(),
+            });
+        }
+    }
+}
+
+/// Whether the `width` becomes negative anywhere between its start and `end`, relative to the
+/// start of the lane section. Between its roots, the sign of the polynomial is that of any point
+/// in between, so only the ends and the midpoints between the roots have to be evaluated.
+fn is_negative(width: &Width, end: Length) -> bool {
+    let length = (end - width.s_offset).get::<meter>();
+    let mut points = vec![0.0];
+    points.extend(
+        width
+            .roots()
+            .into_iter()
+            .filter(|root| *root > 0.0 && *root < length),
+    );
+    points.push(length);
+
+    let negative = |ds: f64| {
+        width
+            .width_at(width.s_offset + Length::new::<meter>(ds))
+            .value
+            < 0.0
+    };
+    points.iter().any(|ds| negative(*ds))
+        || points.windows(2).any(|w| negative((w[0] + w[1]) / 2.0))
+}
+
+/// An issue found by [`OpenDrive::validate_all`], locating the affected road or element
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ValidationError {
+    #[error("Road `{road}`: {error}")]
+    Coverage { road: String, error: CoverageError },
+    #[error("The geometry element {index} of road `{road}` starts {distance}m and {heading}rad off the end of the previous one")]
+    Discontinuity {
+        road: String,
+        index: usize,
+        distance: f64,
+        heading: f64,
+    },
+    #[error(
+        "The lane section {section_index} of road `{road}` starts at s={s} before the previous one"
+    )]
+    UnsortedLaneSection {
+        road: String,
+        section_index: usize,
+        s: f64,
+    },
+    #[error("Road `{road}`, lane section {section_index}: {error}")]
+    LaneSection {
+        road: String,
+        section_index: usize,
+        error: LaneSectionError,
+    },
+    #[error("The width of lane {lane_id} in lane section {section_index} of road `{road}` is negative for the element at sOffset={s_offset}")]
+    NegativeWidth {
+        road: String,
+        section_index: usize,
+        lane_id: i64,
+        s_offset: f64,
+    },
+    #[error("Road `{road}`: {error}")]
+    Shape { road: String, error: ShapeError },
+    #[error("The {element} of road `{road}` references `{target}`, which does not exist")]
+    DanglingLink {
+        road: String,
+        element: &'static str,
+        target: String,
+    },
+    #[error(transparent)]
+    Junction(JunctionError),
+    #[error(transparent)]
+    Warning(Warning),
+}
@@ -0,0 +1,28 @@
+use std::fmt::{Display, Formatter};
+
+/// The revision of the ASAM OpenDRIVE format, as declared by `revMajor` and `revMinor` in the
+/// `<header>` element
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Version {
+    pub major: u16,
+    pub minor: u16,
+}
+
+impl Version {
+    pub const V1_4: Self = Self::new(1, 4);
+    pub const V1_5: Self = Self::new(1, 5);
+    pub const V1_6: Self = Self::new(1, 6);
+    pub const V1_7: Self = Self::new(1, 7);
+
+    #[inline]
+    pub const fn new(major: u16, minor: u16) -> Self {
+        Self { major, minor }
+    }
+}
+
+impl Display for Version {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
@@ -6,6 +6,7 @@ use std::borrow::Cow;
 
 /// Provides information about a single connection within a junction.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Connection {
     pub predecessor: Option<PredecessorSuccessor>,
@@ -27,6 +28,15 @@ pub struct Connection {
 }
 
 impl Connection {
+    /// The road entered from the incoming road: the connecting road, or the directly linked road
+    /// for connections of direct junctions, which have no connecting road
+    #[inline]
+    pub fn outgoing_road(&self) -> Option<&str> {
+        self.connecting_road
+            .as_deref()
+            .or(self.linked_road.as_deref())
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 
 /// Lists the controllers that are used for the management of a junction.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Controller {
     /// ID of the controller
@@ -6,6 +6,7 @@ use uom::si::length::meter;
 
 /// Data described in OpenCRG are represented by the `<CRG>` element within the `<surface>` element.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Crg {
     /// Name of the file containing the CRG data
     pub file: String,
@@ -8,6 +8,7 @@ use vec1::Vec1;
 /// to the same roundabout.
 /// The `<junctionGroup>` element is split into a header element and a series of member elements.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct JunctionGroup {
     pub junction_reference: Vec1<JunctionReference>,
     /// Unique ID within database
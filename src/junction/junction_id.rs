@@ -0,0 +1,90 @@
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The id of the junction a road belongs to, where [`JunctionId::NONE`] (`-1`) marks a road that
+/// is not part of a junction. Any other string is accepted as id, as mandated by the standard.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
+pub struct JunctionId(String);
+
+impl JunctionId {
+    /// The value of a road that does not belong to a junction
+    pub const NONE: &'static str = "-1";
+
+    #[inline]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+
+    #[inline]
+    pub fn none() -> Self {
+        Self::new(Self::NONE)
+    }
+
+    /// Whether the road does not belong to a junction
+    #[inline]
+    pub fn is_none(&self) -> bool {
+        self.0.trim() == Self::NONE
+    }
+
+    /// The id of the junction, unless the road does not belong to a junction
+    #[inline]
+    pub fn as_junction(&self) -> Option<&str> {
+        if self.is_none() {
+            None
+        } else {
+            Some(&self.0)
+        }
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for JunctionId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for JunctionId {
+    type Err = Infallible;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
+impl From<String> for JunctionId {
+    #[inline]
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+
+impl From<&str> for JunctionId {
+    #[inline]
+    fn from(id: &str) -> Self {
+        Self::new(id)
+    }
+}
+
+impl PartialEq<str> for JunctionId {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for JunctionId {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
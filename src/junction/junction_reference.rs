@@ -2,6 +2,7 @@ use std::borrow::Cow;
 
 /// References to existing `<junction> elements.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct JunctionReference {
     /// ID of the junction
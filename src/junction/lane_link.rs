@@ -4,6 +4,7 @@ use std::borrow::Cow;
 /// road. It is strongly recommended to provide this element. It is deprecated to omit the
 /// `<laneLink>` element.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct LaneLink {
     /// ID of the incoming lane
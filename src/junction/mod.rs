@@ -20,6 +20,7 @@ pub mod crg_purpose;
 pub mod element_dir;
 pub mod junction_group;
 pub mod junction_group_type;
+pub mod junction_id;
 pub mod junction_reference;
 pub mod junction_type;
 pub mod lane_link;
@@ -28,6 +29,7 @@ pub mod priority;
 pub mod surface;
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Junction {
     pub connection: Vec1<Connection>,
     pub priority: Vec<Priority>,
@@ -61,6 +63,78 @@ pub struct Junction {
 }
 
 impl Junction {
+    #[inline]
+    pub fn is_virtual(&self) -> bool {
+        matches!(self.r#type, Some(JunctionType::Virtual))
+    }
+
+    #[inline]
+    pub fn is_direct(&self) -> bool {
+        matches!(self.r#type, Some(JunctionType::Direct))
+    }
+
+    /// The (incoming, outgoing) road ids of all connections naming both roads. The outgoing road
+    /// is the connecting road of a regular junction or the linked road of a direct junction, see
+    /// [`Connection::outgoing_road`].
+    pub fn connected_roads(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.connection
+            .iter()
+            .filter_map(|c| Some((c.incoming_road.as_deref()?, c.outgoing_road()?)))
+    }
+
+    /// The ids of all incoming, connecting and linked roads of the connections
+    pub fn road_ids(&self) -> impl Iterator<Item = &str> {
+        self.connection.iter().flat_map(|c| {
+            [&c.incoming_road, &c.connecting_road, &c.linked_road]
+                .into_iter()
+                .filter_map(|id| id.as_deref())
+        })
+    }
+
+    /// The connecting road ids of the connections, in the order of the connections. Connections
+    /// without a connecting road, such as those of direct junctions, are skipped.
+    pub fn connecting_road_ids(&self) -> impl Iterator<Item = &str> {
+        self.connection
+            .iter()
+            .filter_map(|c| c.connecting_road.as_deref())
+    }
+
+    /// The (high, low) connecting road ids of all `<priority>` records which name both roads, the
+    /// road `high` has priority over the road `low`.
+    pub fn priorities(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.priority
+            .iter()
+            .filter_map(|p| Some((p.high.as_deref()?, p.low.as_deref()?)))
+    }
+
+    /// Checks that the attributes `mainRoad`, `sStart`, `sEnd` and `orientation` are present for
+    /// virtual junctions and absent for all other junction types.
+    pub fn validate(&self) -> Result<(), JunctionError> {
+        let virtual_attributes = [
+            ("mainRoad", self.main_road.is_some()),
+            ("sStart", self.s_start.is_some()),
+            ("sEnd", self.s_end.is_some()),
+            ("orientation", self.orientation.is_some()),
+        ];
+
+        let is_virtual = self.is_virtual();
+        for (attribute, present) in virtual_attributes {
+            if is_virtual && !present {
+                return Err(JunctionError::MissingVirtualAttribute {
+                    junction: self.id.clone(),
+                    attribute,
+                });
+            } else if !is_virtual && present {
+                return Err(JunctionError::UnexpectedVirtualAttribute {
+                    junction: self.id.clone(),
+                    attribute,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
@@ -103,6 +177,24 @@ impl Junction {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum JunctionError {
+    #[error("Virtual junction `{junction}` is missing the mandatory attribute `{attribute}`")]
+    MissingVirtualAttribute {
+        junction: String,
+        attribute: &'static str,
+    },
+    #[error("Junction `{junction}` is not virtual but specifies the attribute `{attribute}`")]
+    UnexpectedVirtualAttribute {
+        junction: String,
+        attribute: &'static str,
+    },
+}
+
+impl crate::parser::FromXmlStr for Junction {
+    const ELEMENT_NAME: &'static str = "junction";
+}
+
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Junction
 where
     I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
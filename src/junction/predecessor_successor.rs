@@ -6,6 +6,7 @@ use uom::si::length::meter;
 /// Provides detailed information about the predecessor / successor road of a virtual connection.
 /// Currently, only the @elementType “road” is allowed.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PredecessorSuccessor {
     /// Direction, relative to the s-direction, of the connection on the preceding / succeeding road
     pub element_dir: ElementDir,
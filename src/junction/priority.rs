@@ -4,6 +4,7 @@ use std::borrow::Cow;
 /// another connecting road. It is only required if priorities cannot be derived from signs or
 /// signals in a junction or on tracks leading to a junction.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Priority {
     /// ID of the prioritized connecting road
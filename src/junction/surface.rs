@@ -6,6 +6,7 @@ use std::borrow::Cow;
 /// contains a `<surface>` element, the `<surface>` element supersedes all elevation data for
 /// connecting roads.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Surface {
     pub crg: Vec<Crg>,
@@ -11,6 +11,7 @@ pub mod rule;
 /// Each element is valid in direction of the increasing s coordinate until a new element is
 /// defined. If multiple elements are defined, they shall be listed in ascending order.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Access {
     /// Identifier of the participant to whom the restriction applies
     pub restriction: AccessRestrictionType,
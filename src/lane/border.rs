@@ -14,6 +14,7 @@ use uom::si::length::meter;
 /// In ASAM OpenDRIVE, lane borders are represented by the `<border>` element within the `<lane>`
 /// element.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Border {
     /// Polynom parameter a, border position at @s (ds=0)
     // https://github.com/RReverser/serde-xml-rs/issues/137
@@ -34,19 +35,26 @@ pub struct Border {
 }
 
 impl Border {
+    /// The distance of the outer border from the center lane at `ds`, relative to the start of the
+    /// lane section
+    pub fn border_at(&self, ds: Length) -> Length {
+        let ds = (ds - self.s_offset).get::<meter>();
+        Length::new::<meter>(self.a + ds * (self.b + ds * (self.c + ds * self.d)))
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
         ) -> xml::writer::Result<()>,
     ) -> xml::writer::Result<()> {
-        visit_attributes!(
+        visit_scientific_attributes!(
             visitor,
-            "a" => &self.a.to_scientific_string(),
-            "b" => &self.b.to_scientific_string(),
-            "c" => &self.c.to_scientific_string(),
-            "d" => &self.d.to_scientific_string(),
-            "sOffset" => &self.s_offset.value.to_scientific_string(),
+            "sOffset" => self.s_offset.value,
+            "a" => self.a,
+            "b" => self.b,
+            "c" => self.c,
+            "d" => self.d,
         )
     }
 
@@ -7,12 +7,20 @@ use vec1::Vec1;
 /// section are grouped into left, center, and right lanes. Each lane section shall contain one
 /// `<center>` element and at least one `<right>` or `<left>` element.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Center {
     pub lane: Vec1<CenterLane>,
     pub additional_data: AdditionalData,
 }
 
 impl Center {
+    /// The center lane, which shall have the id 0 and no width. A valid `<center>` element
+    /// contains exactly one lane, so this is the first lane.
+    #[inline]
+    pub fn lane0(&self) -> &CenterLane {
+        self.lane.first()
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
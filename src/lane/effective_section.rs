@@ -0,0 +1,16 @@
+use crate::lane::center::Center;
+use crate::lane::left::Left;
+use crate::lane::right::Right;
+
+/// The full cross-section of the road from one lane section start to the next, with single side
+/// lane sections merged into it, see [`Lanes::effective_sections`]
+///
+/// [`Lanes::effective_sections`]: crate::lane::lanes::Lanes::effective_sections
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EffectiveSection<'a> {
+    /// s-coordinate of start position
+    pub s: f64,
+    pub left: Option<&'a Left>,
+    pub center: &'a Center,
+    pub right: Option<&'a Right>,
+}
@@ -7,6 +7,7 @@ use uom::si::length::meter;
 /// as raising pedestrian walkways. Lane height is specified as offset from the road (including
 /// elevation, superelevation, shape) in z direction.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Height {
     /// Inner offset from road level
     pub inner: Length,
@@ -0,0 +1,17 @@
+use uom::si::f64::Length;
+
+/// The borders of a single lane of a lane section as polylines in the inertial system, see
+/// [`LaneSection::lane_boundaries`]
+///
+/// [`LaneSection::lane_boundaries`]: crate::lane::lane_section::LaneSection::lane_boundaries
+#[derive(Debug, Clone, PartialEq)]
+pub struct LaneBoundary {
+    /// ID of the lane
+    pub lane_id: i64,
+    /// Inertial `(x, y, z)` positions of the border towards the center lane, one per sampled
+    /// s-coordinate
+    pub inner: Vec<(Length, Length, Length)>,
+    /// Inertial `(x, y, z)` positions of the border away from the center lane, one per sampled
+    /// s-coordinate
+    pub outer: Vec<(Length, Length, Length)>,
+}
@@ -9,6 +9,7 @@ use std::borrow::Cow;
 /// line depending on the contact point of the road linkage.
 /// This element may only be omitted, if lanes end at a junction or have no physical link.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct LaneLink {
     pub predecessor: Vec<PredecessorSuccessor>,
@@ -0,0 +1,19 @@
+use crate::lane::lane_side::LaneSide;
+use crate::lane::Lane;
+
+/// A lane together with its location in the road network, see [`OpenDrive::all_lanes`]
+///
+/// [`OpenDrive::all_lanes`]: crate::core::OpenDrive::all_lanes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LaneRef<'a> {
+    /// ID of the road the lane belongs to
+    pub road_id: &'a str,
+    /// Index of the lane section within [`Lanes::lane_section`]
+    ///
+    /// [`Lanes::lane_section`]: crate::lane::lanes::Lanes::lane_section
+    pub section_index: usize,
+    pub side: LaneSide,
+    /// ID of the lane
+    pub lane_id: i64,
+    pub lane: &'a Lane,
+}
@@ -1,18 +1,30 @@
 use crate::core::additional_data::AdditionalData;
 use crate::lane::center::Center;
+use crate::lane::lane_boundary::LaneBoundary;
+use crate::lane::lane_side::LaneSide;
 use crate::lane::left::Left;
 use crate::lane::right::Right;
+use crate::lane::road_mark::RoadMark;
+use crate::lane::Lane;
+use crate::road::Road;
 use std::borrow::Cow;
+use uom::si::f64::Length;
+use uom::si::length::meter;
 
 /// Lanes may be split into multiple lane sections. Each lane section contains a fixed number of
 /// lanes. Every time the number of lanes changes, a new lane section is required. The distance
 /// between two succeeding lane sections shall not be zero.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LaneSection {
     /// s-coordinate of start position
     pub s: f64,
     /// Lane section element is valid for one side only (left, center, or right), depending on the
     /// child elements.
+    ///
+    /// The lanes of a single side section are independent of the opposite side of the road, which
+    /// is therefore usually omitted and continues to be described by other lane sections. See
+    /// [`LaneSection::is_single_side`].
     pub single_side: Option<bool>,
     pub left: Option<Left>,
     pub center: Center,
@@ -21,6 +33,143 @@ pub struct LaneSection {
 }
 
 impl LaneSection {
+    /// [`LaneSection::s`] as [`Length`], the value is stored in meters
+    #[inline]
+    pub fn s_length(&self) -> Length {
+        Length::new::<meter>(self.s)
+    }
+
+    /// Whether this lane section describes one side of the road only, in which case the missing
+    /// side must not be interpreted as having no lanes
+    #[inline]
+    pub fn is_single_side(&self) -> bool {
+        self.single_side.unwrap_or(false)
+    }
+
+    /// Checks that this lane section contains a `<left>` or `<right>` element and that single side
+    /// sections do not contain both. A single side section is expected to omit the opposite side.
+    pub fn validate(&self) -> Result<(), LaneSectionError> {
+        match (&self.left, &self.right) {
+            (None, None) => Err(LaneSectionError::MissingSide { s: self.s }),
+            (Some(_), Some(_)) if self.is_single_side() => {
+                Err(LaneSectionError::SingleSideWithBothSides { s: self.s })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Iterates over the ids and lanes of the left, center and right lanes of this section
+    pub fn lanes(&self) -> impl Iterator<Item = (i64, &Lane)> {
+        self.lanes_by_side().map(|(_, id, lane)| (id, lane))
+    }
+
+    /// Iterates over the left, center and right lanes of this section together with their side
+    pub fn lanes_by_side(&self) -> impl Iterator<Item = (LaneSide, i64, &Lane)> {
+        let left = self
+            .left
+            .iter()
+            .flat_map(|l| l.lane.iter().map(|l| (LaneSide::Left, l.id, &l.base)));
+        let center = self
+            .center
+            .lane
+            .iter()
+            .map(|l| (LaneSide::Center, l.id, &l.base));
+        let right = self
+            .right
+            .iter()
+            .flat_map(|r| r.lane.iter().map(|l| (LaneSide::Right, l.id, &l.base)));
+        left.chain(center).chain(right)
+    }
+
+    /// Finds the lane with the given id in the left, center or right lanes of this section
+    pub fn lane(&self, id: i64) -> Option<&Lane> {
+        self.lanes()
+            .find_map(|(lane_id, lane)| (lane_id == id).then_some(lane))
+    }
+
+    /// The lane adjacent to the lane `lane_id` on its left side, looking in the direction of the
+    /// reference line. This is the lane with the next greater id, so the left neighbor of the lane
+    /// -1 is the center lane 0. Returns `None` if either lane does not exist.
+    pub fn left_neighbor(&self, lane_id: i64) -> Option<(i64, &Lane)> {
+        self.lane(lane_id)?;
+        let id = lane_id.checked_add(1)?;
+        self.lane(id).map(|lane| (id, lane))
+    }
+
+    /// The lane adjacent to the lane `lane_id` on its right side, looking in the direction of the
+    /// reference line. This is the lane with the next smaller id, so the right neighbor of the
+    /// lane 1 is the center lane 0. Returns `None` if either lane does not exist.
+    pub fn right_neighbor(&self, lane_id: i64) -> Option<(i64, &Lane)> {
+        self.lane(lane_id)?;
+        let id = lane_id.checked_sub(1)?;
+        self.lane(id).map(|lane| (id, lane))
+    }
+
+    /// The inner and outer borders of each lane of this section as polylines in the inertial
+    /// system, from left to right and including the center lane, which lies on the lane offset.
+    /// The section is assumed to belong to `road` and to extend up to the start of the next lane
+    /// section of `road` or the end of the road. It is sampled at equidistant s-coordinates at
+    /// most `step` apart, including its start and end, skipping those outside of the reference
+    /// line. A `step` that is not positive samples only the start and end. Widths accumulate from
+    /// the center lane outwards, with ascending ids on the left and descending ids on the right
+    /// side, see [`Road::cross_section`].
+    pub fn lane_boundaries(&self, road: &Road, step: Length) -> Vec<LaneBoundary> {
+        let start = self.s_length();
+        let end = road
+            .lanes
+            .lane_section
+            .iter()
+            .map(LaneSection::s_length)
+            .find(|s| *s > start)
+            .unwrap_or(road.length);
+        let count = if step.value > 0.0 {
+            (((end - start) / step).value.ceil() as usize).max(1)
+        } else {
+            1
+        };
+
+        let mut boundaries = Vec::<LaneBoundary>::new();
+        for index in 0..=count {
+            let s = start + (end - start) * (index as f64 / count as f64);
+            for slice in road.section_cross_section(self, s) {
+                let position = |t| {
+                    road.inertial_at(s, t).map(|(x, y, z, _)| {
+                        (
+                            Length::new::<meter>(x),
+                            Length::new::<meter>(y),
+                            Length::new::<meter>(z),
+                        )
+                    })
+                };
+                let (Some(inner), Some(outer)) = (position(slice.inner_t), position(slice.outer_t))
+                else {
+                    continue;
+                };
+                let boundary = match boundaries.iter_mut().find(|b| b.lane_id == slice.lane_id) {
+                    Some(boundary) => boundary,
+                    None => {
+                        boundaries.push(LaneBoundary {
+                            lane_id: slice.lane_id,
+                            inner: Vec::with_capacity(count + 1),
+                            outer: Vec::with_capacity(count + 1),
+                        });
+                        boundaries.last_mut().unwrap()
+                    }
+                };
+                boundary.inner.push(inner);
+                boundary.outer.push(outer);
+            }
+        }
+        boundaries
+    }
+
+    /// The road mark of the center lane valid at `ds`, relative to the start of this lane section.
+    /// The center lane has no width, its road mark is the divider between the left and the right
+    /// lanes, such as the centerline.
+    pub fn center_road_mark_at(&self, ds: Length) -> Option<&RoadMark> {
+        self.center.lane0().base.road_mark_at(ds)
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
@@ -52,6 +201,18 @@ impl LaneSection {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum LaneSectionError {
+    #[error("Lane section at s={s} contains neither a `<left>` nor a `<right>` element")]
+    MissingSide { s: f64 },
+    #[error("Single side lane section at s={s} contains a `<left>` and a `<right>` element")]
+    SingleSideWithBothSides { s: f64 },
+}
+
+impl crate::parser::FromXmlStr for LaneSection {
+    const ELEMENT_NAME: &'static str = "laneSection";
+}
+
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for LaneSection
 where
     I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
@@ -74,7 +235,7 @@ where
 
         Ok(Self {
             s: read.attribute("s")?,
-            single_side: read.attribute_opt("singleSide")?,
+            single_side: read.attribute_bool_opt("singleSide")?,
             left,
             center: center.unwrap(),
             right,
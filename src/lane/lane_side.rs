@@ -0,0 +1,9 @@
+/// The group of a lane within a lane section, see [`LaneSection`]
+///
+/// [`LaneSection`]: crate::lane::lane_section::LaneSection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LaneSide {
+    Left,
+    Center,
+    Right,
+}
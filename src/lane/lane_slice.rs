@@ -0,0 +1,24 @@
+use crate::lane::lane_type::LaneType;
+use uom::si::f64::Length;
+
+/// The lateral extent of a single lane at some s-coordinate, see [`Road::cross_section`]
+///
+/// [`Road::cross_section`]: crate::road::Road::cross_section
+#[derive(Debug, Clone, PartialEq)]
+pub struct LaneSlice {
+    /// ID of the lane
+    pub lane_id: i64,
+    /// t-coordinate of the border towards the center lane
+    pub inner_t: Length,
+    /// t-coordinate of the border away from the center lane
+    pub outer_t: Length,
+    /// Type of the lane
+    pub r#type: LaneType,
+}
+
+impl LaneSlice {
+    /// The width of the lane, which is never negative
+    pub fn width(&self) -> Length {
+        (self.outer_t - self.inner_t).abs()
+    }
+}
@@ -1,6 +1,7 @@
 /// The lane type is defined per lane. A lane type defines the main purpose of a lane and its
 /// corresponding traffic rules.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub enum LaneType {
     /// Describes a soft shoulder  at the edge of the roa
@@ -48,6 +49,40 @@ pub enum LaneType {
     HOV,
 }
 
+impl LaneType {
+    /// Whether motor vehicles are meant to drive on lanes of this type. This is the case for
+    /// [`LaneType::Driving`], [`LaneType::Entry`], [`LaneType::Exit`], [`LaneType::OnRamp`],
+    /// [`LaneType::OffRamp`], [`LaneType::ConnectingRamp`], [`LaneType::Bidirectional`] and the
+    /// restricted lanes [`LaneType::Bus`], [`LaneType::Taxi`] and [`LaneType::HOV`].
+    pub fn is_drivable(&self) -> bool {
+        matches!(
+            self,
+            Self::Driving
+                | Self::Entry
+                | Self::Exit
+                | Self::OnRamp
+                | Self::OffRamp
+                | Self::ConnectingRamp
+                | Self::Bidirectional
+                | Self::Bus
+                | Self::Taxi
+                | Self::HOV
+        )
+    }
+
+    /// Whether lanes of this type are meant for pedestrians, which is only the case for
+    /// [`LaneType::Sidewalk`].
+    pub fn is_pedestrian(&self) -> bool {
+        matches!(self, Self::Sidewalk)
+    }
+
+    /// Whether lanes of this type provide parking space, which is only the case for
+    /// [`LaneType::Parking`].
+    pub fn is_parking(&self) -> bool {
+        matches!(self, Self::Parking)
+    }
+}
+
 impl_from_str_as_str!(
     LaneType,
     "shoulder" => Shoulder,
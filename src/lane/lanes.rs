@@ -1,12 +1,16 @@
 use crate::core::additional_data::AdditionalData;
+use crate::lane::effective_section::EffectiveSection;
 use crate::lane::lane_section::LaneSection;
 use crate::lane::offset::Offset;
 use std::borrow::Cow;
+use uom::si::f64::Length;
+use uom::si::length::meter;
 use vec1::Vec1;
 
 /// Contains a series of lane section elements that define the characteristics of the road cross
 /// sections with respect to the lanes along the reference line.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Lanes {
     pub lane_offset: Vec<Offset>,
     pub lane_section: Vec1<LaneSection>,
@@ -14,6 +18,108 @@ pub struct Lanes {
 }
 
 impl Lanes {
+    /// Cleans up common inconsistencies of (machine generated) lane descriptions:
+    ///  - sorts the lane sections and lane offsets in ascending order of s
+    ///  - removes lane sections of zero length, by keeping only the latter of all sections
+    ///    starting at the same s
+    ///  - inserts a zero lane offset at s=0, if the first lane offset starts later or there is none
+    pub fn repair(&mut self) -> LanesRepair {
+        let mut repair = LanesRepair::default();
+
+        if !self.lane_section.windows(2).all(|w| w[0].s <= w[1].s) {
+            self.lane_section.sort_by(|a, b| a.s.total_cmp(&b.s));
+            repair.sorted_sections = true;
+        }
+
+        let len = self.lane_section.len();
+        self.lane_section.dedup_by(|later, earlier| {
+            if later.s == earlier.s {
+                std::mem::swap(later, earlier);
+                true
+            } else {
+                false
+            }
+        });
+        repair.removed_sections = len - self.lane_section.len();
+
+        if !self.lane_offset.windows(2).all(|w| w[0].s <= w[1].s) {
+            self.lane_offset.sort_by(|a, b| a.s.total_cmp(&b.s));
+            repair.sorted_lane_offsets = true;
+        }
+
+        if self.lane_offset.first().is_none_or(|offset| offset.s > 0.0) {
+            self.lane_offset.insert(
+                0,
+                Offset {
+                    a: 0.0,
+                    b: 0.0,
+                    c: 0.0,
+                    d: 0.0,
+                    s: 0.0,
+                },
+            );
+            repair.inserted_lane_offset = true;
+        }
+
+        repair
+    }
+
+    /// The index of the lane section valid at the s-coordinate `s`, which is the last section
+    /// starting at or before `s`. Positions before the first section map to the first section.
+    /// Requires the lane sections to be in ascending order of s, as mandated by the standard.
+    pub fn section_index_at(&self, s: f64) -> usize {
+        self.lane_section
+            .partition_point(|section| section.s <= s)
+            .saturating_sub(1)
+    }
+
+    /// The offset of the center lane from the reference line at the road s-coordinate `s`, both in
+    /// meters like for [`Lanes::section_index_at`]. The cubic polynom of the last `<laneOffset>`
+    /// starting at or before `s` is evaluated, so the earlier element applies up to the start of
+    /// the next one. Without such an element the center lane lies on the reference line.
+    pub fn offset_at(&self, s: f64) -> f64 {
+        self.lane_offset
+            .iter()
+            .rev()
+            .find(|offset| offset.s <= s)
+            .map(|offset| offset.offset_at(Length::new::<meter>(s)).get::<meter>())
+            .unwrap_or_default()
+    }
+
+    /// The full cross-sections of the road, one for each distinct s-coordinate a lane section
+    /// starts at. A single side lane section only replaces the side it describes, the opposite side
+    /// continues from the preceding lane sections. Sections starting at the same s are applied in
+    /// document order. Requires the lane sections to be in ascending order of s.
+    pub fn effective_sections(&self) -> Vec<EffectiveSection<'_>> {
+        let first = self.lane_section.first();
+        let mut current = EffectiveSection {
+            s: first.s,
+            left: None,
+            center: &first.center,
+            right: None,
+        };
+        let mut sections: Vec<EffectiveSection> = Vec::new();
+
+        for section in &self.lane_section {
+            if section.s != current.s {
+                sections.push(current);
+                current.s = section.s;
+            }
+
+            current.center = &section.center;
+            if section.is_single_side() {
+                current.left = section.left.as_ref().or(current.left);
+                current.right = section.right.as_ref().or(current.right);
+            } else {
+                current.left = section.left.as_ref();
+                current.right = section.right.as_ref();
+            }
+        }
+
+        sections.push(current);
+        sections
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
@@ -39,6 +145,26 @@ impl Lanes {
     }
 }
 
+/// The changes applied by [`Lanes::repair`]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LanesRepair {
+    /// Whether the lane sections were not in ascending order of s
+    pub sorted_sections: bool,
+    /// The number of removed lane sections of zero length
+    pub removed_sections: usize,
+    /// Whether the lane offsets were not in ascending order of s
+    pub sorted_lane_offsets: bool,
+    /// Whether a zero lane offset was inserted at s=0
+    pub inserted_lane_offset: bool,
+}
+
+impl LanesRepair {
+    /// Whether nothing had to be changed
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Lanes
 where
     I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
@@ -4,6 +4,7 @@ use std::borrow::Cow;
 /// Lane elements are included in left/center/right elements. Lane elements should represent the
 /// lanes from left to right, that is, with descending ID.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct LeftLane {
     /// ID of the lane
@@ -21,10 +22,10 @@ impl LeftLane {
         self.base.visit_attributes(|attributes| {
             let mut attributes = attributes.to_vec();
             let value = self.id.to_string();
-            attributes.push(xml::attribute::Attribute::new(
-                xml::name::Name::local("id"),
-                &value,
-            ));
+            attributes.insert(
+                0,
+                xml::attribute::Attribute::new(xml::name::Name::local("id"), &value),
+            );
             visitor(Cow::Owned(attributes))
         })
     }
@@ -5,6 +5,7 @@ use uom::si::length::meter;
 /// Stores information about the material of lanes. Each element is valid until a new element is
 /// defined. If multiple elements are defined, they must be listed in ascending order.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Material {
     /// Friction coefficient
     pub friction: f64,
@@ -18,6 +19,9 @@ pub struct Material {
 }
 
 impl Material {
+    /// The friction coefficient assumed where neither a lane nor the road specify one
+    pub const DEFAULT_FRICTION: f64 = 1.0;
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
@@ -11,15 +11,22 @@ use lane_choice::LaneChoice;
 use lane_link::LaneLink;
 use lane_type::LaneType;
 use std::borrow::Cow;
+use uom::si::f64::Length;
+use uom::si::length::meter;
 
 pub mod access;
 pub mod border;
 pub mod center;
 pub mod center_lane;
+pub mod effective_section;
 pub mod height;
+pub mod lane_boundary;
 pub mod lane_choice;
 pub mod lane_link;
+pub mod lane_ref;
 pub mod lane_section;
+pub mod lane_side;
+pub mod lane_slice;
 pub mod lane_type;
 pub mod lanes;
 pub mod left;
@@ -38,6 +45,7 @@ pub mod width;
 /// Lane elements are included in left/center/right elements. Lane elements should represent the
 /// lanes from left to right, that is, with descending ID.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Lane {
     pub link: Option<LaneLink>,
     pub choice: Vec<LaneChoice>,
@@ -56,6 +64,102 @@ pub struct Lane {
 }
 
 impl Lane {
+    /// The `<material>` element that is valid at `ds`, relative to the start of the lane section.
+    /// That is the last element with an `sOffset` not greater than `ds`.
+    pub fn material_at(&self, ds: Length) -> Option<&Material> {
+        self.material.iter().rev().find(|m| m.s_offset <= ds)
+    }
+
+    /// The `<roadMark>` element that is valid at `ds`, relative to the start of the lane section.
+    /// That is the last element with an `sOffset` not greater than `ds`.
+    pub fn road_mark_at(&self, ds: Length) -> Option<&RoadMark> {
+        self.road_mark.iter().rev().find(|m| m.s_offset <= ds)
+    }
+
+    /// The `<width>` element that is valid at `ds`, relative to the start of the lane section.
+    /// That is the last element with an `sOffset` not greater than `ds`.
+    pub fn width_record_at(&self, ds: Length) -> Option<&Width> {
+        self.choice.iter().rev().find_map(|choice| match choice {
+            LaneChoice::Width(width) if width.s_offset <= ds => Some(width),
+            _ => None,
+        })
+    }
+
+    /// The width of this lane at `ds`, relative to the start of the lane section. Returns `None`
+    /// if no `<width>` element is valid at `ds`, as for lanes described by `<border>` elements.
+    pub fn width_at(&self, ds: Length) -> Option<Length> {
+        self.width_record_at(ds).map(|width| width.width_at(ds))
+    }
+
+    /// The `<height>` element that is valid at `ds`, relative to the start of the lane section.
+    /// That is the last element with an `sOffset` not greater than `ds`.
+    pub fn height_record_at(&self, ds: Length) -> Option<&Height> {
+        self.height.iter().rev().find(|h| h.s_offset <= ds)
+    }
+
+    /// The inner and outer height offsets from the road level at `ds`, relative to the start of
+    /// the lane section. The height is constant from one `<height>` element to the next. Returns
+    /// `None` if no `<height>` element is valid at `ds`.
+    pub fn height_at(&self, ds: Length) -> Option<(Length, Length)> {
+        self.height_record_at(ds).map(|h| (h.inner, h.outer))
+    }
+
+    /// The distance of the outer border of this lane from the center lane at `ds`, relative to the
+    /// start of the lane section, given the distance `inner` of its inner border. `<width>`
+    /// elements take precedence over `<border>` elements, as mandated by the standard. Without
+    /// either, the lane has no width.
+    pub fn outer_at(&self, inner: Length, ds: Length) -> Length {
+        let mut width = None;
+        let mut border = None;
+        for choice in &self.choice {
+            match choice {
+                LaneChoice::Width(w) if w.s_offset <= ds => width = Some(w),
+                LaneChoice::Border(b) if b.s_offset <= ds => border = Some(b),
+                _ => {}
+            }
+        }
+        match (width, border) {
+            (Some(width), _) => inner + width.width_at(ds),
+            (None, Some(border)) => border.border_at(ds),
+            (None, None) => inner,
+        }
+    }
+
+    /// Roots of a `<width>` element that lie at most this far, in meters, beyond the end of the
+    /// element are still considered by [`Lane::ends_within`], as rounding can push a root that lies
+    /// exactly at the end of the element slightly beyond it
+    pub const ENDS_WITHIN_TOLERANCE: f64 = 1e-9;
+
+    /// The `ds`, relative to the start of the lane section, at which the width of this lane first
+    /// drops to zero within a lane section of length `section_length`, as it does at the end of a
+    /// lane drop. Only `<width>` elements are evaluated, each up to the start of the next one.
+    /// Only roots the width decreases to, from the element itself or the preceding one, end the
+    /// lane, a lane widening from zero does not end at its start.
+    pub fn ends_within(&self, section_length: Length) -> Option<Length> {
+        let widths = self
+            .choice
+            .iter()
+            .filter_map(|choice| match choice {
+                LaneChoice::Width(width) => Some(width),
+                LaneChoice::Border(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        widths.iter().enumerate().find_map(|(index, width)| {
+            let end = widths
+                .get(index + 1)
+                .map(|next| next.s_offset)
+                .unwrap_or(section_length);
+            let length = (end - width.s_offset).get::<meter>();
+            width
+                .roots()
+                .into_iter()
+                .filter(|root| (0.0..=length + Self::ENDS_WITHIN_TOLERANCE).contains(root))
+                .find(|root| width.decreases_to_zero_at(*root))
+                .map(|root| width.s_offset + Length::new::<meter>(root.min(length)))
+        })
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
@@ -64,8 +168,8 @@ impl Lane {
     ) -> xml::writer::Result<()> {
         visit_attributes_flatten!(
             visitor,
-            "level" => self.level.map(|v| v.to_string()).as_deref(),
             "type" => Some(self.r#type.as_str()),
+            "level" => self.level.map(|v| v.to_string()).as_deref(),
         )
     }
 
@@ -152,7 +256,7 @@ where
             access,
             height,
             rule,
-            level: read.attribute_opt("level")?,
+            level: read.attribute_bool_opt("level")?,
             r#type: read.attribute("type")?,
             additional_data,
         })
@@ -1,7 +1,10 @@
 use std::borrow::Cow;
+use uom::si::f64::Length;
+use uom::si::length::meter;
 
 /// A lane offset may be used to shift the center lane away from the road reference line.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Offset {
     /// Polynom parameter a, offset at @s (ds=0)
     pub a: f64,
@@ -16,19 +19,31 @@ pub struct Offset {
 }
 
 impl Offset {
+    /// [`Offset::s`] as [`Length`], the value is stored in meters
+    #[inline]
+    pub fn s_length(&self) -> Length {
+        Length::new::<meter>(self.s)
+    }
+
+    /// The offset of the center lane from the reference line at the road s-coordinate `s`
+    pub fn offset_at(&self, s: Length) -> Length {
+        let ds = (s - self.s_length()).get::<meter>();
+        Length::new::<meter>(self.a + ds * (self.b + ds * (self.c + ds * self.d)))
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
         ) -> xml::writer::Result<()>,
     ) -> xml::writer::Result<()> {
-        visit_attributes!(
+        visit_scientific_attributes!(
             visitor,
-            "a" => &self.a.to_scientific_string(),
-            "b" => &self.b.to_scientific_string(),
-            "c" => &self.c.to_scientific_string(),
-            "d" => &self.d.to_scientific_string(),
-            "s" => &self.s.to_scientific_string(),
+            "s" => self.s,
+            "a" => self.a,
+            "b" => self.b,
+            "c" => self.c,
+            "d" => self.d,
         )
     }
 
@@ -7,6 +7,7 @@ use vec1::Vec1;
 /// section are grouped into left, center, and right lanes. Each lane section shall contain one
 /// `<center>` element and at least one `<right>` or `<left>` element.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Right {
     pub lane: Vec1<RightLane>,
     pub additional_data: AdditionalData,
@@ -1,5 +1,6 @@
 /// The known keywords for the road mark color information
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub enum Color {
     /// equivalent to [`Color::White`]
@@ -11,6 +11,7 @@ use vec1::Vec1;
 /// within the `<explicit>` element.
 // The `<explicit>` element should specifically be used for measurement data.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Explicit {
     pub line: Vec1<ExplicitLine>,
     pub additional_data: AdditionalData,
@@ -4,6 +4,7 @@ use uom::si::f64::Length;
 use uom::si::length::meter;
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExplicitLine {
     /// Length of the visible line
     pub length: Length,
@@ -23,6 +23,7 @@ pub mod weight;
 /// Defines the style of the line at the outer border of a lane. The style of the center line that
 /// separates left and right lanes is determined by the road mark element for the center lane.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RoadMark {
     pub sway: Vec<Sway>,
     pub r#type: Option<Type>,
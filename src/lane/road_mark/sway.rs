@@ -4,6 +4,7 @@ use std::borrow::Cow;
 /// defines an offset. The sway offset is relative to the nominal reference position of the lane
 /// marking, meaning the lane border.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Sway {
     /// Polynom parameter a, sway value at @s (ds=0)
     a: f64,
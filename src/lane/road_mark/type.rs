@@ -8,6 +8,7 @@ use vec1::Vec1;
 /// Each type definition shall contain one or more line definitions with additional information
 /// about the lines that the road mark is composed of.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Type {
     pub line: Vec1<TypeLine>,
     /// Name of the road mark type. May be chosen freely.
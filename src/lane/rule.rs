@@ -5,6 +5,7 @@ use uom::si::length::meter;
 /// Used to add rules that are not covered by any of the other lane attributes that are described in
 /// this specification.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rule {
     /// s-coordinate of start position, relative to the position of the preceding `<laneSection>`
     /// element
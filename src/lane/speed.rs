@@ -6,6 +6,7 @@ use uom::si::length::meter;
 /// Defines the maximum allowed speed on a given lane. Each element is valid in direction of the
 /// increasing s-coordinate until a new element is defined.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Speed {
     /// Maximum allowed speed. If the attribute unit is not specified, m/s is used as default.
     pub max: f64,
@@ -8,6 +8,7 @@ use uom::si::length::meter;
 /// side-by-side. A line definition is valid for a given length of the lane and will be repeated
 /// automatically.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TypeLine {
     /// Line color. If given, this attribute supersedes the definition in the `<roadMark>` element.
     pub color: Option<Color>,
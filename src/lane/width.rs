@@ -9,6 +9,7 @@ use uom::si::length::meter;
 /// application must use the information from the `<width>` elements.
 /// In ASAM OpenDRIVE, lane width is described by the `<width>` element within the `<lane>` element.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Width {
     /// Polynom parameter a, width at @s (ds=0)
     // https://github.com/RReverser/serde-xml-rs/issues/137
@@ -29,19 +30,99 @@ pub struct Width {
 }
 
 impl Width {
+    /// The width at `ds`, relative to the start of the lane section
+    pub fn width_at(&self, ds: Length) -> Length {
+        let ds = (ds - self.s_offset).get::<meter>();
+        Length::new::<meter>(self.a + ds * (self.b + ds * (self.c + ds * self.d)))
+    }
+
+    /// The real roots of the width polynomial, relative to [`Width::s_offset`] and in ascending
+    /// order
+    pub(crate) fn roots(&self) -> Vec<f64> {
+        const EPSILON: f64 = 1e-12;
+        let (a, b, c, d) = (self.a, self.b, self.c, self.d);
+
+        let mut roots = if d.abs() > EPSILON {
+            // depressed cubic t^3 + pt + q = 0 with x = t - c / (3d)
+            let (b2, c2, d2) = (c / d, b / d, a / d);
+            let p = c2 - b2 * b2 / 3.0;
+            let q = 2.0 * b2 * b2 * b2 / 27.0 - b2 * c2 / 3.0 + d2;
+            let shift = -b2 / 3.0;
+            let discriminant = q * q / 4.0 + p * p * p / 27.0;
+            if discriminant > EPSILON {
+                let sqrt = discriminant.sqrt();
+                vec![(-q / 2.0 + sqrt).cbrt() + (-q / 2.0 - sqrt).cbrt() + shift]
+            } else if p.abs() <= EPSILON {
+                vec![shift]
+            } else if discriminant.abs() <= EPSILON {
+                let t = 3.0 * q / p;
+                vec![t + shift, -t / 2.0 + shift]
+            } else {
+                let r = 2.0 * (-p / 3.0).sqrt();
+                let phi = (3.0 * q / (p * r)).clamp(-1.0, 1.0).acos() / 3.0;
+                (0..3)
+                    .map(|k| r * (phi - 2.0 * std::f64::consts::PI * k as f64 / 3.0).cos() + shift)
+                    .collect()
+            }
+        } else if c.abs() > EPSILON {
+            let discriminant = b * b - 4.0 * c * a;
+            if discriminant < -EPSILON {
+                Vec::new()
+            } else {
+                // a double root may come out slightly negative
+                let sqrt = discriminant.max(0.0).sqrt();
+                vec![(-b - sqrt) / (2.0 * c), (-b + sqrt) / (2.0 * c)]
+            }
+        } else if b.abs() > EPSILON {
+            vec![-a / b]
+        } else if a.abs() <= EPSILON {
+            vec![0.0]
+        } else {
+            Vec::new()
+        };
+
+        roots.sort_by(f64::total_cmp);
+        roots
+    }
+
+    /// Whether the width decreases to zero at its root `ds`, relative to [`Width::s_offset`], that
+    /// is whether it is positive right before the root, as told by the sign and order of the first
+    /// derivative that does not vanish there. The width before the start of the element belongs to
+    /// the preceding element, so a root at the start only requires the width not to rise after it.
+    pub(crate) fn decreases_to_zero_at(&self, ds: f64) -> bool {
+        const EPSILON: f64 = 1e-12;
+        let derivatives = [
+            self.b + ds * (2.0 * self.c + ds * 3.0 * self.d),
+            2.0 * self.c + ds * 6.0 * self.d,
+            6.0 * self.d,
+        ];
+        let Some((order, derivative)) = (1..)
+            .zip(derivatives)
+            .find(|(_, derivative)| derivative.abs() > EPSILON)
+        else {
+            // the width is zero throughout
+            return true;
+        };
+        if ds > 0.0 {
+            (derivative < 0.0) == (order % 2 == 1)
+        } else {
+            derivative < 0.0
+        }
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
         ) -> xml::writer::Result<()>,
     ) -> xml::writer::Result<()> {
-        visit_attributes!(
+        visit_scientific_attributes!(
             visitor,
-            "a" => &self.a.to_scientific_string(),
-            "b" => &self.b.to_scientific_string(),
-            "c" => &self.c.to_scientific_string(),
-            "d" => &self.d.to_scientific_string(),
-            "sOffset" => &self.s_offset.value.to_scientific_string(),
+            "sOffset" => self.s_offset.value,
+            "a" => self.a,
+            "b" => self.b,
+            "c" => self.c,
+            "d" => self.d,
         )
     }
 
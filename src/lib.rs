@@ -33,6 +33,8 @@
 pub mod parser;
 pub mod writer;
 
+pub mod approx_eq;
+
 pub mod core;
 pub mod junction;
 pub mod lane;
@@ -44,8 +46,12 @@ pub mod signal;
 #[cfg(feature = "fuzzing")]
 pub mod fuzzing;
 
+#[cfg(feature = "python")]
+pub mod python;
+
 #[cfg(test)]
 mod tests {
+    use crate::core::additional_data_choice::AdditionalDataChoice;
     use crate::core::OpenDrive;
 
     #[test]
@@ -92,6 +98,53 @@ mod tests {
         let _ = OpenDrive::from_xml_str(source).unwrap();
     }
 
+    #[test]
+    pub fn test_xml_lane_choice_lanes() {
+        use crate::lane::lane_side::LaneSide;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE> 
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <link>
+                        </link>
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lateralProfile>
+                        </lateralProfile>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="1337" type="driving" level="false">
+                                        <border sOffset="0.0000000000000000e+00" a="3.5699999999999998e+00" b="0.0000000000000000e+00" c="0.0000000000000000e+00" d="0.0000000000000000e+00"/>
+                                    </lane>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+
+        let lanes = drive.lanes().collect::<Vec<_>>();
+        assert_eq!(1, lanes.len());
+        assert_eq!(
+            ("1", 0, LaneSide::Center, 1337),
+            (
+                lanes[0].road_id,
+                lanes[0].section_index,
+                lanes[0].side,
+                lanes[0].lane_id
+            )
+        );
+        assert_eq!(1, lanes[0].lane.choice.len());
+    }
+
     #[test]
     pub fn test_xml_events_center_lane() {
         let source = r#"
@@ -123,4 +176,6056 @@ mod tests {
             "#;
         let _ = OpenDrive::from_xml_str(source).unwrap();
     }
+
+    #[test]
+    pub fn test_virtual_junction_missing_main_road() {
+        use crate::junction::JunctionError;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+01" id="2" junction="7">
+                        <link>
+                            <predecessor elementType="road" elementId="1" elementS="2.5000000000000000e+01" elementDir="+"/>
+                        </link>
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+01">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                    <junction id="7" type="virtual" sStart="2.0000000000000000e+01" sEnd="4.0000000000000000e+01" orientation="+">
+                        <connection id="0" incomingRoad="1" connectingRoad="2" contactPoint="start"/>
+                    </junction>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+
+        let link = drive.road[0].link.as_ref().unwrap();
+        assert!(link.predecessor.as_ref().unwrap().is_virtual_connection());
+
+        let junction = &drive.junction[0];
+        assert!(junction.is_virtual());
+        assert_eq!(
+            junction.validate(),
+            Err(JunctionError::MissingVirtualAttribute {
+                junction: "7".to_string(),
+                attribute: "mainRoad",
+            })
+        );
+    }
+
+    #[test]
+    pub fn test_object_shape_validation() {
+        use crate::object::ShapeError;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                        <objects>
+                            <object id="angular" s="1.0e+01" t="2.0e+00" zOffset="0.0e+00" width="1.0e+00" length="2.0e+00" height="1.0e+00"/>
+                            <object id="circular" s="2.0e+01" t="2.0e+00" zOffset="0.0e+00" radius="5.0e-01" height="3.0e+00"/>
+                            <object id="mixed" s="3.0e+01" t="2.0e+00" zOffset="0.0e+00" width="1.0e+00" radius="5.0e-01"/>
+                        </objects>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let objects = &drive.road[0].objects.as_ref().unwrap().object;
+
+        assert_eq!(Ok(()), objects[0].validate_shape());
+        assert_eq!(Ok(()), objects[1].validate_shape());
+        assert_eq!(
+            Err(ShapeError::AngularAndCircular {
+                object: "mixed".to_string()
+            }),
+            objects[2].validate_shape()
+        );
+    }
+
+    #[test]
+    pub fn test_objects_and_signals_sorted_by_s() {
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                        <objects>
+                            <object id="o3" s="3.0e+01" t="0.0e+00" zOffset="0.0e+00"/>
+                            <object id="o1" s="1.0e+01" t="0.0e+00" zOffset="0.0e+00"/>
+                            <object id="o2" s="2.0e+01" t="0.0e+00" zOffset="0.0e+00"/>
+                        </objects>
+                        <signals>
+                            <signal id="s2" s="5.0e+01" t="0.0e+00" zOffset="0.0e+00" dynamic="no" orientation="+" type="206" subtype="-1"/>
+                            <signal id="s1" s="5.0e+00" t="0.0e+00" zOffset="0.0e+00" dynamic="no" orientation="+" type="206" subtype="-1"/>
+                        </signals>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let road = &drive.road[0];
+
+        let objects = road.objects.as_ref().unwrap();
+        let ids = objects
+            .iter_sorted_by_s()
+            .map(|o| o.id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["o1", "o2", "o3"], ids);
+        assert_eq!("o3", objects.object[0].id);
+
+        let signals = road.signals.as_ref().unwrap();
+        let ids = signals
+            .iter_sorted_by_s()
+            .map(|s| s.id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["s1", "s2"], ids);
+    }
+
+    #[test]
+    pub fn test_max_depth_exceeded() {
+        use crate::parser::{Error, ParseOptions};
+
+        let depth = 10_000;
+        let user_data = format!(
+            r#"<userData code="nested">{}{}</userData>"#,
+            "<a>".repeat(depth),
+            "</a>".repeat(depth),
+        );
+        let source = format!(
+            r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                        {user_data}
+                    </road>
+                </OpenDRIVE>
+            "#
+        );
+
+        let error = OpenDrive::from_xml_str(&source).unwrap_err();
+        assert!(matches!(
+            *error,
+            Error::MaxDepthExceeded {
+                max_depth: ParseOptions::DEFAULT_MAX_DEPTH,
+                ..
+            }
+        ));
+
+        let shallow = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let options = ParseOptions::default().with_max_depth(Some(5));
+        let error = OpenDrive::from_xml_str_with_options(shallow, options).unwrap_err();
+        assert!(matches!(
+            *error,
+            Error::MaxDepthExceeded { max_depth: 5, .. }
+        ));
+
+        let options = ParseOptions::default().with_max_depth(Some(6));
+        assert!(OpenDrive::from_xml_str_with_options(shallow, options).is_ok());
+    }
+
+    #[test]
+    pub fn test_lane_type_predicates() {
+        use crate::lane::lane_type::LaneType;
+
+        assert!(LaneType::Driving.is_drivable());
+        assert!(LaneType::OnRamp.is_drivable());
+        assert!(LaneType::Bidirectional.is_drivable());
+        assert!(!LaneType::Sidewalk.is_drivable());
+        assert!(!LaneType::Shoulder.is_drivable());
+
+        assert!(LaneType::Sidewalk.is_pedestrian());
+        assert!(!LaneType::Driving.is_pedestrian());
+
+        assert!(LaneType::Parking.is_parking());
+        assert!(!LaneType::Stop.is_parking());
+    }
+
+    #[test]
+    pub fn test_road_fragment_round_trip() {
+        use crate::parser::FromXmlStr;
+        use crate::road::Road;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let road = &drive.road[0];
+
+        let fragment = road.to_xml_string().unwrap();
+        assert!(fragment.starts_with("<road "));
+        assert!(!fragment.contains("OpenDRIVE"));
+
+        assert_eq!(road, &Road::from_xml_str(&fragment).unwrap());
+    }
+
+    #[test]
+    pub fn test_element_fragments() {
+        use crate::junction::Junction;
+        use crate::parser::FromXmlStr;
+        use crate::signal::Signal;
+
+        let signal = Signal::from_xml_str(
+            r#"<signal id="5" s="1.2e+01" t="-3.0e+00" zOffset="2.0e+00" dynamic="yes" orientation="-" type="1000001" subtype="-1" country="DE"/>"#,
+        )
+        .unwrap();
+        assert_eq!("5", signal.id);
+        assert!(signal.dynamic);
+        assert_eq!(12.0, signal.s.value);
+
+        let junction = Junction::from_xml_str(
+            r#"
+                <junction id="3" name="T">
+                    <connection id="0" incomingRoad="1" connectingRoad="10" contactPoint="start"/>
+                    <connection id="1" incomingRoad="2" connectingRoad="11" contactPoint="end"/>
+                </junction>
+            "#,
+        )
+        .unwrap();
+        assert_eq!("3", junction.id);
+        assert_eq!(2, junction.connection.len());
+        assert_eq!(
+            Some("11"),
+            junction.connection[1].connecting_road.as_deref()
+        );
+
+        assert!(Junction::from_xml_str(r#"<road id="1"/>"#).is_err());
+    }
+
+    #[test]
+    pub fn test_param_poly_3_arc_length_table() {
+        use crate::road::geometry::param_poly_3::ParamPoly3;
+        use crate::road::geometry::param_poly_3_p_range::ParamPoly3pRange;
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        // u = p, v = p - p², symmetric around p = 0.5
+        let poly = ParamPoly3 {
+            a_u: 0.0,
+            a_v: 0.0,
+            b_u: 1.0,
+            b_v: 1.0,
+            c_u: 0.0,
+            c_v: -1.0,
+            d_u: 0.0,
+            d_v: 0.0,
+            p_range: ParamPoly3pRange::Normalized,
+        };
+        let lut = poly.build_arclength_table(Length::new::<meter>(1.2), 1000);
+
+        // closed form arc length of the parabola
+        let expected = 2.0_f64.sqrt() / 2.0 + 1.0_f64.asinh() / 2.0;
+        assert!((lut.total_length() - expected).abs() < 1e-9);
+
+        let (u, v) = poly.point_at_arclength(&lut, lut.total_length() / 2.0);
+        assert!((u - 0.5).abs() < 1e-6);
+        assert!((v - 0.25).abs() < 1e-6);
+
+        let (u, v) = poly.point_at_arclength(&lut, lut.total_length() * 2.0);
+        assert_eq!((1.0, 0.0), (u, v));
+    }
+
+    #[test]
+    pub fn test_effective_friction() {
+        use crate::lane::material::Material;
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false">
+                                        <width sOffset="0.0e+00" a="3.5e+00" b="0.0e+00" c="0.0e+00" d="0.0e+00"/>
+                                        <material sOffset="1.0e+01" friction="6.0e-01"/>
+                                    </lane>
+                                    <lane id="-2" type="shoulder" level="false">
+                                        <width sOffset="0.0e+00" a="1.5e+00" b="0.0e+00" c="0.0e+00" d="0.0e+00"/>
+                                    </lane>
+                                </right>
+                            </laneSection>
+                        </lanes>
+                        <surface>
+                            <CRG file="friction.crg" sStart="6.0e+01" sEnd="1.0e+02" orientation="same" mode="genuine" purpose="friction"/>
+                        </surface>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let road = &drive.road[0];
+
+        let at = |lane_id, s| road.effective_friction(0, lane_id, Length::new::<meter>(s));
+        assert_eq!(0.6, at(-1, 50.0));
+        // before its material starts and without any, the lane inherits from the road, whose
+        // friction surface lies in an external file
+        assert_eq!(Material::DEFAULT_FRICTION, at(-1, 5.0));
+        assert_eq!(Material::DEFAULT_FRICTION, at(-2, 50.0));
+        assert_eq!(Material::DEFAULT_FRICTION, at(-2, 80.0));
+        // lanes and lane sections that do not exist fall back to the default as well
+        assert_eq!(Material::DEFAULT_FRICTION, at(-3, 50.0));
+        assert_eq!(
+            Material::DEFAULT_FRICTION,
+            road.effective_friction(1, -1, Length::new::<meter>(0.0))
+        );
+    }
+
+    #[test]
+    pub fn test_duplicate_road_ids() {
+        use crate::parser::{Error, ParseOptions, Warning};
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+
+        let (drive, warnings) =
+            OpenDrive::from_xml_str_with_options(source, ParseOptions::default()).unwrap();
+        assert_eq!(2, drive.road.len());
+        assert!(warnings.is_empty());
+
+        let options = ParseOptions::default().with_check_duplicate_ids(true);
+        let (_, warnings) = OpenDrive::from_xml_str_with_options(source, options).unwrap();
+        let duplicate = Warning::DuplicateId {
+            element: "road",
+            id: "1".to_string(),
+        };
+        assert_eq!(vec![duplicate.clone()], warnings);
+
+        let error =
+            OpenDrive::from_xml_str_with_options(source, options.with_strict(true)).unwrap_err();
+        assert!(matches!(*error, Error::Rejected(w) if w == duplicate));
+    }
+
+    #[test]
+    pub fn test_section_index_at() {
+        let sections = (0..50)
+            .map(|i| {
+                format!(
+                    r#"<laneSection s="{}"><center><lane id="0" type="none"/></center></laneSection>"#,
+                    i as f64 * 2.0
+                )
+            })
+            .collect::<String>();
+        let source = format!(
+            r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            {sections}
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#
+        );
+
+        let drive = OpenDrive::from_xml_str(&source).unwrap();
+        let lanes = &drive.road[0].lanes;
+        assert_eq!(50, lanes.lane_section.len());
+
+        assert_eq!(0, lanes.section_index_at(-1.0));
+        assert_eq!(0, lanes.section_index_at(0.0));
+        assert_eq!(0, lanes.section_index_at(1.999));
+        assert_eq!(1, lanes.section_index_at(2.0));
+        assert_eq!(24, lanes.section_index_at(49.0));
+        assert_eq!(49, lanes.section_index_at(98.0));
+        assert_eq!(49, lanes.section_index_at(1000.0));
+    }
+
+    #[test]
+    pub fn test_plain_si_getters() {
+        use uom::si::angle::radian;
+        use uom::si::length::meter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="7.8539816339744828e-01" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                        <objects>
+                            <object id="1" s="1.5e+01" t="-2.0e+00" zOffset="0.0e+00" hdg="1.0e+00"/>
+                        </objects>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let road = &drive.road[0];
+
+        let geometry = &road.plan_view.geometry[0];
+        assert_eq!(geometry.hdg.get::<radian>(), geometry.hdg_rad());
+        assert_eq!(std::f64::consts::FRAC_PI_4, geometry.hdg_rad());
+        assert_eq!(geometry.length.get::<meter>(), geometry.length_m());
+        assert_eq!(100.0, road.length_m());
+
+        let object = &road.objects.as_ref().unwrap().object[0];
+        assert_eq!(15.0, object.s_m());
+        assert_eq!(-2.0, object.t_m());
+        assert_eq!(Some(1.0), object.hdg_rad());
+    }
+
+    #[test]
+    pub fn test_road_surface_crg_list() {
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                        <surface>
+                            <CRG file="first.crg" sStart="0.0e+00" sEnd="4.0e+01" orientation="same" mode="attached" purpose="elevation"/>
+                            <CRG file="second.crg" sStart="6.0e+01" sEnd="1.0e+02" orientation="same" mode="genuine" purpose="friction"/>
+                        </surface>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let surface = drive.road[0].surface.as_ref().unwrap();
+        assert_eq!(2, surface.crg.len());
+
+        let file_at = |s| {
+            surface
+                .crg_at(Length::new::<meter>(s))
+                .map(|c| c.file.as_str())
+        };
+        assert_eq!(Some("first.crg"), file_at(0.0));
+        assert_eq!(None, file_at(40.0));
+        assert_eq!(None, file_at(50.0));
+        assert_eq!(Some("second.crg"), file_at(75.0));
+
+        let written = drive.to_xml_string().unwrap();
+        assert_eq!(drive, OpenDrive::from_xml_str(&written).unwrap());
+    }
+
+    #[test]
+    pub fn test_lanes_repair() {
+        use crate::lane::lanes::LanesRepair;
+
+        let sections = [(50.0, "a"), (0.0, "b"), (20.0, "c"), (50.0, "d"), (0.0, "e")]
+            .into_iter()
+            .map(|(s, name)| {
+                format!(
+                    r#"<laneSection s="{s}"><center><lane id="0" type="none"/></center><userData code="{name}"/></laneSection>"#,
+                )
+            })
+            .collect::<String>();
+        let source = format!(
+            r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneOffset s="1.0e+01" a="1.0e+00" b="0.0e+00" c="0.0e+00" d="0.0e+00"/>
+                            {sections}
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#
+        );
+        let mut drive = OpenDrive::from_xml_str(&source).unwrap();
+        let lanes = &mut drive.road[0].lanes;
+
+        assert_eq!(
+            LanesRepair {
+                sorted_sections: true,
+                removed_sections: 2,
+                sorted_lane_offsets: false,
+                inserted_lane_offset: true,
+            },
+            lanes.repair()
+        );
+
+        let sections = lanes
+            .lane_section
+            .iter()
+            .map(|s| {
+                (
+                    s.s,
+                    s.additional_data.user_data().next().unwrap().code.as_str(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(vec![(0.0, "e"), (20.0, "c"), (50.0, "d")], sections);
+        assert_eq!(
+            vec![0.0, 10.0],
+            lanes.lane_offset.iter().map(|o| o.s).collect::<Vec<_>>()
+        );
+
+        assert!(lanes.repair().is_empty());
+    }
+
+    #[test]
+    pub fn test_road_curvature_at() {
+        use uom::si::curvature::radian_per_meter;
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="5.0e+01" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0" x="0.0" y="0.0" hdg="0.0" length="10.0"><line/></geometry>
+                            <geometry s="10.0" x="10.0" y="0.0" hdg="0.0" length="10.0"><arc curvature="0.1"/></geometry>
+                            <geometry s="20.0" x="15.0" y="5.0" hdg="1.0" length="20.0"><spiral curvStart="0.1" curvEnd="-0.3"/></geometry>
+                            <geometry s="40.0" x="20.0" y="20.0" hdg="1.0" length="10.0"><paramPoly3 aU="0.0" bU="1.0" cU="0.0" dU="0.0" aV="0.0" bV="0.0" cV="0.5" dV="0.0" pRange="arcLength"/></geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let road = &drive.road[0];
+        let curvature = |s: f64| {
+            road.curvature_at(Length::new::<meter>(s))
+                .get::<radian_per_meter>()
+        };
+
+        assert_eq!(0.0, curvature(0.0));
+        assert_eq!(0.0, curvature(9.9));
+        assert_eq!(0.1, curvature(10.0));
+        assert_eq!(0.1, curvature(15.0));
+        assert!((curvature(20.0) - 0.1).abs() < 1e-12);
+        assert!((curvature(25.0) - 0.0).abs() < 1e-12);
+        assert!((curvature(30.0) - -0.1).abs() < 1e-12);
+        assert!((curvature(39.999) - -0.3).abs() < 1e-3);
+        // v = 0.5 u², so the curvature is 1 / (1 + u²)^1.5
+        assert!((curvature(40.0) - 1.0).abs() < 1e-12);
+        assert!((curvature(41.0) - 2f64.powf(-1.5)).abs() < 1e-12);
+    }
+
+    #[test]
+    pub fn test_statistics() {
+        use crate::lane::lane_type::LaneType;
+        use uom::si::length::meter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                        <objects>
+                            <object id="o1" s="1.0e+01" t="0.0e+00" zOffset="0.0e+00"/>
+                            <object id="o2" s="2.0e+01" t="0.0e+00" zOffset="0.0e+00"/>
+                        </objects>
+                        <signals>
+                            <signal id="s1" s="5.0e+00" t="0.0e+00" zOffset="0.0e+00" dynamic="no" orientation="+" type="206" subtype="-1"/>
+                        </signals>
+                    </road>
+                    <road rule="RHT" name="" length="5.0e+01" id="2" junction="-1">
+                        <planView>
+                            <geometry s="0.0e+00" x="0.0e+00" y="1.0e+01" hdg="0.0e+00" length="5.0e+01">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                    <lane id="-2" type="sidewalk" level="false"/>
+                                </right>
+                            </laneSection>
+                            <laneSection s="2.5e+01">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                </right>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let statistics = drive.statistics();
+
+        assert_eq!(2, statistics.roads);
+        assert_eq!(150.0, statistics.total_length.get::<meter>());
+        assert_eq!(0, statistics.junctions);
+        assert_eq!(2, statistics.objects);
+        assert_eq!(1, statistics.signals);
+        assert_eq!(6, statistics.lanes());
+        assert_eq!(Some(&3), statistics.lanes_by_type.get(&LaneType::None));
+        assert_eq!(Some(&2), statistics.lanes_by_type.get(&LaneType::Driving));
+        assert_eq!(Some(&1), statistics.lanes_by_type.get(&LaneType::Sidewalk));
+    }
+
+    #[test]
+    pub fn test_additional_data_order_round_trip() {
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                        <include file="a.xodr"/>
+                        <userData code="b"/>
+                        <include file="c.xodr"/>
+                        <userData code="d" value="e"/>
+                        <userData code="f"/>
+                        <include file="g.xodr"/>
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let order = |drive: &OpenDrive| {
+            drive
+                .header
+                .additional_data
+                .choice
+                .iter()
+                .map(|choice| match choice {
+                    AdditionalDataChoice::Include(include) => include.file.clone(),
+                    AdditionalDataChoice::UserData(user_data) => user_data.code.clone(),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            vec!["a.xodr", "b", "c.xodr", "d", "f", "g.xodr"],
+            order(&drive)
+        );
+        assert_eq!(3, drive.header.additional_data.include().count());
+        assert_eq!(3, drive.header.additional_data.user_data().count());
+
+        let written = drive.to_xml_string().unwrap();
+        let positions = ["a.xodr", "\"b\"", "c.xodr", "\"d\"", "\"f\"", "g.xodr"]
+            .map(|needle| written.find(needle).unwrap());
+        assert!(positions.windows(2).all(|w| w[0] < w[1]));
+
+        let read_back = OpenDrive::from_xml_str(&written).unwrap();
+        assert_eq!(drive, read_back);
+        assert_eq!(written, read_back.to_xml_string().unwrap());
+    }
+
+    #[test]
+    pub fn test_diff() {
+        use crate::core::diff::DiffEntry;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let before = OpenDrive::from_xml_str(source).unwrap();
+
+        let mut after = before.clone();
+        after.road[0].length *= 2.0;
+        after.road[0].lanes.lane_section[0].single_side = Some(true);
+        let mut added = after.road[0].clone();
+        added.id = "2".to_string();
+        after.road.push(added);
+
+        assert!(before.diff(&before).is_empty());
+        assert_eq!(
+            vec![
+                DiffEntry::Changed {
+                    element: "road",
+                    id: "1".to_string(),
+                    field: "length",
+                    before: "100".to_string(),
+                    after: "200".to_string(),
+                },
+                DiffEntry::Added {
+                    element: "road",
+                    id: "2".to_string(),
+                },
+            ],
+            before.diff(&after)
+        );
+        assert_eq!(
+            vec![
+                DiffEntry::Changed {
+                    element: "road",
+                    id: "1".to_string(),
+                    field: "length",
+                    before: "200".to_string(),
+                    after: "100".to_string(),
+                },
+                DiffEntry::Removed {
+                    element: "road",
+                    id: "2".to_string(),
+                },
+            ],
+            after.diff(&before)
+        );
+
+        after.road.pop();
+        after.road[0].length = before.road[0].length;
+        assert_eq!(
+            vec![DiffEntry::Modified {
+                element: "road",
+                id: "1".to_string(),
+            }],
+            before.diff(&after)
+        );
+        assert_eq!("road 1 modified", before.diff(&after)[0].to_string());
+    }
+
+    #[test]
+    pub fn test_approx_eq_after_round_trip() {
+        use crate::approx_eq::ApproxEq;
+        use crate::road::geometry::geometry_type::GeometryType;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0" x="0.1" y="0.2" hdg="0.3" length="33.333333333333336"><arc curvature="0.01"/></geometry>
+                            <geometry s="33.333333333333336" x="-12.7" y="4.1" hdg="1.0471975511965976" length="66.66666666666667">
+                                <paramPoly3 aU="0.0" bU="1.0" cU="-0.001" dU="1.1e-6" aV="0.0" bV="0.0" cV="0.05" dV="-0.0003" pRange="arcLength"/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                        <elevationProfile>
+                            <elevation s="0.1" a="0.3" b="-0.7" c="1e-7" d="3.3333333333333335e-9"/>
+                        </elevationProfile>
+                        <lateralProfile>
+                            <superelevation s="0.1" a="0.2" b="0.3" c="0.4" d="0.5"/>
+                        </lateralProfile>
+                    </road>
+                </OpenDRIVE>
+            "#;
+
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let written = drive.to_xml_string().unwrap();
+        let read_back = OpenDrive::from_xml_str(&written).unwrap();
+
+        let (before, after) = (&drive.road[0], &read_back.road[0]);
+        assert!(before.plan_view.approx_eq(&after.plan_view, 1e-12));
+        assert!(before
+            .elevation_profile
+            .approx_eq(&after.elevation_profile, 1e-12));
+        assert!(before
+            .lateral_profile
+            .approx_eq(&after.lateral_profile, 1e-12));
+        assert!(before
+            .lanes
+            .lane_offset
+            .approx_eq(&after.lanes.lane_offset, 1e-12));
+
+        // a perturbation of the last bit is not equal, but approximately equal
+        let mut perturbed = after.plan_view.clone();
+        perturbed.geometry[1].x.value = f64::from_bits(perturbed.geometry[1].x.value.to_bits() + 1);
+        if let GeometryType::ParamPoly3(poly3) = &mut perturbed.geometry[1].r#type {
+            poly3.d_v = f64::from_bits(poly3.d_v.to_bits() + 1);
+        }
+        assert_ne!(before.plan_view, perturbed);
+        assert!(before.plan_view.approx_eq(&perturbed, 1e-12));
+
+        perturbed.geometry[0].hdg.value += 1e-9;
+        assert!(!before.plan_view.approx_eq(&perturbed, 1e-12));
+        assert!(before.plan_view.approx_eq(&perturbed, 1e-6));
+    }
+
+    #[test]
+    pub fn test_junction_priorities() {
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                    <junction id="9" name="">
+                        <connection id="0" incomingRoad="1" connectingRoad="3" contactPoint="start"/>
+                        <priority high="3" low="5"/>
+                        <priority high="4"/>
+                    </junction>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let junction = &drive.junction[0];
+
+        assert_eq!(Some("3"), junction.priority[0].high.as_deref());
+        assert_eq!(Some("5"), junction.priority[0].low.as_deref());
+        assert_eq!(None, junction.priority[1].low);
+        assert_eq!(vec![("3", "5")], junction.priorities().collect::<Vec<_>>());
+
+        let written = drive.to_xml_string().unwrap();
+        assert!(written.contains(r#"<priority high="3" low="5" />"#));
+        assert_eq!(drive, OpenDrive::from_xml_str(&written).unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "bincode")]
+    pub fn test_binary_round_trip() {
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                        <objects>
+                            <object id="o1" s="1.0e+01" t="0.0e+00" zOffset="0.0e+00" type="pole"/>
+                        </objects>
+                        <signals>
+                            <signal id="s1" s="5.0e+00" t="0.0e+00" zOffset="0.0e+00" dynamic="no" orientation="+" type="206" subtype="-1"/>
+                        </signals>
+                        <userData code="nested"><a b="c"><d/></a></userData>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let binary = drive.to_binary();
+
+        assert_eq!(drive, OpenDrive::from_binary(&binary).unwrap());
+        assert!(OpenDrive::from_binary(&binary[..binary.len() / 2]).is_err());
+    }
+
+    #[test]
+    pub fn test_lane_neighbors() {
+        use crate::lane::lane_section::LaneSection;
+        use crate::lane::lane_type::LaneType;
+        use crate::parser::FromXmlStr;
+
+        let section = LaneSection::from_xml_str(
+            r#"
+                <laneSection s="0.0e+00">
+                    <left>
+                        <lane id="1" type="driving" level="false"/>
+                    </left>
+                    <center>
+                        <lane id="0" type="none" level="false"/>
+                    </center>
+                    <right>
+                        <lane id="-1" type="driving" level="false"/>
+                        <lane id="-2" type="shoulder" level="false"/>
+                    </right>
+                </laneSection>
+            "#,
+        )
+        .unwrap();
+        let id = |neighbor: Option<(i64, &crate::lane::Lane)>| neighbor.map(|(id, _)| id);
+
+        assert_eq!(Some(0), id(section.left_neighbor(-1)));
+        assert_eq!(Some(-2), id(section.right_neighbor(-1)));
+        assert_eq!(
+            Some(&LaneType::Shoulder),
+            section.right_neighbor(-1).map(|(_, lane)| &lane.r#type)
+        );
+        assert_eq!(None, id(section.right_neighbor(-2)));
+        assert_eq!(None, id(section.left_neighbor(1)));
+        assert_eq!(Some(0), id(section.right_neighbor(1)));
+        assert_eq!(Some(1), id(section.left_neighbor(0)));
+        assert_eq!(None, id(section.left_neighbor(7)));
+    }
+
+    #[test]
+    pub fn test_single_side_lane_section() {
+        use crate::lane::lane_section::{LaneSection, LaneSectionError};
+        use crate::parser::FromXmlStr;
+
+        let section = LaneSection::from_xml_str(
+            r#"
+                <laneSection s="1.0e+01" singleSide="true">
+                    <center>
+                        <lane id="0" type="none" level="false"/>
+                    </center>
+                    <right>
+                        <lane id="-1" type="driving" level="false"/>
+                    </right>
+                </laneSection>
+            "#,
+        )
+        .unwrap();
+
+        assert!(section.is_single_side());
+        assert!(section.left.is_none());
+        assert_eq!(Ok(()), section.validate());
+
+        let mut both = section.clone();
+        both.left = Some(crate::lane::left::Left {
+            lane: vec1::vec1![crate::lane::left_lane::LeftLane {
+                id: 1,
+                base: section.lane(-1).unwrap().clone(),
+            }],
+            additional_data: Default::default(),
+        });
+        assert_eq!(
+            Err(LaneSectionError::SingleSideWithBothSides { s: 10.0 }),
+            both.validate()
+        );
+        both.single_side = None;
+        assert!(!both.is_single_side());
+        assert_eq!(Ok(()), both.validate());
+
+        let mut none = section.clone();
+        none.right = None;
+        assert_eq!(
+            Err(LaneSectionError::MissingSide { s: 10.0 }),
+            none.validate()
+        );
+    }
+
+    #[test]
+    pub fn test_stream_writer() {
+        use crate::writer::OpenDriveStreamWriter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let mut template = OpenDrive::from_xml_str(source).unwrap();
+        let road = template.road.pop().unwrap();
+
+        let mut writer = OpenDriveStreamWriter::new(Vec::new()).unwrap();
+        writer.write_header(&template.header).unwrap();
+        for id in ["1", "2"] {
+            let mut road = road.clone();
+            road.id = id.to_string();
+            writer.write_road(&road).unwrap();
+        }
+        let written = String::from_utf8(writer.finish().unwrap()).unwrap();
+
+        let drive = OpenDrive::from_xml_str(&written).unwrap();
+        assert_eq!(template.header, drive.header);
+        assert_eq!(
+            vec!["1", "2"],
+            drive.road.iter().map(|r| r.id.as_str()).collect::<Vec<_>>()
+        );
+        assert_eq!(road, drive.road[0]);
+
+        template.road = drive.road.clone();
+        assert_eq!(template.to_xml_string().unwrap(), written);
+    }
+
+    #[test]
+    pub fn test_profile_s_length() {
+        use uom::si::length::meter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneOffset s="7.5e+00" a="0.0e+00" b="0.0e+00" c="0.0e+00" d="0.0e+00"/>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                        <elevationProfile>
+                            <elevation s="1.25e+01" a="1.0e+00" b="0.0e+00" c="0.0e+00" d="0.0e+00"/>
+                        </elevationProfile>
+                        <lateralProfile>
+                            <superelevation s="2.5e+01" a="0.0e+00" b="0.0e+00" c="0.0e+00" d="0.0e+00"/>
+                            <shape s="5.0e+01" t="-1.5e+00" a="0.0e+00" b="0.0e+00" c="0.0e+00" d="0.0e+00"/>
+                        </lateralProfile>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let road = &drive.road[0];
+        let lateral = road.lateral_profile.as_ref().unwrap();
+
+        assert_eq!(
+            12.5,
+            road.elevation_profile.as_ref().unwrap().elevation[0]
+                .s_length()
+                .get::<meter>()
+        );
+        assert_eq!(25.0, lateral.super_elevation[0].s_length().get::<meter>());
+        assert_eq!(50.0, lateral.shape[0].s_length().get::<meter>());
+        assert_eq!(-1.5, lateral.shape[0].t_length().get::<meter>());
+        assert_eq!(7.5, road.lanes.lane_offset[0].s_length().get::<meter>());
+        assert_eq!(0.0, road.lanes.lane_section[0].s_length().get::<meter>());
+
+        let written = drive.to_xml_string().unwrap();
+        assert!(written.contains(r#"s="1.25000000000000000e1""#));
+        assert!(written.contains(r#"t="-1.50000000000000000e0""#));
+        assert_eq!(drive, OpenDrive::from_xml_str(&written).unwrap());
+    }
+
+    #[test]
+    pub fn test_object_borders_round_trip() {
+        use crate::object::border_type::BorderType;
+        use uom::si::length::meter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                        <objects>
+                            <object id="o1" s="1.0e+01" t="0.0e+00" zOffset="0.0e+00" type="trafficIsland">
+                                <outlines>
+                                    <outline id="0">
+                                        <cornerLocal u="0.0" v="0.0" z="0.0" height="0.2" id="0"/>
+                                        <cornerLocal u="2.0" v="0.0" z="0.0" height="0.2" id="1"/>
+                                        <cornerLocal u="2.0" v="1.0" z="0.0" height="0.2" id="2"/>
+                                    </outline>
+                                </outlines>
+                                <borders>
+                                    <border outlineId="0" type="curb" width="1.5e-01" useCompleteOutline="false">
+                                        <cornerReference id="0"/>
+                                        <cornerReference id="1"/>
+                                    </border>
+                                    <border outlineId="0" type="concrete" width="3.0e-01"/>
+                                </borders>
+                            </object>
+                        </objects>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let object = &drive.road[0].objects.as_ref().unwrap().object[0];
+        let borders = object.borders.as_ref().unwrap();
+
+        assert_eq!(2, borders.border.len());
+        assert_eq!(0, borders.border[0].outline_id);
+        assert_eq!(BorderType::Curb, borders.border[0].r#type);
+        assert_eq!(0.15, borders.border[0].width.get::<meter>());
+        assert_eq!(Some(false), borders.border[0].use_complete_outline);
+        assert_eq!(
+            vec![0, 1],
+            borders.border[0]
+                .corner_reference
+                .iter()
+                .map(|c| c.id)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(BorderType::Concrete, borders.border[1].r#type);
+        assert_eq!(None, borders.border[1].use_complete_outline);
+        assert!(borders.border[1].corner_reference.is_empty());
+
+        let written = drive.to_xml_string().unwrap();
+        assert!(written.contains("<borders>"));
+        assert_eq!(drive, OpenDrive::from_xml_str(&written).unwrap());
+    }
+
+    #[test]
+    pub fn test_version_mismatch_warning() {
+        use crate::core::version::Version;
+        use crate::parser::{Error, ParseOptions, Warning};
+
+        let source = |rev_minor: u8| {
+            format!(
+                r#"
+                    <?xml version="1.0" standalone="yes"?>
+                    <OpenDRIVE>
+                        <header revMajor="1" revMinor="{rev_minor}" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                        </header>
+                        <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                            <planView>
+                                <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                    <line/>
+                                </geometry>
+                            </planView>
+                            <lanes>
+                                <laneSection s="0.0000000000000000e+00">
+                                    <center>
+                                        <lane id="0" type="none" level="false">
+                                            <roadMark sOffset="0.0" type="solid" color="standard">
+                                                <sway d_s="0.0" a="0.1" b="0.0" c="0.0" d="0.0"/>
+                                            </roadMark>
+                                        </lane>
+                                    </center>
+                                </laneSection>
+                            </lanes>
+                        </road>
+                    </OpenDRIVE>
+                "#
+            )
+        };
+        let declared = source(4);
+        let options = ParseOptions::default().with_check_version(true);
+
+        let (drive, warnings) = OpenDrive::from_xml_str_with_options(&declared, options).unwrap();
+        assert_eq!(Version::V1_4, drive.header.revision());
+        assert_eq!(
+            vec![
+                Warning::VersionMismatch {
+                    feature: "the road attribute `rule`",
+                    since: Version::V1_5,
+                    declared: Version::V1_4,
+                },
+                Warning::VersionMismatch {
+                    feature: "the element `<sway>`",
+                    since: Version::V1_6,
+                    declared: Version::V1_4,
+                },
+            ],
+            warnings
+        );
+        assert_eq!(
+            "The document declares revision 1.4, but uses the element `<sway>` of revision 1.6",
+            warnings[1].to_string()
+        );
+
+        let (_, warnings) =
+            OpenDrive::from_xml_str_with_options(&declared, ParseOptions::default()).unwrap();
+        assert!(warnings.is_empty());
+
+        let error =
+            OpenDrive::from_xml_str_with_options(&declared, options.with_strict(true)).unwrap_err();
+        assert!(matches!(
+            *error,
+            Error::Rejected(Warning::VersionMismatch { .. })
+        ));
+
+        let current = source(7);
+        let (_, warnings) = OpenDrive::from_xml_str_with_options(&current, options).unwrap();
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    pub fn test_all_lanes() {
+        use crate::lane::lane_side::LaneSide;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                    <road rule="RHT" name="" length="5.0e+01" id="2" junction="-1">
+                        <planView>
+                            <geometry s="0.0e+00" x="0.0e+00" y="1.0e+01" hdg="0.0e+00" length="5.0e+01">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                    <lane id="-2" type="sidewalk" level="false"/>
+                                </right>
+                            </laneSection>
+                            <laneSection s="2.5e+01">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                </right>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let lanes = drive
+            .all_lanes()
+            .map(|lane| (lane.road_id, lane.section_index, lane.side, lane.lane_id))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                ("1", 0, LaneSide::Center, 0),
+                ("2", 0, LaneSide::Center, 0),
+                ("2", 0, LaneSide::Right, -1),
+                ("2", 0, LaneSide::Right, -2),
+                ("2", 1, LaneSide::Center, 0),
+                ("2", 1, LaneSide::Right, -1),
+            ],
+            lanes
+        );
+        assert_eq!(drive.statistics().lanes(), drive.all_lanes().count());
+        assert_eq!(
+            2,
+            drive
+                .all_lanes()
+                .filter(|lane| lane.lane.r#type.is_drivable())
+                .count()
+        );
+    }
+
+    #[test]
+    pub fn test_center_lane_id() {
+        use crate::parser::{Error, ParseOptions, Warning};
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="1337" type="driving" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let options = ParseOptions::default().with_check_center_lane(true);
+
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        assert_eq!(1337, drive.road[0].lanes.lane_section[0].center.lane0().id);
+
+        let (_, warnings) = OpenDrive::from_xml_str_with_options(source, options).unwrap();
+        let expected = Warning::InvalidCenterLaneId {
+            road: "1".to_string(),
+            section_index: 0,
+            id: 1337,
+        };
+        assert_eq!(vec![expected.clone()], warnings);
+
+        let error =
+            OpenDrive::from_xml_str_with_options(source, options.with_strict(true)).unwrap_err();
+        assert!(matches!(*error, Error::Rejected(warning) if warning == expected));
+
+        let valid = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let (drive, warnings) =
+            OpenDrive::from_xml_str_with_options(valid, options.with_strict(true)).unwrap();
+        assert!(warnings.is_empty());
+        assert_eq!(0, drive.road[0].lanes.lane_section[0].center.lane0().id);
+    }
+
+    #[test]
+    pub fn test_xml_write_flushes() {
+        use std::io::BufWriter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let expected = drive.to_xml_string().unwrap();
+
+        // the buffer is larger than the document, so nothing reaches the Vec without a flush
+        let buffered = BufWriter::with_capacity(1 << 20, Vec::new());
+        let buffered = drive.to_xml_write(buffered).unwrap();
+        assert_eq!(0, buffered.buffer().len());
+        assert_eq!(expected.as_bytes(), buffered.get_ref().as_slice());
+
+        let mut writer = xml::EmitterConfig::new()
+            .write_document_declaration(false)
+            .create_writer(BufWriter::with_capacity(1 << 20, Vec::new()));
+        drive.road[0].append_to_writer(&mut writer).unwrap();
+        let buffered = writer.into_inner();
+        assert_eq!(0, buffered.buffer().len());
+        assert_eq!(
+            drive.road[0].to_xml_string().unwrap().as_bytes(),
+            buffered.get_ref().as_slice()
+        );
+    }
+
+    #[test]
+    pub fn test_lane_height_at() {
+        use crate::lane::lane_section::LaneSection;
+        use crate::parser::FromXmlStr;
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        let section = LaneSection::from_xml_str(
+            r#"
+                <laneSection s="0.0e+00">
+                    <center>
+                        <lane id="0" type="none" level="false"/>
+                    </center>
+                    <right>
+                        <lane id="-1" type="sidewalk" level="false">
+                            <height sOffset="5.0e+00" inner="1.2e-01" outer="1.5e-01"/>
+                            <height sOffset="2.0e+01" inner="0.0e+00" outer="2.0e-02"/>
+                        </lane>
+                    </right>
+                </laneSection>
+            "#,
+        )
+        .unwrap();
+        let lane = section.lane(-1).unwrap();
+        let height_at = |ds: f64| {
+            lane.height_at(Length::new::<meter>(ds))
+                .map(|(inner, outer)| (inner.get::<meter>(), outer.get::<meter>()))
+        };
+
+        assert_eq!(None, height_at(0.0));
+        assert_eq!(Some((0.12, 0.15)), height_at(5.0));
+        assert_eq!(Some((0.12, 0.15)), height_at(19.9));
+        assert_eq!(Some((0.0, 0.02)), height_at(20.0));
+        assert_eq!(Some((0.0, 0.02)), height_at(1000.0));
+        assert_eq!(
+            None,
+            section
+                .lane(0)
+                .unwrap()
+                .height_at(Length::new::<meter>(10.0))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "rtree")]
+    pub fn test_spatial_index() {
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        // road 1 runs along the x-axis, road 2 is a quarter circle of radius 20 around (0, 50)
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                    <road rule="RHT" name="" length="5.0e+01" id="2" junction="-1">
+                        <planView>
+                            <geometry s="0.0e+00" x="2.0e+01" y="5.0e+01" hdg="1.5707963267948966e+00" length="3.1415926535897931e+01">
+                                <arc curvature="5.0e-02"/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                    <lane id="-2" type="sidewalk" level="false"/>
+                                </right>
+                            </laneSection>
+                            <laneSection s="2.5e+01">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                </right>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let index = drive.build_spatial_index();
+        let m = Length::new::<meter>;
+
+        assert_eq!("1", index.nearest_road(m(50.0), m(3.0)).unwrap().id);
+        assert_eq!("1", index.nearest_road(m(-10.0), m(-10.0)).unwrap().id);
+        // the end of the arc is at (0, 70)
+        assert_eq!("2", index.nearest_road(m(1.0), m(69.0)).unwrap().id);
+        // close to the arc at 45°, but far from the chord of its end points
+        assert_eq!("2", index.nearest_road(m(15.0), m(65.0)).unwrap().id);
+
+        fn ids(roads: Vec<&crate::road::Road>) -> Vec<&str> {
+            roads.into_iter().map(|r| r.id.as_str()).collect()
+        }
+        assert!(ids(index.roads_in_radius(m(10.0), m(40.0), m(5.0))).is_empty());
+        assert_eq!(
+            vec!["2"],
+            ids(index.roads_in_radius(m(20.0), m(40.0), m(10.5)))
+        );
+        let mut both = ids(index.roads_in_radius(m(20.0), m(20.0), m(40.0)));
+        both.sort();
+        assert_eq!(vec!["1", "2"], both);
+    }
+
+    #[test]
+    pub fn test_road_project_point() {
+        use uom::si::angle::radian;
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0" x="0.0" y="0.0" hdg="0.0" length="20.0"><line/></geometry>
+                            <geometry s="20.0" x="20.0" y="0.0" hdg="0.0" length="30.0"><arc curvature="0.04"/></geometry>
+                            <geometry s="50.0" x="43.30097714918065" y="15.941056138083159" hdg="1.2" length="50.0"><spiral curvStart="0.04" curvEnd="-0.02"/></geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let road = &drive.road[0];
+
+        for (s, t) in [
+            (5.0, 2.5),
+            (20.0, -1.0),
+            (33.3, 4.0),
+            (47.0, -3.0),
+            (72.5, 1.5),
+        ] {
+            let (x, y, hdg) = road.plan_view.pose_at(Length::new::<meter>(s)).unwrap();
+            let (sin, cos) = hdg.get::<radian>().sin_cos();
+            let x = x + Length::new::<meter>(-t * sin);
+            let y = y + Length::new::<meter>(t * cos);
+
+            let (projected_s, projected_t, distance) = road.project_point(x, y);
+            assert!(
+                (projected_s.get::<meter>() - s).abs() < 1e-6,
+                "{s} != {projected_s:?}"
+            );
+            assert!((projected_t.get::<meter>() - t).abs() < 1e-6);
+            assert!((distance - t.abs()).abs() < 1e-6);
+        }
+
+        // beyond the start of the reference line
+        let (s, t, distance) =
+            road.project_point(Length::new::<meter>(-3.0), Length::new::<meter>(4.0));
+        assert_eq!(0.0, s.get::<meter>());
+        assert!((t.get::<meter>() - 4.0).abs() < 1e-9);
+        assert!((distance - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    #[cfg(feature = "rtree")]
+    pub fn test_localize() {
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        // road 1 runs along y=0 from x=0 to x=100, road 2 along y=10 from x=0 to x=50
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                    <road rule="RHT" name="" length="5.0e+01" id="2" junction="-1">
+                        <planView>
+                            <geometry s="0.0e+00" x="0.0e+00" y="1.0e+01" hdg="0.0e+00" length="5.0e+01">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                    <lane id="-2" type="sidewalk" level="false"/>
+                                </right>
+                            </laneSection>
+                            <laneSection s="2.5e+01">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                </right>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let m = Length::new::<meter>;
+        let localize = |x: f64, y: f64| {
+            drive
+                .localize(m(x), m(y))
+                .map(|(id, s, t)| (id, s.get::<meter>(), t.get::<meter>()))
+        };
+
+        assert_eq!(Some(("1".to_string(), 25.0, 4.0)), localize(25.0, 4.0));
+        assert_eq!(Some(("2".to_string(), 25.0, -4.0)), localize(25.0, 6.0));
+        assert_eq!(Some(("2".to_string(), 10.0, 1.0)), localize(10.0, 11.0));
+        // road 2 ends at x=50, the point is closer to road 1
+        assert_eq!(Some(("1".to_string(), 70.0, 9.0)), localize(70.0, 9.0));
+
+        let index = drive.build_spatial_index();
+        let (road, s, t) = index.localize(m(25.0), m(-2.0)).unwrap();
+        assert_eq!("1", road.id);
+        assert_eq!((25.0, -2.0), (s.get::<meter>(), t.get::<meter>()));
+
+        let empty = OpenDrive::default();
+        assert_eq!(None, empty.localize(m(0.0), m(0.0)));
+    }
+
+    #[test]
+    pub fn test_attribute_order_matches_schema() {
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <paramPoly3 pRange="normalized" dV="0" cV="0" bV="0" aV="0" dU="0" cU="0" bU="1" aU="0"/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneOffset d="0" c="0" b="0" a="1.5" s="0"/>
+                            <laneSection s="0">
+                                <left><lane level="false" type="sidewalk" id="1">
+                                    <border d="0" c="0" b="0" a="2" sOffset="0"/>
+                                </lane></left>
+                                <center><lane level="false" type="none" id="0"/></center>
+                                <right><lane level="false" type="driving" id="-1">
+                                    <width d="0" c="0" b="0" a="3.5" sOffset="0"/>
+                                </lane></right>
+                            </laneSection>
+                        </lanes>
+                        <elevationProfile>
+                            <elevation d="0" c="0" b="0" a="1" s="0"/>
+                        </elevationProfile>
+                        <lateralProfile>
+                            <superelevation d="0" c="0" b="0" a="0.1" s="0"/>
+                            <shape d="0" c="0" b="0" a="0.2" t="0" s="0"/>
+                        </lateralProfile>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let xml = drive.to_xml_string().unwrap();
+
+        fn assert_order(xml: &str, tag: &str, attributes: &[&str]) {
+            let start = xml.find(tag).unwrap();
+            let end = start + xml[start..].find('>').unwrap();
+            let element = &xml[start..end];
+            let positions = attributes
+                .iter()
+                .map(|a| element.find(&format!(" {a}=")).unwrap())
+                .collect::<Vec<_>>();
+            assert!(
+                positions.windows(2).all(|w| w[0] < w[1]),
+                "unexpected attribute order in {element}"
+            );
+        }
+
+        assert_order(
+            &xml,
+            "<road ",
+            &["name", "length", "id", "junction", "rule"],
+        );
+        assert_order(&xml, "<geometry ", &["s", "x", "y", "hdg", "length"]);
+        assert_order(&xml, "<laneOffset ", &["s", "a", "b", "c", "d"]);
+        assert_order(&xml, "<lane ", &["id", "type", "level"]);
+        assert_order(&xml, "<width ", &["sOffset", "a", "b", "c", "d"]);
+        assert_order(&xml, "<border ", &["sOffset", "a", "b", "c", "d"]);
+        assert_order(&xml, "<elevation ", &["s", "a", "b", "c", "d"]);
+        assert_order(&xml, "<superelevation ", &["s", "a", "b", "c", "d"]);
+        assert_order(&xml, "<shape ", &["s", "t", "a", "b", "c", "d"]);
+        assert_order(
+            &xml,
+            "<paramPoly3 ",
+            &["aU", "bU", "cU", "dU", "aV", "bV", "cV", "dV", "pRange"],
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "tokio")]
+    pub fn test_from_async_read() {
+        let xml = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let drive = runtime
+            .block_on(OpenDrive::from_async_read(xml.trim().as_bytes()))
+            .unwrap();
+        assert_eq!(OpenDrive::from_xml_str(xml).unwrap(), drive);
+    }
+
+    #[test]
+    pub fn test_signal_dependencies() {
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                        <signals>
+                            <signal id="sign" s="1.0e+01" t="-3.0e+00" zOffset="2.0e+00" dynamic="no" orientation="+" type="274" subtype="-1" country="DE">
+                                <dependency id="wet" type="weather"/>
+                                <dependency id="time"/>
+                            </signal>
+                            <signal id="wet" s="1.0e+01" t="-3.0e+00" zOffset="1.5e+00" dynamic="no" orientation="+" type="1053" subtype="-1" country="DE"/>
+                            <signal id="time" s="1.0e+01" t="-3.0e+00" zOffset="1.0e+00" dynamic="no" orientation="+" type="1040" subtype="-1" country="DE"/>
+                        </signals>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+
+        let dependencies = drive.signal_dependencies();
+        assert_eq!(1, dependencies.len());
+        let sign = &dependencies["sign"];
+        assert_eq!(2, sign.len());
+        assert_eq!("wet", sign[0].id);
+        assert_eq!(Some("weather"), sign[0].r#type.as_deref());
+        assert_eq!("time", sign[1].id);
+        assert_eq!(None, sign[1].r#type);
+    }
+
+    #[test]
+    pub fn test_s_range_boundaries() {
+        use crate::core::s_range::SRange;
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        let m = Length::new::<meter>;
+        let range = SRange::new(m(25.0), m(50.0));
+        assert!(!range.contains(m(24.999999)));
+        assert!(range.contains(m(25.0)));
+        assert!(range.contains(m(49.999999)));
+        assert!(!range.contains(m(50.0)));
+        assert_eq!(m(25.0), range.clamp(m(0.0)));
+        assert_eq!(m(50.0), range.clamp(m(75.0)));
+        assert_eq!(m(25.0), range.length());
+        assert!(SRange::new(m(1.0), m(1.0)).is_empty());
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                    <road rule="RHT" name="" length="5.0e+01" id="2" junction="-1">
+                        <planView>
+                            <geometry s="0.0e+00" x="0.0e+00" y="1.0e+01" hdg="0.0e+00" length="5.0e+01">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                    <lane id="-2" type="sidewalk" level="false"/>
+                                </right>
+                            </laneSection>
+                            <laneSection s="2.5e+01">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                </right>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let road = drive.road.iter().find(|r| r.id == "2").unwrap();
+        let ranges = road
+            .lane_section_ranges()
+            .map(|(range, _)| range)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![SRange::new(m(0.0), m(25.0)), SRange::new(m(25.0), m(50.0))],
+            ranges
+        );
+        // exactly at the boundary, only the latter section is valid
+        let at_boundary = road
+            .lane_section_ranges()
+            .filter(|(range, _)| range.contains(m(25.0)))
+            .map(|(_, section)| section.s)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![25.0], at_boundary);
+    }
+
+    #[test]
+    pub fn test_parking_space_round_trip() {
+        use crate::object::access::Access;
+        use crate::object::side_type::SideType;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                        <objects>
+                            <object id="p1" type="parkingSpace" s="1.0e+01" t="-4.0e+00" zOffset="0.0e+00" width="2.5e+00" length="5.0e+00">
+                                <parkingSpace access="handicapped" restrictions="2h">
+                                    <marking side="front" color="white" width="1.2e-01" lineLength="1.0e+00" spaceLength="0.0e+00" startOffset="0.0e+00" stopOffset="0.0e+00"/>
+                                </parkingSpace>
+                            </object>
+                        </objects>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+
+        let object = &drive.road[0].objects.as_ref().unwrap().object[0];
+        let parking = object.parking().unwrap();
+        assert_eq!(Access::Handicapped, parking.access);
+        assert_eq!(Some("2h"), parking.restrictions.as_deref());
+        assert_eq!(1, parking.marking.len());
+        assert_eq!(Some(SideType::Front), parking.marking[0].side);
+
+        let written = drive.to_xml_string().unwrap();
+        assert_eq!(drive, OpenDrive::from_xml_str(&written).unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    pub fn test_parallel_parse() {
+        let xml = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                        <objects>
+                            <object id="ü" s="1.0e+01" t="2.0e+00" zOffset="0.0e+00" name="Straße"/>
+                        </objects>
+                    </road>
+                    <road rule="RHT" name="" length="5.0e+01" id="2" junction="-1">
+                        <planView>
+                            <geometry s="0.0e+00" x="0.0e+00" y="1.0e+01" hdg="0.0e+00" length="5.0e+01">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                    <lane id="-2" type="sidewalk" level="false"/>
+                                </right>
+                            </laneSection>
+                            <laneSection s="2.5e+01">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                </right>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let parallel = OpenDrive::from_xml_str_parallel(xml).unwrap();
+        assert_eq!(2, parallel.road.len());
+        assert_eq!(OpenDrive::from_xml_str(xml).unwrap(), parallel);
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    pub fn test_parallel_parse_prefixed_document() {
+        use crate::parser::ParseOptions;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <odr:OpenDRIVE xmlns:odr="http://code.asam.net/simulation/standard/opendrive_schema">
+                    <odr:header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </odr:header>
+                    <odr:road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </odr:road>
+                </odr:OpenDRIVE>
+            "#;
+        let parallel = OpenDrive::from_xml_str_parallel(source).unwrap();
+        assert_eq!(1, parallel.road.len());
+        assert_eq!(OpenDrive::from_xml_str(source).unwrap(), parallel);
+
+        // errors within roads report the same path and position as the serial parser
+        let missing_length = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <odr:OpenDRIVE xmlns:odr="http://code.asam.net/simulation/standard/opendrive_schema">
+                    <odr:header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </odr:header>
+                    <odr:road rule="RHT" name="" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </odr:road>
+                </odr:OpenDRIVE>
+            "#;
+        let serial = OpenDrive::from_xml_str(missing_length).unwrap_err();
+        let parallel = OpenDrive::from_xml_str_parallel(missing_length).unwrap_err();
+        assert!(serial.to_string().contains("OpenDRIVE.road"));
+        assert_eq!(serial.to_string(), parallel.to_string());
+        assert_eq!(serial.position(), parallel.position());
+
+        // roads are parsed at their depth within the document
+        let options = ParseOptions::default().with_max_depth(Some(3));
+        let serial = OpenDrive::from_xml_str_with_options(source, options).unwrap_err();
+        let parallel = OpenDrive::from_xml_str_parallel_with_options(source, options).unwrap_err();
+        assert!(matches!(
+            *parallel,
+            crate::parser::Error::MaxDepthExceeded { .. }
+        ));
+        assert_eq!(serial.to_string(), parallel.to_string());
+    }
+
+    #[test]
+    pub fn test_enum_all_variants() {
+        use crate::lane::lane_type::LaneType;
+        use std::str::FromStr;
+
+        assert!(LaneType::all_variants().contains(&LaneType::Driving));
+        for variant in LaneType::all_variants() {
+            assert_eq!(variant, &LaneType::from_str(variant.as_str()).unwrap());
+        }
+    }
+
+    #[test]
+    pub fn test_padded_enum_values() {
+        use crate::lane::lane_type::LaneType;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                    <road rule="RHT" name="" length="5.0e+01" id="2" junction="-1">
+                        <planView>
+                            <geometry s="0.0e+00" x="0.0e+00" y="1.0e+01" hdg="0.0e+00" length="5.0e+01">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type=" Driving " level="false"/>
+                                    <lane id="-2" type="sidewalk" level="false"/>
+                                </right>
+                            </laneSection>
+                            <laneSection s="2.5e+01">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                </right>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let section = &drive.road[1].lanes.lane_section[0];
+        assert_eq!(LaneType::Driving, section.lane(-1).unwrap().r#type);
+        assert!(" unknown ".parse::<LaneType>().is_err());
+    }
+
+    #[test]
+    pub fn test_outline_area_and_centroid() {
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                        <objects>
+                            <object id="island" s="1.0e+01" t="0.0e+00" zOffset="0.0e+00">
+                                <outlines>
+                                    <outline id="0" closed="true">
+                                        <cornerLocal u="0.0e+00" v="0.0e+00" z="0.0e+00" height="0.0e+00"/>
+                                        <cornerLocal u="1.0e+00" v="0.0e+00" z="0.0e+00" height="0.0e+00"/>
+                                        <cornerLocal u="1.0e+00" v="1.0e+00" z="0.0e+00" height="0.0e+00"/>
+                                        <cornerLocal u="0.0e+00" v="1.0e+00" z="0.0e+00" height="0.0e+00"/>
+                                    </outline>
+                                </outlines>
+                            </object>
+                        </objects>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let object = &drive.road[0].objects.as_ref().unwrap().object[0];
+        let mut outline = object.outlines.as_ref().unwrap().outline[0].clone();
+
+        assert_eq!(1.0, outline.area());
+        assert_eq!((0.5, 0.5), outline.centroid());
+
+        outline.choice.reverse();
+        assert_eq!(-1.0, outline.area());
+        assert_eq!((0.5, 0.5), outline.centroid());
+    }
+
+    #[test]
+    pub fn test_plan_view_coverage() {
+        use crate::road::geometry::plan_view::CoverageError;
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        let tolerance = Length::new::<meter>(1e-6);
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                    <road rule="RHT" name="" length="5.0e+01" id="2" junction="-1">
+                        <planView>
+                            <geometry s="0.0e+00" x="0.0e+00" y="1.0e+01" hdg="0.0e+00" length="5.0e+01">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                    <lane id="-2" type="sidewalk" level="false"/>
+                                </right>
+                            </laneSection>
+                            <laneSection s="2.5e+01">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                </right>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let mut drive = OpenDrive::from_xml_str(source).unwrap();
+        for road in &drive.road {
+            assert_eq!(
+                Ok(()),
+                road.plan_view.validate_coverage(road.length, tolerance)
+            );
+        }
+
+        let road = &mut drive.road[0];
+        let mut second = road.plan_view.geometry[0].clone();
+        road.plan_view.geometry[0].length = Length::new::<meter>(40.0);
+        second.s = Length::new::<meter>(50.0);
+        second.length = Length::new::<meter>(50.0);
+        road.plan_view.geometry.push(second);
+        assert_eq!(
+            Err(CoverageError::Gap { end: 40.0, s: 50.0 }),
+            road.plan_view.validate_coverage(road.length, tolerance)
+        );
+
+        road.plan_view.geometry[0].length = Length::new::<meter>(50.0);
+        assert_eq!(
+            Ok(()),
+            road.plan_view.validate_coverage(road.length, tolerance)
+        );
+        assert_eq!(
+            Err(CoverageError::Length {
+                end: 100.0,
+                road_length: 90.0
+            }),
+            road.plan_view
+                .validate_coverage(Length::new::<meter>(90.0), tolerance)
+        );
+    }
+
+    #[test]
+    pub fn test_reproject_translation() {
+        use std::convert::Infallible;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let mut drive = OpenDrive::from_xml_str(source).unwrap();
+        drive.set_geo_reference("+proj=utm +zone=32 +ellps=WGS84 +units=m +no_defs");
+        let drive = OpenDrive::from_xml_str(&drive.to_xml_string().unwrap()).unwrap();
+        assert_eq!(
+            Some("+proj=utm +zone=32 +ellps=WGS84 +units=m +no_defs"),
+            drive
+                .header
+                .geo_reference
+                .as_ref()
+                .and_then(|g| g.proj.as_deref())
+        );
+
+        let mut moved = drive.clone();
+        moved
+            .reproject_with(
+                "+proj=utm +zone=33 +ellps=WGS84 +units=m +no_defs",
+                |x, y| Ok::<_, Infallible>((x + 100.0, y - 50.0)),
+            )
+            .unwrap();
+        let before = &drive.road[0].plan_view.geometry[0];
+        let after = &moved.road[0].plan_view.geometry[0];
+        assert_eq!(before.x_m() + 100.0, after.x_m());
+        assert_eq!(before.y_m() - 50.0, after.y_m());
+        assert!((before.hdg_rad() - after.hdg_rad()).abs() < 1e-12);
+        assert_eq!(before.length, after.length);
+        assert_eq!(
+            Some("+proj=utm +zone=33 +ellps=WGS84 +units=m +no_defs"),
+            moved
+                .header
+                .geo_reference
+                .as_ref()
+                .and_then(|g| g.proj.as_deref())
+        );
+
+        #[cfg(feature = "proj")]
+        {
+            let mut reprojected = drive.clone();
+            reprojected
+                .reproject("+proj=utm +zone=33 +ellps=WGS84 +units=m +no_defs")
+                .unwrap();
+            let after = &reprojected.road[0].plan_view.geometry[0];
+            assert!((before.x_m() - after.x_m()).abs() > 1000.0);
+        }
+    }
+
+    #[test]
+    pub fn test_lane_ends_within() {
+        use crate::lane::lane_choice::LaneChoice;
+        use crate::lane::width::Width;
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        let m = Length::new::<meter>;
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                    <road rule="RHT" name="" length="5.0e+01" id="2" junction="-1">
+                        <planView>
+                            <geometry s="0.0e+00" x="0.0e+00" y="1.0e+01" hdg="0.0e+00" length="5.0e+01">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                    <lane id="-2" type="sidewalk" level="false"/>
+                                </right>
+                            </laneSection>
+                            <laneSection s="2.5e+01">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                </right>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let mut lane = drive.road[1].lanes.lane_section[0]
+            .lane(-1)
+            .unwrap()
+            .clone();
+        let width = |s_offset, a, b, c, d| {
+            LaneChoice::Width(Width {
+                a,
+                b,
+                c,
+                d,
+                s_offset: m(s_offset),
+            })
+        };
+
+        lane.choice = vec![width(0.0, 3.5, 0.0, 0.0, 0.0)];
+        assert_eq!(None, lane.ends_within(m(25.0)));
+
+        // narrows linearly from 3.5m to zero at ds=20
+        lane.choice = vec![width(0.0, 3.5, -0.175, 0.0, 0.0)];
+        let end = lane.ends_within(m(25.0)).unwrap().get::<meter>();
+        assert!((end - 20.0).abs() < 1e-9, "{end}");
+        assert_eq!(None, lane.ends_within(m(15.0)));
+        // a root just past the end of the section, as rounding may place it, still ends the lane
+        let section_length = m(20.0 - 1e-12);
+        assert_eq!(Some(section_length), lane.ends_within(section_length));
+
+        // widens from zero at ds=0, which is not the end of the lane
+        lane.choice = vec![width(0.0, 0.0, 0.175, 0.0, 0.0)];
+        assert_eq!(None, lane.ends_within(m(25.0)));
+
+        // but a width element starting at zero and narrowing further ends the lane at its start
+        lane.choice = vec![
+            width(0.0, 3.5, 0.0, 0.0, 0.0),
+            width(20.0, 0.0, -0.1, 0.0, 0.0),
+        ];
+        assert_eq!(Some(m(20.0)), lane.ends_within(m(25.0)));
+
+        // only touches zero at ds=10 and widens again, the width still decreases to zero there
+        lane.choice = vec![width(0.0, 3.5, -0.7, 0.035, 0.0)];
+        let end = lane.ends_within(m(25.0)).unwrap().get::<meter>();
+        assert!((end - 10.0).abs() < 1e-6, "{end}");
+
+        // constant up to ds=5, then closes with a cubic transition over 10m
+        lane.choice = vec![
+            width(0.0, 3.5, 0.0, 0.0, 0.0),
+            width(5.0, 3.5, 0.0, -0.105, 0.007),
+        ];
+        let end = lane.ends_within(m(25.0)).unwrap().get::<meter>();
+        assert!((end - 15.0).abs() < 1e-6, "{end}");
+        if let LaneChoice::Width(closing) = &lane.choice[1] {
+            assert!(closing.width_at(m(end)).get::<meter>().abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    pub fn test_resolve_signal_reference() {
+        use crate::object::orientation::Orientation;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                        <signals>
+                            <signal id="shared" s="4.0e+01" t="-3.0e+00" zOffset="2.0e+00" dynamic="no" orientation="+" type="206" subtype="-1" country="DE">
+                                <validity fromLane="-1" toLane="-1"/>
+                            </signal>
+                        </signals>
+                    </road>
+                    <road rule="RHT" name="" length="5.0e+01" id="2" junction="-1">
+                        <planView>
+                            <geometry s="0.0e+00" x="0.0e+00" y="1.0e+01" hdg="0.0e+00" length="5.0e+01">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                    <lane id="-2" type="sidewalk" level="false"/>
+                                </right>
+                            </laneSection>
+                            <laneSection s="2.5e+01">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                </right>
+                            </laneSection>
+                        </lanes>
+                        <signals>
+                            <signalReference id="shared" s="1.0e+01" t="3.0e+00" orientation="-">
+                                <validity fromLane="1" toLane="2"/>
+                            </signalReference>
+                        </signals>
+                    </road>
+                </OpenDRIVE>
+            "#;
+
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let road = &drive.road[1];
+        let reference = &road.signals.as_ref().unwrap().signal_reference[0];
+        let signal = road.resolve_signal_reference(reference, &drive).unwrap();
+        assert_eq!(40.0, signal.s_m());
+
+        let seen = reference.apply(signal);
+        assert_eq!("shared", seen.id);
+        assert_eq!(10.0, seen.s_m());
+        assert_eq!(Orientation::Minus, seen.orientation);
+        assert_eq!(
+            (1, 2),
+            (seen.validity[0].from_lane, seen.validity[0].to_lane)
+        );
+        assert_eq!(signal.r#type, seen.r#type);
+
+        let mut missing = reference.clone();
+        missing.id = "unknown".to_string();
+        assert!(road.resolve_signal_reference(&missing, &drive).is_none());
+    }
+
+    #[test]
+    pub fn test_non_positive_road_length() {
+        use crate::parser::{Error, ParseOptions, Warning};
+        use crate::road::RoadError;
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="-5" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        assert_eq!(
+            Err(RoadError::NonPositiveLength {
+                road: "1".to_string(),
+                length: -5.0
+            }),
+            drive.road[0].validate_length()
+        );
+
+        let options = ParseOptions::default()
+            .with_check_road_length(true)
+            .with_strict(true);
+        let error = OpenDrive::from_xml_str_with_options(source, options).unwrap_err();
+        assert!(matches!(
+            *error,
+            Error::Rejected(Warning::NonPositiveRoadLength { road }) if road == "1"
+        ));
+
+        let mut road = drive.road[0].clone();
+        assert!(road.set_length(Length::new::<meter>(0.0)).is_err());
+        assert_eq!(Length::new::<meter>(-5.0), road.length);
+        assert_eq!(Ok(()), road.set_length(Length::new::<meter>(5.0)));
+        assert_eq!(Ok(()), road.validate_length());
+    }
+
+    #[test]
+    pub fn test_plan_view_sample_with_s() {
+        use crate::road::geometry::arc::Arc;
+        use crate::road::geometry::geometry_type::GeometryType;
+        use uom::si::curvature::radian_per_meter;
+        use uom::si::f64::{Curvature, Length};
+        use uom::si::length::meter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let mut drive = OpenDrive::from_xml_str(source).unwrap();
+        let plan_view = &mut drive.road[0].plan_view;
+        let mut arc = plan_view.geometry[0].clone();
+        arc.s = Length::new::<meter>(100.0);
+        arc.x = Length::new::<meter>(100.0);
+        arc.length = Length::new::<meter>(10.5);
+        arc.r#type = GeometryType::Arc(Arc {
+            curvature: Curvature::new::<radian_per_meter>(0.01),
+        });
+        plan_view.geometry.push(arc);
+
+        let samples = plan_view.sample_with_s(Length::new::<meter>(1.0));
+        assert_eq!(Length::new::<meter>(0.0), samples[0].0);
+        assert_eq!((0.0, 0.0, 0.0), (samples[0].1, samples[0].2, samples[0].3));
+        assert!(samples.windows(2).all(|w| w[0].0 < w[1].0));
+
+        let (s, x, _, _) = samples[1];
+        assert_eq!(Length::new::<meter>(100.0), s);
+        assert!((x - 100.0).abs() < 1e-9);
+
+        let (s, _, _, hdg) = *samples.last().unwrap();
+        assert_eq!(Length::new::<meter>(110.5), s);
+        assert!((hdg - 0.105).abs() < 1e-9);
+    }
+
+    #[test]
+    pub fn test_effective_sections() {
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0e+00">
+                                <left><lane id="1" type="driving" level="false"/></left>
+                                <center><lane id="0" type="none" level="false"/></center>
+                                <right><lane id="-1" type="driving" level="false"/></right>
+                            </laneSection>
+                            <laneSection s="2.0e+01" singleSide="true">
+                                <left><lane id="1" type="sidewalk" level="false"/></left>
+                                <center><lane id="0" type="none" level="false"/></center>
+                            </laneSection>
+                            <laneSection s="2.0e+01" singleSide="true">
+                                <center><lane id="0" type="none" level="false"/></center>
+                                <right><lane id="-1" type="biking" level="false"/></right>
+                            </laneSection>
+                            <laneSection s="3.0e+01" singleSide="true">
+                                <center><lane id="0" type="none" level="false"/></center>
+                                <right><lane id="-1" type="shoulder" level="false"/></right>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let lanes = &drive.road[0].lanes;
+        let effective = lanes.effective_sections();
+        assert_eq!(
+            vec![0.0, 20.0, 30.0],
+            effective.iter().map(|e| e.s).collect::<Vec<_>>()
+        );
+
+        let types = |section: &crate::lane::effective_section::EffectiveSection| {
+            (
+                section.left.unwrap().lane[0].base.r#type.as_str(),
+                section.right.unwrap().lane[0].base.r#type.as_str(),
+            )
+        };
+        assert_eq!(("driving", "driving"), types(&effective[0]));
+        assert_eq!(("sidewalk", "biking"), types(&effective[1]));
+        assert_eq!(("sidewalk", "shoulder"), types(&effective[2]));
+    }
+
+    #[test]
+    pub fn test_junction_id() {
+        use crate::junction::junction_id::JunctionId;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                    <road rule="RHT" name="" length="5.0e+01" id="2" junction="7">
+                        <planView>
+                            <geometry s="0.0e+00" x="0.0e+00" y="1.0e+01" hdg="0.0e+00" length="5.0e+01">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                    <lane id="-2" type="sidewalk" level="false"/>
+                                </right>
+                            </laneSection>
+                            <laneSection s="2.5e+01">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                </right>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let none = &drive.road[0].junction;
+        assert!(none.is_none());
+        assert_eq!(None, none.as_junction());
+        assert_eq!(&JunctionId::none(), none);
+
+        let junction = &drive.road[1].junction;
+        assert!(!junction.is_none());
+        assert_eq!(Some("7"), junction.as_junction());
+        assert_eq!(*junction, "7");
+
+        let written = drive.to_xml_string().unwrap();
+        assert!(written.contains(r#"junction="7""#));
+        assert_eq!(drive, OpenDrive::from_xml_str(&written).unwrap());
+    }
+
+    #[test]
+    pub fn test_schema_location() {
+        use crate::writer::WriteOptions;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        assert!(!drive.to_xml_string().unwrap().contains("xsi"));
+
+        let options =
+            WriteOptions::default().with_schema_location(Some("OpenDRIVE_Core.xsd".to_string()));
+        let written = drive.to_xml_string_with_options(&options).unwrap();
+        let root = &written[written.find("<OpenDRIVE").unwrap()..];
+        let root = &root[..root.find('>').unwrap()];
+        assert!(root.contains(r#"xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance""#));
+        assert!(root.contains(r#"xsi:noNamespaceSchemaLocation="OpenDRIVE_Core.xsd""#));
+        assert_eq!(drive, OpenDrive::from_xml_str(&written).unwrap());
+    }
+
+    #[test]
+    pub fn test_direct_junction_connection() {
+        use crate::junction::contact_point::ContactPoint;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                    <junction id="100" name="ramp" type="direct">
+                        <connection id="0" incomingRoad="1" linkedRoad="2" contactPoint="start">
+                            <laneLink from="-1" to="-1"/>
+                        </connection>
+                        <connection id="1" incomingRoad="3" connectingRoad="4" contactPoint="end"/>
+                    </junction>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let junction = &drive.junction[0];
+        assert!(junction.is_direct());
+
+        let connection = &junction.connection[0];
+        assert_eq!(None, connection.connecting_road);
+        assert_eq!(Some("2"), connection.linked_road.as_deref());
+        assert_eq!(Some("1"), connection.incoming_road.as_deref());
+        assert_eq!(Some(ContactPoint::Start), connection.contact_point);
+        assert_eq!(Some("2"), connection.outgoing_road());
+        assert_eq!(
+            vec![("1", "2"), ("3", "4")],
+            junction.connected_roads().collect::<Vec<_>>()
+        );
+
+        let written = drive.to_xml_string().unwrap();
+        assert!(written.contains(r#"linkedRoad="2""#));
+        assert_eq!(drive, OpenDrive::from_xml_str(&written).unwrap());
+    }
+
+    #[test]
+    pub fn test_road_length_3d() {
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        let step = Length::new::<meter>(1.0);
+        let flat_source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let flat = OpenDrive::from_xml_str(flat_source).unwrap();
+        assert_eq!(flat.road[0].length, flat.road[0].length_3d(step));
+
+        let sloped_source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                        <elevationProfile>
+                            <elevation s="0.0e+00" a="1.0e+01" b="1.0e-01" c="0.0e+00" d="0.0e+00"/>
+                        </elevationProfile>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let sloped = OpenDrive::from_xml_str(sloped_source).unwrap();
+        let road = &sloped.road[0];
+        let profile = road.elevation_profile.as_ref().unwrap();
+        assert_eq!(15.0, profile.elevation_at(50.0));
+        let length_3d = road.length_3d(step).get::<meter>();
+        assert!(
+            (length_3d - 100.0 * 1.01_f64.sqrt()).abs() < 1e-9,
+            "{length_3d}"
+        );
+    }
+
+    #[test]
+    pub fn test_write_filtered() {
+        use crate::writer::WriteOptions;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                    <road rule="RHT" name="" length="5.0e+01" id="2" junction="-1">
+                        <planView>
+                            <geometry s="0.0e+00" x="0.0e+00" y="1.0e+01" hdg="0.0e+00" length="5.0e+01">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                    <lane id="-2" type="sidewalk" level="false"/>
+                                </right>
+                            </laneSection>
+                            <laneSection s="2.5e+01">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                </right>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                    <junction id="100" name="">
+                        <connection id="0" incomingRoad="1" connectingRoad="10" contactPoint="start"/>
+                    </junction>
+                    <junction id="200" name="">
+                        <connection id="0" incomingRoad="2" connectingRoad="20" contactPoint="start"/>
+                    </junction>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+
+        let written = drive
+            .write_filtered(Vec::new(), |road| road.id == "2")
+            .unwrap();
+        let filtered = OpenDrive::from_xml_read(written.as_slice()).unwrap();
+        assert_eq!(drive.header, filtered.header);
+        assert_eq!(vec![drive.road[1].clone()], filtered.road);
+        assert_eq!(drive.junction, filtered.junction);
+
+        let options = WriteOptions::default().with_prune_junctions(true);
+        let written = drive
+            .write_filtered_with_options(Vec::new(), |road| road.id == "2", &options)
+            .unwrap();
+        let pruned = OpenDrive::from_xml_read(written.as_slice()).unwrap();
+        assert_eq!(vec![drive.road[1].clone()], pruned.road);
+        assert_eq!(vec![drive.junction[1].clone()], pruned.junction);
+    }
+
+    #[test]
+    pub fn test_snap_endpoints() {
+        use uom::si::angle::radian;
+        use uom::si::f64::{Angle, Length};
+        use uom::si::length::meter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <link>
+                            <successor elementType="road" elementId="2" contactPoint="start"/>
+                        </link>
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                    <road rule="RHT" name="" length="5.0e+01" id="2" junction="-1">
+                        <planView>
+                            <geometry s="0.0e+00" x="0.0e+00" y="1.0e+01" hdg="0.0e+00" length="5.0e+01">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                    <lane id="-2" type="sidewalk" level="false"/>
+                                </right>
+                            </laneSection>
+                            <laneSection s="2.5e+01">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                </right>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let mut drive = OpenDrive::from_xml_str(source).unwrap();
+        {
+            let start = drive.road[1].plan_view.geometry.first_mut();
+            start.x = Length::new::<meter>(100.0 + 1e-4);
+            start.y = Length::new::<meter>(-1e-4);
+            start.hdg = Angle::new::<radian>(1e-5);
+        }
+
+        let tolerance = Length::new::<meter>(1e-3);
+        assert_eq!(0, drive.clone().snap_endpoints(Length::new::<meter>(1e-5)));
+        assert_eq!(1, drive.snap_endpoints(tolerance));
+
+        let first = drive.road[0].plan_view.geometry.last();
+        let end = first.position_at(first.s + first.length);
+        let start = drive.road[1].plan_view.geometry.first();
+        assert_eq!(end, (start.x, start.y, start.hdg));
+        assert_eq!(0, drive.snap_endpoints(tolerance));
+    }
+
+    #[test]
+    pub fn test_crosswalk_marking_round_trip() {
+        use crate::lane::road_mark::weight::Weight;
+        use crate::object::road_mark_color::RoadMarkColor;
+        use uom::si::length::meter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                        <objects>
+                            <object id="crosswalk" type="crosswalk" s="5.0e+01" t="0.0e+00" zOffset="0.0e+00">
+                                <outlines>
+                                    <outline id="0" closed="true">
+                                        <cornerLocal id="0" u="0.0e+00" v="-3.5e+00" z="0.0e+00" height="0.0e+00"/>
+                                        <cornerLocal id="1" u="4.0e+00" v="-3.5e+00" z="0.0e+00" height="0.0e+00"/>
+                                        <cornerLocal id="2" u="4.0e+00" v="3.5e+00" z="0.0e+00" height="0.0e+00"/>
+                                        <cornerLocal id="3" u="0.0e+00" v="3.5e+00" z="0.0e+00" height="0.0e+00"/>
+                                    </outline>
+                                </outlines>
+                                <markings>
+                                    <marking color="white" weight="bold" width="5.0e-01" zOffset="5.0e-03" spaceLength="5.0e-01" lineLength="5.0e-01" startOffset="0.0e+00" stopOffset="0.0e+00">
+                                        <cornerReference id="0"/>
+                                        <cornerReference id="3"/>
+                                    </marking>
+                                </markings>
+                            </object>
+                        </objects>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+
+        let object = &drive.road[0].objects.as_ref().unwrap().object[0];
+        let markings = object.markings.as_ref().unwrap().markings();
+        assert_eq!(1, markings.len());
+        let marking = &markings[0];
+        assert_eq!(RoadMarkColor::White, marking.color);
+        assert_eq!(Some(Weight::Bold), marking.weight);
+        assert_eq!(Some(0.005), marking.z_offset.map(|z| z.get::<meter>()));
+        assert_eq!(0.5, marking.space_length.get::<meter>());
+        assert_eq!(0.5, marking.line_length.get::<meter>());
+        assert_eq!(
+            vec![0, 3],
+            marking
+                .corner_reference
+                .iter()
+                .map(|c| c.id)
+                .collect::<Vec<_>>()
+        );
+
+        let written = drive.to_xml_string().unwrap();
+        assert_eq!(drive, OpenDrive::from_xml_str(&written).unwrap());
+    }
+
+    #[test]
+    pub fn test_failing_writer_returns_error() {
+        use crate::writer::OpenDriveStreamWriter;
+        use std::io::{Error, Write};
+
+        struct FailingWriter {
+            remaining: usize,
+        }
+
+        impl Write for FailingWriter {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                if self.remaining < buf.len() {
+                    Err(Error::other("disk full"))
+                } else {
+                    self.remaining -= buf.len();
+                    Ok(buf.len())
+                }
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                    <road rule="RHT" name="" length="5.0e+01" id="2" junction="-1">
+                        <planView>
+                            <geometry s="0.0e+00" x="0.0e+00" y="1.0e+01" hdg="0.0e+00" length="5.0e+01">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                    <lane id="-2" type="sidewalk" level="false"/>
+                                </right>
+                            </laneSection>
+                            <laneSection s="2.5e+01">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                </right>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        for remaining in [0, 100, 1000] {
+            assert!(drive.to_xml_write(FailingWriter { remaining }).is_err());
+            assert!(drive
+                .write_filtered(FailingWriter { remaining }, |_| true)
+                .is_err());
+        }
+
+        let result =
+            OpenDriveStreamWriter::new(FailingWriter { remaining: 200 }).and_then(|mut writer| {
+                writer.write_header(&drive.header)?;
+                for road in &drive.road {
+                    writer.write_road(road)?;
+                }
+                writer.finish()
+            });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    pub fn test_cross_section() {
+        use crate::lane::lane_type::LaneType;
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneOffset s="0" a="0.5" b="0" c="0" d="0"/>
+                            <laneSection s="0">
+                                <left>
+                                    <lane id="2" type="sidewalk"><width sOffset="0" a="2" b="0" c="0" d="0"/></lane>
+                                    <lane id="1" type="driving"><width sOffset="0" a="3.5" b="0" c="0" d="0"/></lane>
+                                </left>
+                                <center>
+                                    <lane id="0" type="none"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving"><width sOffset="0" a="3.5" b="0" c="0" d="0"/></lane>
+                                    <lane id="-2" type="sidewalk"><border sOffset="0" a="5.5" b="0.01" c="0" d="0"/></lane>
+                                </right>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let road = &drive.road[0];
+
+        let slices = road.cross_section(Length::new::<meter>(50.0));
+        let summary = slices
+            .iter()
+            .map(|slice| {
+                (
+                    slice.lane_id,
+                    slice.inner_t.get::<meter>(),
+                    slice.outer_t.get::<meter>(),
+                    slice.r#type.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                (2, 4.0, 6.0, LaneType::Sidewalk),
+                (1, 0.5, 4.0, LaneType::Driving),
+                (0, 0.5, 0.5, LaneType::None),
+                (-1, 0.5, -3.0, LaneType::Driving),
+                (-2, -3.0, -5.5, LaneType::Sidewalk),
+            ]
+        );
+        assert_eq!(slices[1].width(), Length::new::<meter>(3.5));
+        assert_eq!(slices[4].width(), Length::new::<meter>(2.5));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    pub fn test_country_code_validation() {
+        use crate::road::country_code::{CountryCode, CountryCodeDeprecated};
+        use std::str::FromStr;
+
+        let de = CountryCode::from_str("DE").unwrap();
+        assert_eq!(de, CountryCode::Iso3166alpha2("DE".to_string()));
+        assert!(de.is_valid_iso());
+        assert!(!CountryCode::from_str("XX").unwrap().is_valid_iso());
+
+        let open_drive = CountryCode::from_str("OpenDRIVE").unwrap();
+        assert_eq!(
+            open_drive,
+            CountryCode::CountryCodeDeprecated(CountryCodeDeprecated::OpenDRIVE)
+        );
+        assert!(!open_drive.is_valid_iso());
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                        <signals><signal id="5" s="1.0e+01" t="-3.0e+00" zOffset="2.0e+00" dynamic="no" orientation="+" type="274" subtype="-1" country="United Kingdom"/></signals>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let signal = &drive.road[0].signals.as_ref().unwrap().signal[0];
+        let country = signal.country.as_ref().unwrap();
+        assert_eq!(country, &CountryCode::Other("United Kingdom".to_string()));
+        assert_eq!(country.as_str(), "United Kingdom");
+        assert!(!country.is_valid_iso());
+
+        let written = drive.to_xml_string().unwrap();
+        assert!(written.contains(r#"country="United Kingdom""#));
+    }
+
+    #[test]
+    pub fn test_renumber_ids() {
+        use crate::core::renumber::RenumberError;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                        <link><successor elementType="road" elementId="2" contactPoint="start"/></link>
+                    </road>
+                    <road rule="RHT" name="" length="5.0e+01" id="2" junction="-1">
+                        <planView>
+                            <geometry s="0.0e+00" x="0.0e+00" y="1.0e+01" hdg="0.0e+00" length="5.0e+01">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                    <lane id="-2" type="sidewalk" level="false"/>
+                                </right>
+                            </laneSection>
+                            <laneSection s="2.5e+01">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving" level="false"/>
+                                </right>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let mut drive = OpenDrive::from_xml_str(source).unwrap();
+
+        assert_eq!(
+            drive.clone().renumber_ids(|_| "x".to_string()),
+            Err(RenumberError::Collision {
+                element: "road",
+                id: "x".to_string(),
+                first: "1".to_string(),
+                second: "2".to_string(),
+            })
+        );
+
+        drive.renumber_ids(|id| format!("tile_{id}")).unwrap();
+        let ids = drive.road.iter().map(|r| r.id.as_str()).collect::<Vec<_>>();
+        assert_eq!(ids, vec!["tile_1", "tile_2"]);
+
+        let successor = drive.road[0]
+            .link
+            .as_ref()
+            .unwrap()
+            .successor
+            .as_ref()
+            .unwrap();
+        assert_eq!(successor.element_id, "tile_2");
+        assert!(drive.road.iter().any(|r| r.id == successor.element_id));
+        assert!(drive.road[0].junction.is_none());
+    }
+
+    #[test]
+    pub fn test_empty_elevation_profile_round_trip() {
+        let absent_source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let absent = OpenDrive::from_xml_str(absent_source).unwrap();
+        assert_eq!(absent.road[0].elevation_profile, None);
+        let written = absent.to_xml_string().unwrap();
+        assert!(!written.contains("elevationProfile"));
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                        <elevationProfile/>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let profile = drive.road[0].elevation_profile.as_ref().unwrap();
+        assert!(profile.elevation.is_empty());
+
+        let written = drive.to_xml_string().unwrap();
+        assert!(written.contains("<elevationProfile"));
+        assert_eq!(OpenDrive::from_xml_str(&written).unwrap(), drive);
+    }
+
+    #[test]
+    pub fn test_lane_center_pose() {
+        use uom::si::angle::radian;
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0">
+                                <left>
+                                    <lane id="1" type="driving"><width sOffset="0" a="3.5" b="0" c="0" d="0"/></lane>
+                                </left>
+                                <center>
+                                    <lane id="0" type="none"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving"><width sOffset="0" a="3.5" b="0" c="0" d="0"/></lane>
+                                    <lane id="-2" type="shoulder"><width sOffset="0" a="1" b="0" c="0" d="0"/></lane>
+                                </right>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let road = &drive.road[0];
+        let s = Length::new::<meter>(20.0);
+
+        let (x, y, z, hdg) = road.lane_center_pose(0, 1, s).unwrap();
+        assert_eq!((x, y, z, hdg.get::<radian>()), (20.0, 1.75, 0.0, 0.0));
+        let (x, y, _, _) = road.lane_center_pose(0, -1, s).unwrap();
+        assert_eq!((x, y), (20.0, -1.75));
+        let (_, y, _, _) = road.lane_center_pose(0, -2, s).unwrap();
+        assert_eq!(y, -4.0);
+
+        assert_eq!(road.lane_center_pose(0, 3, s), None);
+        assert_eq!(road.lane_center_pose(1, 1, s), None);
+        assert_eq!(
+            road.lane_center_pose(0, 1, Length::new::<meter>(150.0)),
+            None
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    pub fn test_tracing_skipped_element() {
+        use std::sync::Mutex;
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        #[derive(Default)]
+        struct Capture {
+            spans: Mutex<Vec<String>>,
+            events: Mutex<Vec<(String, Option<u64>)>>,
+            current: Mutex<Option<u64>>,
+        }
+
+        struct Message<'a>(&'a mut String);
+
+        impl Visit for Message<'_> {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                if field.name() == "message" {
+                    *self.0 = format!("{value:?}");
+                }
+            }
+        }
+
+        impl Subscriber for Capture {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                let mut spans = self.spans.lock().unwrap();
+                spans.push(span.metadata().name().to_string());
+                Id::from_u64(spans.len() as u64)
+            }
+
+            fn record(&self, _: &Id, _: &Record<'_>) {}
+
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+            fn event(&self, event: &Event<'_>) {
+                let mut message = String::new();
+                event.record(&mut Message(&mut message));
+                let current = *self.current.lock().unwrap();
+                self.events.lock().unwrap().push((message, current));
+            }
+
+            fn enter(&self, span: &Id) {
+                *self.current.lock().unwrap() = Some(span.into_u64());
+            }
+
+            fn exit(&self, _: &Id) {
+                *self.current.lock().unwrap() = None;
+            }
+        }
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line><unexpected/></line>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let dispatch = tracing::Dispatch::new(Capture::default());
+        tracing::dispatcher::with_default(&dispatch, || {
+            OpenDrive::from_xml_str(source).unwrap();
+        });
+
+        let capture = dispatch.downcast_ref::<Capture>().unwrap();
+        let spans = capture.spans.lock().unwrap();
+        let events = capture.events.lock().unwrap();
+        let (_, span) = events
+            .iter()
+            .find(|(message, _)| message == "skipped element")
+            .unwrap();
+        let span = span.unwrap() as usize;
+        assert_eq!(spans[span - 1], "element");
+    }
+
+    #[test]
+    pub fn test_signals_by_s() {
+        use uom::si::length::meter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                        <signals>
+                            <signal id="far" s="8.0e+01" t="-3.0e+00" zOffset="2.0e+00" dynamic="no" orientation="+" type="274" subtype="-1"/>
+                            <signal id="near" s="5.0e+00" t="-3.0e+00" zOffset="2.0e+00" dynamic="no" orientation="+" type="206" subtype="-1">
+                                <validity fromLane="-1" toLane="-1"/>
+                            </signal>
+                            <signal id="middle" s="4.0e+01" t="3.0e+00" zOffset="2.0e+00" dynamic="no" orientation="-" type="205" subtype="-1"/>
+                        </signals>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+
+        let signals = drive.road[0].signals_by_s();
+        let order = signals
+            .iter()
+            .map(|(s, signal)| (s.get::<meter>(), signal.id.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(order, vec![(5.0, "near"), (40.0, "middle"), (80.0, "far")]);
+        assert_eq!(signals[0].1.validity.len(), 1);
+    }
+
+    #[test]
+    pub fn test_center_road_mark() {
+        use crate::lane::road_mark::color::Color;
+        use crate::lane::road_mark::type_simplified::TypeSimplified;
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false">
+                                        <roadMark sOffset="0" type="broken" weight="standard" color="white" width="0.12"/>
+                                        <roadMark sOffset="30" type="solid solid" weight="standard" color="yellow" width="0.12"/>
+                                    </lane>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let section = &drive.road[0].lanes.lane_section[0];
+
+        let mark = section
+            .center_road_mark_at(Length::new::<meter>(50.0))
+            .unwrap();
+        assert_eq!(mark.type_simplified, TypeSimplified::SolidSolid);
+        assert_eq!(mark.color, Color::Yellow);
+
+        let mark = section
+            .center_road_mark_at(Length::new::<meter>(10.0))
+            .unwrap();
+        assert_eq!(mark.type_simplified, TypeSimplified::Broken);
+    }
+
+    #[test]
+    pub fn test_tolerant_bool_attributes() {
+        let parse = |level: &str| {
+            let source = format!(
+                r#"
+                    <?xml version="1.0" standalone="yes"?>
+                    <OpenDRIVE>
+                        <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                        </header>
+                        <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                            <planView>
+                                <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                    <line/>
+                                </geometry>
+                            </planView>
+                            <lanes>
+                                <laneSection s="0.0000000000000000e+00">
+                                    <center>
+                                        <lane id="0" type="none" level="{level}"/>
+                                    </center>
+                                </laneSection>
+                            </lanes>
+                        </road>
+                    </OpenDRIVE>
+                "#
+            );
+            OpenDrive::from_xml_str(&source).map(|drive| {
+                drive.road[0].lanes.lane_section[0]
+                    .center
+                    .lane0()
+                    .base
+                    .level
+            })
+        };
+
+        assert_eq!(parse("1").unwrap(), Some(true));
+        assert_eq!(parse("0").unwrap(), Some(false));
+        assert_eq!(parse("Yes").unwrap(), Some(true));
+        assert_eq!(parse("no").unwrap(), Some(false));
+        assert_eq!(parse("TRUE").unwrap(), Some(true));
+        assert!(parse("2").is_err());
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00" singleSide="1">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        assert!(drive.road[0].lanes.lane_section[0].is_single_side());
+    }
+
+    #[test]
+    pub fn test_drivable_patches() {
+        use crate::lane::lane_type::LaneType;
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving"><width sOffset="0" a="3.5" b="0" c="0" d="0"/></lane>
+                                    <lane id="-2" type="sidewalk"><width sOffset="0" a="2" b="0" c="0" d="0"/></lane>
+                                </right>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+
+        let patches = drive.drivable_patches(Length::new::<meter>(10.0), LaneType::is_drivable);
+        assert_eq!(patches.len(), 1);
+        let patch = &patches[0];
+        assert_eq!(
+            (patch.road_id, patch.section_index, patch.lane_id),
+            ("1", 0, -1)
+        );
+        assert_eq!(patch.vertices.len(), 22);
+        assert_eq!(patch.quad_count(), 10);
+        for (index, pair) in patch.vertices.chunks(2).enumerate() {
+            let x = index as f64 * 10.0;
+            assert_eq!(pair, [[x, 0.0, 0.0], [x, -3.5, 0.0]]);
+        }
+
+        let all = drive.drivable_patches(Length::new::<meter>(30.0), |_| true);
+        let lanes = all.iter().map(|p| p.lane_id).collect::<Vec<_>>();
+        assert_eq!(lanes, vec![0, -1, -2]);
+        assert!(all.iter().all(|p| p.vertices.len() == 10));
+
+        // a step of zero yields a single quad per lane
+        let coarse = drive.drivable_patches(Length::new::<meter>(0.0), LaneType::is_drivable);
+        assert_eq!(1, coarse.len());
+        assert_eq!(
+            vec![
+                [0.0, 0.0, 0.0],
+                [0.0, -3.5, 0.0],
+                [100.0, 0.0, 0.0],
+                [100.0, -3.5, 0.0]
+            ],
+            coarse[0].vertices
+        );
+    }
+
+    #[test]
+    pub fn test_parse_error_position() {
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="start" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let trimmed = source.trim();
+        let line = trimmed[..trimmed.find("<geometry").unwrap()]
+            .lines()
+            .count() as u64;
+
+        let error = OpenDrive::from_xml_str(source).unwrap_err();
+        let position = error.position().unwrap();
+        assert_eq!(position.row + 1, line);
+        assert!(matches!(*error, crate::parser::Error::ParseError { .. }));
+        assert!(error.to_string().contains(&format!("at line {line},")));
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let trimmed = source.trim();
+        let line = trimmed[..trimmed.find("<road ").unwrap()].lines().count() as u64;
+        let error = OpenDrive::from_xml_str(source).unwrap_err();
+        assert!(matches!(
+            *error,
+            crate::parser::Error::MissingAttribute { .. }
+        ));
+        assert_eq!(error.position().unwrap().row + 1, line);
+
+        #[cfg(feature = "parallel")]
+        {
+            let error = OpenDrive::from_xml_str_parallel(source).unwrap_err();
+            assert_eq!(error.position().unwrap().row + 1, line);
+        }
+    }
+
+    #[test]
+    pub fn test_namespace_prefixed_document() {
+        const NAMESPACE: &str = "http://code.asam.net/simulation/standard/opendrive_schema";
+
+        let plain_source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let plain = OpenDrive::from_xml_str(plain_source).unwrap();
+        assert_eq!(plain.namespace, None);
+        assert!(!plain.to_xml_string().unwrap().contains("xmlns"));
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <odr:OpenDRIVE xmlns:odr="http://code.asam.net/simulation/standard/opendrive_schema">
+                    <odr:header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </odr:header>
+                    <odr:road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </odr:road>
+                </odr:OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        assert_eq!(drive.namespace.as_deref(), Some(NAMESPACE));
+        assert_eq!(drive.road.len(), 1);
+        assert_eq!(drive.road[0].id, "1");
+
+        let written = drive.to_xml_string().unwrap();
+        assert!(written.contains(&format!(r#"<OpenDRIVE xmlns="{NAMESPACE}""#)));
+        assert_eq!(OpenDrive::from_xml_str(&written).unwrap(), drive);
+
+        let mut without_namespace = drive.clone();
+        without_namespace.namespace = None;
+        assert_eq!(without_namespace, plain);
+    }
+
+    #[test]
+    pub fn test_resample_reference_line() {
+        use crate::road::geometry::geometry_type::GeometryType;
+        use uom::si::angle::radian;
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <arc curvature="0.02"/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let mut drive = OpenDrive::from_xml_str(source).unwrap();
+        let original = drive.road[0].plan_view.clone();
+        let road = &mut drive.road[0];
+
+        road.resample_reference_line(Length::new::<meter>(12.0));
+        let lines = &road.plan_view.geometry;
+        assert_eq!(lines.len(), 9);
+        assert!(lines
+            .iter()
+            .all(|g| matches!(g.r#type, GeometryType::Line(_))));
+
+        // the lines form a chain from the start to the end of the original reference line
+        for (index, line) in lines.iter().enumerate() {
+            let (end_x, end_y, _) = line.position_at(line.s + line.length);
+            let (x, y) = match lines.get(index + 1) {
+                Some(next) => {
+                    assert_eq!(line.s + line.length, next.s);
+                    (next.x, next.y)
+                }
+                None => {
+                    let (x, y, _) = original.pose_at(Length::new::<meter>(100.0)).unwrap();
+                    (x, y)
+                }
+            };
+            let gap = (end_x - x).get::<meter>().hypot((end_y - y).get::<meter>());
+            assert!(gap < 1e-9, "{gap}");
+        }
+        let (x, y, _) = original.pose_at(Length::new::<meter>(0.0)).unwrap();
+        assert_eq!((x, y), (lines[0].x, lines[0].y));
+        assert!(lines[0].hdg.get::<radian>() > 0.0);
+
+        // each line is shorter than its arc of length ds = 100/9 m and radius r = 50 m by
+        // ds - 2 r sin(ds / 2r), which accumulates along s
+        let ds = 100.0_f64 / 9.0;
+        let drift = 9.0 * (ds - 100.0 * (ds / 100.0).sin());
+        let last = lines.last();
+        let end = (last.s + last.length).get::<meter>();
+        assert!((100.0 - drift - end).abs() < 1e-9, "{end}");
+        assert_eq!(last.s + last.length, road.length);
+
+        // a non-positive segment length replaces each element by a single line
+        let mut single = drive.clone();
+        single.road[0].plan_view = original.clone();
+        single.road[0].resample_reference_line(Length::new::<meter>(0.0));
+        assert_eq!(1, single.road[0].plan_view.geometry.len());
+    }
+
+    #[test]
+    pub fn test_header_parsed_date() {
+        use chrono::NaiveDate;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let expected = NaiveDate::from_ymd_opt(2020, 2, 25)
+            .and_then(|date| date.and_hms_opt(13, 2, 27))
+            .unwrap();
+        assert_eq!(drive.header.parsed_date(), Some(expected));
+        assert_eq!(
+            drive.header.date.as_deref(),
+            Some("Tue Feb 25 13:02:27 2020")
+        );
+
+        let mut header = drive.header.clone();
+        for date in [
+            "2020-02-25T13:02:27",
+            "2020-02-25 13:02:27",
+            "2020-02-25T14:02:27+01:00",
+            "25.02.2020 13:02:27",
+        ] {
+            header.date = Some(date.to_string());
+            assert_eq!(header.parsed_date(), Some(expected), "{date}");
+        }
+
+        header.date = Some("2020-02-25".to_string());
+        assert_eq!(
+            header.parsed_date().map(|date| date.date()),
+            Some(expected.date())
+        );
+        header.date = Some("last tuesday".to_string());
+        assert_eq!(header.parsed_date(), None);
+
+        // the deprecated Header::date_parsed keeps to the ASAM format and RFC 3339
+        #[allow(deprecated)]
+        let legacy = drive.header.date_parsed();
+        assert_eq!(legacy.map(|date| date.naive_utc()), Some(expected));
+        header.date = Some("25.02.2020 13:02:27".to_string());
+        #[allow(deprecated)]
+        let legacy = header.date_parsed();
+        assert_eq!(legacy, None);
+    }
+
+    #[test]
+    pub fn test_junction_back_references() {
+        use crate::parser::{ParseOptions, Warning};
+
+        let document = |junction: &str| {
+            format!(
+                r#"
+                    <?xml version="1.0" standalone="yes"?>
+                    <OpenDRIVE>
+                        <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                        </header>
+                        <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                            <planView>
+                                <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                    <line/>
+                                </geometry>
+                            </planView>
+                            <lanes>
+                                <laneSection s="0.0000000000000000e+00">
+                                    <center>
+                                        <lane id="0" type="none" level="false"/>
+                                    </center>
+                                </laneSection>
+                            </lanes>
+                        </road>
+                        <road rule="RHT" name="" length="5.0e+01" id="2" junction="{junction}">
+                            <planView>
+                                <geometry s="0.0e+00" x="0.0e+00" y="1.0e+01" hdg="0.0e+00" length="5.0e+01">
+                                    <line/>
+                                </geometry>
+                            </planView>
+                            <lanes>
+                                <laneSection s="0.0e+00">
+                                    <center>
+                                        <lane id="0" type="none" level="false"/>
+                                    </center>
+                                    <right>
+                                        <lane id="-1" type="driving" level="false"/>
+                                        <lane id="-2" type="sidewalk" level="false"/>
+                                    </right>
+                                </laneSection>
+                                <laneSection s="2.5e+01">
+                                    <center>
+                                        <lane id="0" type="none" level="false"/>
+                                    </center>
+                                    <right>
+                                        <lane id="-1" type="driving" level="false"/>
+                                    </right>
+                                </laneSection>
+                            </lanes>
+                        </road>
+                        <junction id="1" name="">
+                            <connection id="0" incomingRoad="1" connectingRoad="2" contactPoint="start"/>
+                            <connection id="1" incomingRoad="1" connectingRoad="2" contactPoint="end"/>
+                            <connection id="2" incomingRoad="2" connectingRoad="404" contactPoint="start"/>
+                        </junction>
+                    </OpenDRIVE>
+                "#
+            )
+        };
+        let source = document("-1");
+        let drive = OpenDrive::from_xml_str(&source).unwrap();
+        let expected = Warning::JunctionBackReference {
+            road: "2".to_string(),
+            junction: "1".to_string(),
+            declared: "-1".to_string(),
+        };
+        assert_eq!(drive.validate_junction_backrefs(), vec![expected.clone()]);
+
+        let options = ParseOptions::default().with_check_junction_backrefs(true);
+        let (_, warnings) = OpenDrive::from_xml_str_with_options(&source, options).unwrap();
+        assert_eq!(warnings, vec![expected]);
+
+        let fixed = document("1");
+        let drive = OpenDrive::from_xml_str(&fixed).unwrap();
+        assert!(drive.validate_junction_backrefs().is_empty());
+    }
+
+    #[test]
+    pub fn test_geometry_table() {
+        use crate::road::geometry::geometry_table::GeometryKind;
+        use uom::si::angle::radian;
+        use uom::si::length::meter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.2e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                            <geometry s="1.0e+02" x="1.0e+02" y="0.0e+00" hdg="0.0e+00" length="2.0e+01">
+                                <arc curvature="0.01"/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let plan_view = &drive.road[0].plan_view;
+
+        let table = plan_view.as_geometry_table();
+        assert_eq!(table.len(), plan_view.geometry.len());
+        assert_eq!(table.len(), 2);
+        for (index, geometry) in plan_view.geometry.iter().enumerate() {
+            assert_eq!(table.s[index], geometry.s.get::<meter>());
+            assert_eq!(table.x[index], geometry.x.get::<meter>());
+            assert_eq!(table.y[index], geometry.y.get::<meter>());
+            assert_eq!(table.hdg[index], geometry.hdg.get::<radian>());
+            assert_eq!(table.length[index], geometry.length.get::<meter>());
+            assert_eq!(table.kind[index], geometry.r#type.kind());
+        }
+        assert_eq!(table.kind, vec![GeometryKind::Line, GeometryKind::Arc]);
+        assert_eq!(table.kind[1] as u8, 2);
+    }
+
+    #[test]
+    #[cfg(feature = "python")]
+    pub fn test_python_parse() {
+        use crate::python::parse;
+        use pyo3::prelude::*;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let drive = Py::new(py, parse(source).unwrap()).unwrap();
+            let road = drive
+                .bind(py)
+                .getattr("roads")
+                .unwrap()
+                .get_item(0)
+                .unwrap();
+            let id: String = road.getattr("id").unwrap().extract().unwrap();
+            assert_eq!("1", id);
+            let kind: String = road
+                .getattr("geometries")
+                .unwrap()
+                .get_item(0)
+                .unwrap()
+                .getattr("kind")
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!("line", kind);
+            let lane_type: String = road
+                .getattr("lane_sections")
+                .unwrap()
+                .get_item(0)
+                .unwrap()
+                .getattr("lanes")
+                .unwrap()
+                .get_item(0)
+                .unwrap()
+                .getattr("type")
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!("none", lane_type);
+            assert!(drive.borrow(py).inner().road.len() == 1);
+            assert!(parse("<OpenDRIVE>").is_err());
+        });
+    }
+
+    #[test]
+    pub fn test_lane_map() {
+        use crate::lane::lane_type::LaneType;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0">
+                                <center><lane id="0" type="none"/></center>
+                                <right><lane id="-1" type="driving"/></right>
+                            </laneSection>
+                            <laneSection s="40">
+                                <left><lane id="1" type="biking"/></left>
+                                <center><lane id="0" type="none"/></center>
+                                <right>
+                                    <lane id="-1" type="driving"/>
+                                    <lane id="-2" type="sidewalk"/>
+                                </right>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let map = drive.road[0].lane_map();
+
+        assert_eq!(6, map.len());
+        assert_eq!(LaneType::Sidewalk, map[&(1, -2)].r#type);
+        assert_eq!(LaneType::Biking, map[&(1, 1)].r#type);
+        assert_eq!(LaneType::Driving, map[&(0, -1)].r#type);
+        assert!(!map.contains_key(&(0, -2)));
+        assert!(!map.contains_key(&(2, 0)));
+    }
+
+    #[test]
+    pub fn test_geometry_kind() {
+        use crate::road::geometry::geometry_table::GeometryKind;
+        use crate::road::geometry::geometry_type::GeometryType;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.4e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                            <geometry s="1.0e+02" x="1.0e+02" y="0.0" hdg="0.0" length="1.0e+01">
+                                <spiral curvStart="0.0" curvEnd="0.01"/>
+                            </geometry>
+                            <geometry s="1.1e+02" x="1.1e+02" y="0.0" hdg="0.0" length="1.0e+01">
+                                <arc curvature="0.01"/>
+                            </geometry>
+                            <geometry s="1.2e+02" x="1.2e+02" y="0.0" hdg="0.0" length="1.0e+01">
+                                <poly3 a="0" b="0" c="0.001" d="0"/>
+                            </geometry>
+                            <geometry s="1.3e+02" x="1.3e+02" y="0.0" hdg="0.0" length="1.0e+01">
+                                <paramPoly3 aU="0" bU="1" cU="0" dU="0" aV="0" bV="0" cV="0.001" dV="0" pRange="arcLength"/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let geometry = &drive.road[0].plan_view.geometry;
+
+        for g in geometry.iter() {
+            let matches = matches!(
+                (&g.r#type, g.kind()),
+                (GeometryType::Line(_), GeometryKind::Line)
+                    | (GeometryType::Spiral(_), GeometryKind::Spiral)
+                    | (GeometryType::Arc(_), GeometryKind::Arc)
+                    | (GeometryType::Poly3(_), GeometryKind::Poly3)
+                    | (GeometryType::ParamPoly3(_), GeometryKind::ParamPoly3)
+            );
+            assert!(matches, "{:?} for {:?}", g.kind(), g.r#type);
+        }
+        assert_eq!(
+            vec![
+                GeometryKind::Line,
+                GeometryKind::Spiral,
+                GeometryKind::Arc,
+                GeometryKind::Poly3,
+                GeometryKind::ParamPoly3,
+            ],
+            geometry.iter().map(|g| g.kind()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    pub fn test_object_perp_to_road() {
+        use crate::object::corner::Corner;
+        use crate::object::corner_local::CornerLocal;
+        use uom::si::angle::radian;
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                        <lateralProfile>
+                            <superelevation s="0.0" a="0.1" b="0.0" c="0.0" d="0.0"/>
+                        </lateralProfile>
+                        <objects>
+                            <object id="pole" s="1.0e+01" t="-2.0e+00" zOffset="0.0e+00" roll="0.3" pitch="0.2" perpToRoad="true"/>
+                            <object id="raw" s="1.0e+01" t="-2.0e+00" zOffset="0.0e+00" roll="0.3" pitch="0.2"/>
+                            <object id="turned" s="1.0e+01" t="-2.0e+00" zOffset="0.0e+00" hdg="1.5707963267948966" perpToRoad="true"/>
+                        </objects>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let road = &drive.road[0];
+        let poses = road.objects_world();
+        assert_eq!(3, poses.len());
+
+        let (pole, pole_pose) = &poses[0];
+        assert!((pole_pose.roll.get::<radian>() - 0.1).abs() < 1e-9);
+        assert!(pole_pose.pitch.get::<radian>().abs() < 1e-9);
+        assert!((pole_pose.x.get::<meter>() - 10.0).abs() < 1e-9);
+        assert!((pole_pose.y.get::<meter>() + 2.0).abs() < 1e-9);
+
+        let (_, raw_pose) = &poses[1];
+        assert!((raw_pose.roll.get::<radian>() - 0.3).abs() < 1e-9);
+        assert!((raw_pose.pitch.get::<radian>() - 0.2).abs() < 1e-9);
+
+        // seen from the side, the banking tilts the object forward
+        let (_, turned_pose) = &poses[2];
+        assert!(turned_pose.roll.get::<radian>().abs() < 1e-9);
+        assert!((turned_pose.pitch.get::<radian>() + 0.1).abs() < 1e-9);
+
+        // the top of the pole leans towards the lower, right side of the road
+        let top = Corner::Local(CornerLocal {
+            height: Length::new::<meter>(0.0),
+            id: None,
+            u: Length::new::<meter>(0.0),
+            v: Length::new::<meter>(0.0),
+            z: Length::new::<meter>(1.0),
+        });
+        let (x, y, z) = road.corner_world(pole, &top).unwrap();
+        assert!((x.get::<meter>() - 10.0).abs() < 1e-9);
+        assert!((y.get::<meter>() + 2.0 + 0.1_f64.sin()).abs() < 1e-9);
+        assert!((z.get::<meter>() - 0.1_f64.cos()).abs() < 1e-9);
+    }
+
+    #[test]
+    pub fn test_deprecated_object_type() {
+        use crate::object::orientation::{ObjectType, Orientation};
+        use crate::parser::{Error, ParseOptions, Warning};
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                        <objects>
+                            <object id="parked" s="1.0e+01" t="2.0e+00" zOffset="0.0e+00" type="car" orientation=" plus "/>
+                            <object id="pole" s="2.0e+01" t="2.0e+00" zOffset="0.0e+00" type="pole" orientation="-"/>
+                        </objects>
+                    </road>
+                </OpenDRIVE>
+            "#;
+
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let objects = &drive.road[0].objects.as_ref().unwrap().object;
+        #[allow(deprecated)]
+        let car = ObjectType::Car;
+        assert_eq!(Some(car), objects[0].r#type);
+        assert_eq!(Some(Orientation::Plus), objects[0].orientation);
+        assert_eq!(Some(Orientation::Minus), objects[1].orientation);
+        assert_eq!(Orientation::None, " both ".parse().unwrap());
+        assert!("sideways".parse::<Orientation>().is_err());
+
+        let options = ParseOptions::default().with_check_deprecated(true);
+        let (_, warnings) = OpenDrive::from_xml_str_with_options(source, options).unwrap();
+        let expected = Warning::DeprecatedObjectType {
+            road: "1".to_string(),
+            object: "parked".to_string(),
+            r#type: "car",
+        };
+        assert_eq!(warnings, vec![expected.clone()]);
+
+        let error =
+            OpenDrive::from_xml_str_with_options(source, options.with_strict(true)).unwrap_err();
+        assert!(matches!(*error, Error::Rejected(ref warning) if *warning == expected));
+    }
+
+    #[test]
+    pub fn test_to_canonical() {
+        use crate::object::orientation::ObjectType;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="5.0e+01"><center><lane id="0" type="none"/></center></laneSection>
+                            <laneSection s="0.0e+00"><center><lane id="0" type="none"/></center></laneSection>
+                        </lanes>
+                        <objects>
+                            <object id="parked" s="1.0e+01" t="2.0e+00" zOffset="0.0e+00" type="car"/>
+                            <object id="pole" s="2.0e+01" t="2.0e+00" zOffset="0.0e+00" type="pole"/>
+                        </objects>
+                        <signals/>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        assert!(drive.road[0].signals.is_some());
+
+        let canonical = drive.to_canonical();
+        let road = &canonical.road[0];
+        let types = road
+            .objects
+            .as_ref()
+            .unwrap()
+            .object
+            .iter()
+            .map(|o| o.r#type.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![Some(ObjectType::Obstacle), Some(ObjectType::Pole)],
+            types
+        );
+        assert!(road.signals.is_none());
+        assert_eq!(
+            vec![0.0, 50.0],
+            road.lanes
+                .lane_section
+                .iter()
+                .map(|s| s.s)
+                .collect::<Vec<_>>()
+        );
+        assert!(canonical.deprecated_object_types().is_empty());
+        assert_eq!(canonical, canonical.to_canonical());
+        assert_eq!(1, drive.deprecated_object_types().len());
+    }
+
+    #[test]
+    pub fn test_road_class_at() {
+        use crate::road::country_code::CountryCode;
+        use crate::road::road_type_e::RoadTypeE;
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <type s="0" type="motorway" country="DE"/>
+                        <type s="6.0e+01" type="town"/>
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let road = &drive.road[0];
+        assert_eq!(2, road.r#type.len());
+        assert_eq!(RoadTypeE::Motorway, road.r#type[0].r#type);
+        assert_eq!(
+            Some(CountryCode::Iso3166alpha2("DE".to_string())),
+            road.r#type[0].country
+        );
+        assert_eq!(None, road.r#type[1].country);
+
+        let written = drive.to_xml_string().unwrap();
+        assert!(written.contains(r#"country="DE""#));
+        assert!(written.contains(r#"type="motorway""#));
+        assert_eq!(drive, OpenDrive::from_xml_str(&written).unwrap());
+
+        let class_at = |s: f64| road.road_class_at(Length::new::<meter>(s));
+        assert_eq!(Some(&RoadTypeE::Motorway), class_at(0.0));
+        assert_eq!(Some(&RoadTypeE::Motorway), class_at(59.9));
+        assert_eq!(Some(&RoadTypeE::Town), class_at(60.0));
+        assert_eq!(Some(&RoadTypeE::Town), class_at(100.0));
+        assert_eq!(None, class_at(-1.0));
+        assert_eq!(
+            Some("DE"),
+            road.road_type_at(Length::new::<meter>(10.0))
+                .and_then(|t| t.country.as_ref())
+                .map(CountryCode::as_str)
+        );
+    }
+
+    #[test]
+    pub fn test_validate_all() {
+        use crate::core::validation::ValidationError;
+        use crate::parser::Warning;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.3e+02" id="1" junction="-1">
+                        <link>
+                            <successor elementType="road" elementId="404"/>
+                        </link>
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                            <geometry s="1.0e+02" x="1.01e+02" y="0.0e+00" hdg="0.0e+00" length="2.0e+01"><line/></geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="5.0e+01">
+                                <center><lane id="0" type="none"/></center>
+                                <right><lane id="-1" type="driving"><width sOffset="0" a="3.0" b="0" c="0" d="0"/></lane></right>
+                            </laneSection>
+                            <laneSection s="0.0e+00">
+                                <center><lane id="1" type="none"/></center>
+                                <right>
+                                    <lane id="-1" type="driving"><width sOffset="0" a="1.0" b="-0.1" c="0" d="0"/></lane>
+                                    <lane id="-2" type="sidewalk"><width sOffset="0" a="1.0" b="-0.2" c="0.005" d="0"/></lane>
+                                </right>
+                            </laneSection>
+                        </lanes>
+                        <objects>
+                            <object id="both" s="1.0e+01" t="2.0e+00" zOffset="0.0e+00" radius="1.0" width="1.0"/>
+                        </objects>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let errors = drive.validate_all();
+
+        assert!(errors
+            .iter()
+            .any(|e| matches!(e, ValidationError::Coverage { road, .. } if road == "1")));
+        // the second geometry element starts 1m off the end of the first one
+        assert!(errors.contains(&ValidationError::Discontinuity {
+            road: "1".to_string(),
+            index: 1,
+            distance: 1.0,
+            heading: 0.0,
+        }));
+        assert!(errors.contains(&ValidationError::UnsortedLaneSection {
+            road: "1".to_string(),
+            section_index: 1,
+            s: 0.0,
+        }));
+        assert!(errors.contains(&ValidationError::NegativeWidth {
+            road: "1".to_string(),
+            section_index: 1,
+            lane_id: -1,
+            s_offset: 0.0,
+        }));
+        // the width of lane -2 is positive at both ends, but dips below zero around ds=20
+        assert!(errors.contains(&ValidationError::NegativeWidth {
+            road: "1".to_string(),
+            section_index: 1,
+            lane_id: -2,
+            s_offset: 0.0,
+        }));
+        assert!(errors
+            .iter()
+            .any(|e| matches!(e, ValidationError::Shape { road, .. } if road == "1")));
+        assert!(errors.contains(&ValidationError::DanglingLink {
+            road: "1".to_string(),
+            element: "successor",
+            target: "404".to_string(),
+        }));
+        assert!(
+            errors.contains(&ValidationError::Warning(Warning::InvalidCenterLaneId {
+                road: "1".to_string(),
+                section_index: 1,
+                id: 1,
+            }))
+        );
+        assert_eq!(8, errors.len(), "{errors:#?}");
+
+        let valid = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving"/>
+                                </right>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let valid = OpenDrive::from_xml_str(valid).unwrap();
+        assert_eq!(Vec::<ValidationError>::new(), valid.validate_all());
+    }
+
+    #[test]
+    pub fn test_line_position_at() {
+        use uom::si::angle::radian;
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="1.0e+01" y="-5.0e+00" hdg="7.853981633974483e-01" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let line = &drive.road[0].plan_view.geometry[0];
+        let at = |s: f64| {
+            let (x, y, hdg) = line.position_at(Length::new::<meter>(s));
+            (x.get::<meter>(), y.get::<meter>(), hdg.get::<radian>())
+        };
+        let assert_close = |(x, y, hdg): (f64, f64, f64), expected: (f64, f64)| {
+            assert!(
+                (x - expected.0).abs() < 1e-9,
+                "x={x}, expected {}",
+                expected.0
+            );
+            assert!(
+                (y - expected.1).abs() < 1e-9,
+                "y={y}, expected {}",
+                expected.1
+            );
+            assert!((hdg - std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+        };
+
+        // at 45°, each meter along the line advances x and y by sqrt(0.5)
+        let step = 0.5_f64.sqrt();
+        assert_close(at(0.0), (10.0, -5.0));
+        assert_close(at(1.0), (10.0 + step, -5.0 + step));
+        assert_close(at(10.0), (10.0 + 10.0 * step, -5.0 + 10.0 * step));
+        assert_close(at(100.0), (10.0 + 100.0 * step, -5.0 + 100.0 * step));
+
+        // s outside of [s, s + length] is clamped to the ends of the element
+        assert_close(at(-3.0), (10.0, -5.0));
+        assert_close(at(250.0), (10.0 + 100.0 * step, -5.0 + 100.0 * step));
+    }
+
+    #[test]
+    pub fn test_arc_position_at() {
+        use uom::si::angle::radian;
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        let arc = |curvature: &str| {
+            let source = format!(
+                r#"
+                    <?xml version="1.0" standalone="yes"?>
+                    <OpenDRIVE>
+                        <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                        </header>
+                        <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                            <planView>
+                                <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                    <arc curvature="{curvature}"/>
+                                </geometry>
+                            </planView>
+                            <lanes>
+                                <laneSection s="0.0000000000000000e+00">
+                                    <center>
+                                        <lane id="0" type="none" level="false"/>
+                                    </center>
+                                </laneSection>
+                            </lanes>
+                        </road>
+                    </OpenDRIVE>
+                "#
+            );
+            let drive = OpenDrive::from_xml_str(&source).unwrap();
+            drive.road[0].plan_view.geometry[0].clone()
+        };
+        let at = |geometry: &crate::road::geometry::Geometry, s: f64| {
+            let (x, y, hdg) = geometry.position_at(Length::new::<meter>(s));
+            (x.get::<meter>(), y.get::<meter>(), hdg.get::<radian>())
+        };
+
+        for k in [0.01, -0.01] {
+            let geometry = arc(&k.to_string());
+            let r = 1.0 / k;
+
+            // the end heading is the start heading turned by length times curvature
+            let (x, y, hdg) = at(&geometry, 100.0);
+            assert!((hdg - 100.0 * k).abs() < 1e-12);
+            assert!((x - (100.0 * k).sin() * r).abs() < 1e-9);
+            assert!((y - (1.0 - (100.0 * k).cos()) * r).abs() < 1e-9);
+            assert_eq!(k > 0.0, y > 0.0);
+
+            // all positions keep the radius to the center at (0, 1/k)
+            for s in [0.0, 25.0, 50.0, 75.0] {
+                let (x, y, hdg) = at(&geometry, s);
+                assert!((x.hypot(y - r) - r.abs()).abs() < 1e-9);
+                assert!((hdg - s * k).abs() < 1e-12);
+            }
+
+            // the arc is parameterized by arc length
+            let (x0, y0, _) = at(&geometry, 50.0 - 1e-4);
+            let (x1, y1, _) = at(&geometry, 50.0 + 1e-4);
+            assert!(((x1 - x0).hypot(y1 - y0) - 2e-4).abs() < 1e-9);
+
+            // s outside of [s, s + length] is clamped to the ends of the element
+            assert_eq!(at(&geometry, 0.0), at(&geometry, -5.0));
+            assert_eq!(at(&geometry, 100.0), at(&geometry, 105.0));
+        }
+
+        // a vanishing curvature is evaluated as line
+        let (x, y, hdg) = at(&arc("1e-15"), 100.0);
+        assert!((x - 100.0).abs() < 1e-9);
+        assert_eq!(0.0, y);
+        assert_eq!(0.0, hdg);
+
+        // a tiny curvature just above the threshold still bends without numerical issues
+        let (x, y, _) = at(&arc("1e-9"), 100.0);
+        assert!((x - 100.0).abs() < 1e-9);
+        assert!((y - 1e-9 * 100.0 * 100.0 / 2.0).abs() < 1e-15);
+    }
+
+    #[test]
+    pub fn test_spiral_fresnel() {
+        use crate::road::geometry::spiral::Spiral;
+        use uom::si::angle::radian;
+        use uom::si::curvature::radian_per_meter;
+        use uom::si::f64::{Angle, Curvature, Length};
+        use uom::si::length::meter;
+
+        // (curvStart, curvEnd, length, ds, u, v), integrated with 30 significant digits
+        let reference = [
+            (
+                0.0,
+                0.01,
+                100.0,
+                25.0,
+                24.997558704126483,
+                0.26039850200602055,
+            ),
+            (
+                0.0,
+                0.01,
+                100.0,
+                50.0,
+                49.92193149366025,
+                2.0810093401773635,
+            ),
+            (
+                0.0,
+                0.01,
+                100.0,
+                100.0,
+                97.52876882003446,
+                16.37140473757006,
+            ),
+            (
+                0.01,
+                -0.02,
+                80.0,
+                20.0,
+                19.930478462865146,
+                1.4976605703536732,
+            ),
+            (0.01, -0.02, 80.0, 40.0, 39.7736046234712, 3.991054297665478),
+            (
+                0.01,
+                -0.02,
+                80.0,
+                80.0,
+                79.15152561782331,
+                0.0484672252612885,
+            ),
+            (
+                -0.005,
+                -0.03,
+                60.0,
+                15.0,
+                14.969479565669447,
+                -0.7959734743379717,
+            ),
+            (
+                -0.005,
+                -0.03,
+                60.0,
+                30.0,
+                29.57337877855542,
+                -4.090359560461044,
+            ),
+            (
+                -0.005,
+                -0.03,
+                60.0,
+                60.0,
+                52.7325280111361,
+                -22.14726204187705,
+            ),
+        ];
+
+        for (k0, k1, length, ds, u, v) in reference {
+            let spiral = Spiral {
+                curvature_start: Curvature::new::<radian_per_meter>(k0),
+                curvature_end: Curvature::new::<radian_per_meter>(k1),
+            };
+            let length = Length::new::<meter>(length);
+            let (pu, pv, heading) = spiral.local_pose_at(Length::new::<meter>(ds), length);
+            assert!((pu - u).abs() < 1e-6, "u={pu}, expected {u}");
+            assert!((pv - v).abs() < 1e-6, "v={pv}, expected {v}");
+            let dk = (k1 - k0) / length.get::<meter>();
+            assert!((heading - (k0 * ds + dk * ds * ds / 2.0)).abs() < 1e-12);
+
+            // composed with a start pose, the local frame is rotated and translated
+            let hdg = 0.5_f64;
+            let (x, y, h) = spiral.sample(
+                Length::new::<meter>(ds),
+                length,
+                (
+                    Length::new::<meter>(3.0),
+                    Length::new::<meter>(-4.0),
+                    Angle::new::<radian>(hdg),
+                ),
+            );
+            let (sin, cos) = hdg.sin_cos();
+            assert!((x.get::<meter>() - (3.0 + u * cos - v * sin)).abs() < 1e-6);
+            assert!((y.get::<meter>() - (-4.0 + u * sin + v * cos)).abs() < 1e-6);
+            assert!((h.get::<radian>() - hdg - heading).abs() < 1e-12);
+        }
+
+        // a spiral of constant curvature matches the arc, which turns left for positive values
+        let arc_like = Spiral {
+            curvature_start: Curvature::new::<radian_per_meter>(0.02),
+            curvature_end: Curvature::new::<radian_per_meter>(0.02),
+        };
+        let (u, v, _) =
+            arc_like.local_pose_at(Length::new::<meter>(50.0), Length::new::<meter>(50.0));
+        assert!((u - 1.0_f64.sin() / 0.02).abs() < 1e-6);
+        assert!((v - (1.0 - 1.0_f64.cos()) / 0.02).abs() < 1e-6);
+
+        // spirals follow their heading, whether they are evaluated with Fresnel integrals or, with
+        // a change of curvature below the threshold between both, integrated numerically
+        let spiral = |start: f64, end: f64| Spiral {
+            curvature_start: Curvature::new::<radian_per_meter>(start),
+            curvature_end: Curvature::new::<radian_per_meter>(end),
+        };
+        let simpson = |k0: f64, dk: f64, ds: f64| {
+            let steps = 1000;
+            let h = ds / steps as f64;
+            (0..=steps).fold((0.0, 0.0), |(u, v), i| {
+                let weight = match i {
+                    0 => 1.0,
+                    i if i == steps => 1.0,
+                    i if i % 2 == 1 => 4.0,
+                    _ => 2.0,
+                } * h
+                    / 3.0;
+                let s = i as f64 * h;
+                let (sin, cos) = (k0 * s + dk * s * s / 2.0).sin_cos();
+                (u + weight * cos, v + weight * sin)
+            })
+        };
+        let m = Length::new::<meter>;
+        let length = m(50.0);
+        for end in [
+            0.02,
+            0.02 + Spiral::MIN_CURVATURE_CHANGE * 50.0 / 4.0,
+            0.02 + Spiral::MIN_CURVATURE_CHANGE * 50.0 * 2.0,
+            -0.03,
+        ] {
+            let dk = (end - 0.02) / 50.0;
+            for ds in [0.0, 10.0, 50.0] {
+                let (u, v, heading) = spiral(0.02, end).local_pose_at(m(ds), length);
+                let expected = simpson(0.02, dk, ds);
+                assert!((u - expected.0).abs() < 1e-8, "{end} {ds}");
+                assert!((v - expected.1).abs() < 1e-8, "{end} {ds}");
+                assert_eq!(0.02 * ds + dk * ds * ds / 2.0, heading);
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_poly3_position_at() {
+        use uom::si::angle::radian;
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        let geometry = |hdg: &str, length: &str, element: &str| {
+            let source = format!(
+                r#"
+                    <?xml version="1.0" standalone="yes"?>
+                    <OpenDRIVE>
+                        <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                        </header>
+                        <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                            <planView>
+                                <geometry s="0.0000000000000000e+00" x="1.0e+01" y="2.0e+01" hdg="{hdg}" length="{length}">
+                                    {element}
+                                </geometry>
+                            </planView>
+                            <lanes>
+                                <laneSection s="0.0000000000000000e+00">
+                                    <center>
+                                        <lane id="0" type="none" level="false"/>
+                                    </center>
+                                </laneSection>
+                            </lanes>
+                        </road>
+                    </OpenDRIVE>
+                "#
+            );
+            let drive = OpenDrive::from_xml_str(&source).unwrap();
+            drive.road[0].plan_view.geometry[0].clone()
+        };
+        let at = |geometry: &crate::road::geometry::Geometry, s: f64| {
+            let (x, y, hdg) = geometry.position_at(Length::new::<meter>(s));
+            (x.get::<meter>(), y.get::<meter>(), hdg.get::<radian>())
+        };
+        let assert_close = |actual: (f64, f64, f64), expected: (f64, f64, f64)| {
+            assert!(
+                (actual.0 - expected.0).abs() < 1e-9,
+                "{actual:?} != {expected:?}"
+            );
+            assert!(
+                (actual.1 - expected.1).abs() < 1e-9,
+                "{actual:?} != {expected:?}"
+            );
+            assert!(
+                (actual.2 - expected.2).abs() < 1e-9,
+                "{actual:?} != {expected:?}"
+            );
+        };
+        let half_pi = std::f64::consts::FRAC_PI_2;
+
+        // v = 1 + 0.5u + 0.01u² - 0.001u³, at u = ds = 4 and rotated by 90°
+        let poly3 = geometry(
+            &half_pi.to_string(),
+            "1.0e+01",
+            r#"<poly3 a="1" b="0.5" c="0.01" d="-0.001"/>"#,
+        );
+        assert_close(
+            at(&poly3, 4.0),
+            (10.0 - 3.096, 24.0, half_pi + 0.532_f64.atan()),
+        );
+
+        // u = 2 + 10p and v = 1 + 3p², with a_u != 0 the curve starts off the geometry origin
+        let element = |p_range: &str| {
+            format!(
+                r#"<paramPoly3 aU="2" bU="10" cU="0" dU="0" aV="1" bV="0" cV="3" dV="0" pRange="{p_range}"/>"#
+            )
+        };
+        let normalized = geometry("0.0", "1.0e+01", &element("normalized"));
+        assert_close(at(&normalized, 0.0), (12.0, 21.0, 0.0));
+        // p = ds / length = 0.5
+        assert_close(at(&normalized, 5.0), (17.0, 21.75, 3.0_f64.atan2(10.0)));
+
+        let arc_length = geometry("0.0", "1.0e+01", &element("arcLength"));
+        assert_close(at(&arc_length, 0.0), (12.0, 21.0, 0.0));
+        // p = ds = 0.5
+        assert_close(at(&arc_length, 0.5), (17.0, 21.75, 3.0_f64.atan2(10.0)));
+        // p = ds = 5
+        assert_close(at(&arc_length, 5.0), (62.0, 96.0, 30.0_f64.atan2(10.0)));
+
+        // the curves move along their heading, and s is clamped to the ends of the element
+        for geometry in [&poly3, &normalized, &arc_length] {
+            let length = geometry.length_m();
+            for ds in [0.25, 0.5, 0.75].map(|f| f * length) {
+                let (_, _, hdg) = at(geometry, ds);
+                let (x0, y0, _) = at(geometry, ds - 1e-4);
+                let (x1, y1, _) = at(geometry, ds + 1e-4);
+                assert!(((y1 - y0).atan2(x1 - x0) - hdg).abs() < 1e-6);
+            }
+            assert_eq!(at(geometry, 0.0), at(geometry, -5.0));
+            assert_eq!(at(geometry, length), at(geometry, length + 5.0));
+        }
+    }
+
+    #[test]
+    pub fn test_plan_view_pose_at_joints() {
+        use uom::si::angle::radian;
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        // a line of 50m, a quarter circle of radius 50m turning left and a line heading north
+        let quarter = std::f64::consts::FRAC_PI_2 * 50.0;
+        let source = format!(
+            r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0" x="0" y="0" hdg="0" length="50"><line/></geometry>
+                            <geometry s="50" x="50" y="0" hdg="0" length="{quarter}"><arc curvature="0.02"/></geometry>
+                            <geometry s="{s}" x="100" y="50" hdg="{hdg}" length="20"><line/></geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#,
+            s = 50.0 + quarter,
+            hdg = std::f64::consts::FRAC_PI_2,
+        );
+        let drive = OpenDrive::from_xml_str(&source).unwrap();
+        let plan_view = &drive.road[0].plan_view;
+        let pose = |s: f64| {
+            plan_view
+                .pose_at(Length::new::<meter>(s))
+                .map(|(x, y, hdg)| (x.get::<meter>(), y.get::<meter>(), hdg.get::<radian>()))
+        };
+
+        for joint in [50.0, 50.0 + quarter] {
+            let epsilon = 1e-6;
+            let (bx, by, bh) = pose(joint - epsilon).unwrap();
+            let (ax, ay, ah) = pose(joint + epsilon).unwrap();
+            assert!((bx - ax).hypot(by - ay) < 3.0 * epsilon);
+            assert!((bh - ah).abs() < 1e-6);
+
+            // the end of the previous element is the start of the next one
+            let index = plan_view
+                .geometry
+                .iter()
+                .position(|g| g.s.get::<meter>() == joint)
+                .unwrap();
+            let previous = &plan_view.geometry[index - 1];
+            let (px, py, ph) = previous.position_at(previous.s + previous.length);
+            let (jx, jy, jh) = pose(joint).unwrap();
+            assert!((px.get::<meter>() - jx).abs() < 1e-9);
+            assert!((py.get::<meter>() - jy).abs() < 1e-9);
+            assert!((ph.get::<radian>() - jh).abs() < 1e-9);
+        }
+
+        let (x, y, hdg) = pose(50.0 + quarter / 2.0).unwrap();
+        let half = std::f64::consts::FRAC_PI_4;
+        assert!((x - (50.0 + 50.0 * half.sin())).abs() < 1e-9);
+        assert!((y - 50.0 * (1.0 - half.cos())).abs() < 1e-9);
+        assert!((hdg - half).abs() < 1e-9);
+
+        let end = 70.0 + quarter;
+        let (x, y, _) = pose(end).unwrap();
+        assert!((x - 100.0).abs() < 1e-9 && (y - 70.0).abs() < 1e-9);
+        assert_eq!(None, pose(end + 1e-3));
+        assert_eq!(None, pose(-1e-3));
+
+        // each s is evaluated by the element it belongs to
+        for (index, s) in [(0, 25.0), (1, 50.0 + quarter / 3.0), (2, end - 5.0)] {
+            let (x, y, hdg) = plan_view.geometry[index].position_at(Length::new::<meter>(s));
+            let expected = (x.get::<meter>(), y.get::<meter>(), hdg.get::<radian>());
+            assert_eq!(Some(expected), pose(s));
+        }
+    }
+
+    #[test]
+    pub fn test_elevation_profile_segments() {
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <elevationProfile>
+                            <elevation s="0" a="1.0" b="0.1" c="0" d="0"/>
+                            <elevation s="20" a="3.0" b="0" c="0.01" d="0"/>
+                            <elevation s="50" a="12.0" b="-0.2" c="0" d="0.0001"/>
+                        </elevationProfile>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let profile = drive.road[0].elevation_profile.as_ref().unwrap();
+        let z = |s: f64| profile.elevation_at(s);
+
+        assert!((z(0.0) - 1.0).abs() < 1e-12);
+        assert!((z(10.0) - 2.0).abs() < 1e-12);
+        // exactly on a boundary the later segment applies, with ds = 0
+        assert!((z(20.0) - 3.0).abs() < 1e-12);
+        assert!((z(30.0) - 4.0).abs() < 1e-12);
+        assert!((z(50.0) - 12.0).abs() < 1e-12);
+        // ds = 10: 12 - 2 + 0.1
+        assert!((z(60.0) - 10.1).abs() < 1e-12);
+
+        let empty = crate::road::profile::ElevationProfile {
+            elevation: Vec::new(),
+            additional_data: Default::default(),
+        };
+        assert_eq!(0.0, empty.elevation_at(5.0));
+    }
+
+    #[test]
+    pub fn test_lane_width_at() {
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0">
+                                <center><lane id="0" type="none"/></center>
+                                <right>
+                                    <lane id="-1" type="driving">
+                                        <width sOffset="0" a="3.0" b="0.1" c="0" d="0"/>
+                                        <width sOffset="10" a="4.0" b="0" c="0.01" d="-0.001"/>
+                                        <width sOffset="30" a="2.5" b="0" c="0" d="0"/>
+                                    </lane>
+                                    <lane id="-2" type="sidewalk"><border sOffset="0" a="9" b="0" c="0" d="0"/></lane>
+                                </right>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let section = &drive.road[0].lanes.lane_section[0];
+        let driving = section.lane(-1).unwrap();
+        let width = |ds: f64| {
+            driving
+                .width_at(Length::new::<meter>(ds))
+                .unwrap()
+                .get::<meter>()
+        };
+
+        assert!((width(0.0) - 3.0).abs() < 1e-12);
+        assert!((width(5.0) - 3.5).abs() < 1e-12);
+        // on the boundary, the later record applies with x = 0
+        assert!((width(10.0) - 4.0).abs() < 1e-12);
+        // x = 10: 4 + 1 - 1
+        assert!((width(20.0) - 4.0).abs() < 1e-12);
+        assert!(
+            (width(29.0) - (4.0 + 19.0 * 19.0 * 0.01 - 19.0_f64.powi(3) * 0.001)).abs() < 1e-12
+        );
+        assert!((width(30.0) - 2.5).abs() < 1e-12);
+        assert_eq!(
+            Some(10.0),
+            driving
+                .width_record_at(Length::new::<meter>(12.0))
+                .map(|w| w.s_offset.get::<meter>())
+        );
+
+        // lanes described by borders have no width
+        let sidewalk = section.lane(-2).unwrap();
+        assert_eq!(None, sidewalk.width_at(Length::new::<meter>(5.0)));
+    }
+
+    #[test]
+    pub fn test_lane_offset_segments() {
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneOffset s="10" a="0.5" b="0.1" c="0" d="0"/>
+                            <laneOffset s="40" a="1.0" b="0" c="-0.001" d="0"/>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let lanes = &drive.road[0].lanes;
+        let offset = |s: f64| lanes.offset_at(s);
+
+        // before the first element, the center lane lies on the reference line
+        assert_eq!(0.0, offset(5.0));
+        assert!((offset(10.0) - 0.5).abs() < 1e-12);
+        // the earlier element applies up to the start of the next one, even if they do not meet
+        assert!((offset(39.999) - (0.5 + 2.9999)).abs() < 1e-9);
+        assert!((offset(40.0) - 1.0).abs() < 1e-12);
+        assert!((offset(50.0) - 0.9).abs() < 1e-12);
+    }
+
+    #[test]
+    pub fn test_road_to_inertial() {
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        // a left bend of radius 50m around the center (0, 50), rising by 0.1m per meter
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <arc curvature="0.02"/>
+                            </geometry>
+                        </planView>
+                        <elevationProfile>
+                            <elevation s="0" a="2" b="0.1" c="0" d="0"/>
+                        </elevationProfile>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let road = &drive.road[0];
+        let inertial = |s: f64, t: f64| {
+            road.to_inertial(Length::new::<meter>(s), Length::new::<meter>(t))
+                .map(|(x, y, z)| (x.get::<meter>(), y.get::<meter>(), z.get::<meter>()))
+        };
+
+        let s = 25.0 * std::f64::consts::PI;
+        let (x, y, z) = inertial(s, 0.0).unwrap();
+        assert!((x - 50.0).abs() < 1e-9 && (y - 50.0).abs() < 1e-9);
+        assert!((z - (2.0 + 0.1 * s)).abs() < 1e-9);
+
+        // t moves towards the center on the inside of the bend and away from it on the outside
+        let (x, y, z_inside) = inertial(s, 5.0).unwrap();
+        assert!((x - 45.0).abs() < 1e-9 && (y - 50.0).abs() < 1e-9);
+        assert!((x.hypot(y - 50.0) - 45.0).abs() < 1e-9);
+        let (x, y, z_outside) = inertial(s, -5.0).unwrap();
+        assert!((x - 55.0).abs() < 1e-9 && (y - 50.0).abs() < 1e-9);
+        assert!((x.hypot(y - 50.0) - 55.0).abs() < 1e-9);
+        assert_eq!(z_inside, z_outside);
+
+        assert_eq!(None, inertial(100.5, 0.0));
+        assert_eq!(None, inertial(-0.5, 0.0));
+    }
+
+    #[test]
+    pub fn test_road_project() {
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        // a left bend of radius 50m around the center (0, 50)
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <arc curvature="0.02"/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let road = &drive.road[0];
+        let project = |x: f64, y: f64, tolerance: f64| {
+            road.project(
+                Length::new::<meter>(x),
+                Length::new::<meter>(y),
+                Length::new::<meter>(tolerance),
+            )
+            .map(|(s, t, d)| (s.get::<meter>(), t.get::<meter>(), d.get::<meter>()))
+        };
+
+        for s in [10.0, 25.0 * std::f64::consts::PI, 90.0] {
+            for t in [4.0, -4.0, 0.0] {
+                let (x, y, _) = road
+                    .to_inertial(Length::new::<meter>(s), Length::new::<meter>(t))
+                    .unwrap();
+                let (ps, pt, distance) = project(x.get::<meter>(), y.get::<meter>(), 1e-9).unwrap();
+                assert!((ps - s).abs() < 1e-6, "{ps} != {s}");
+                assert!((pt - t).abs() < 1e-6, "{pt} != {t}");
+                assert!((distance - t.abs()).abs() < 1e-6);
+            }
+        }
+
+        // the inside of the bend is to the left, with a positive t
+        let (_, t, _) = project(0.0, 40.0, 1e-9).unwrap();
+        assert!(t > 0.0);
+        let (_, t, _) = project(60.0, 50.0, 1e-9).unwrap();
+        assert!((t + 10.0).abs() < 1e-6);
+
+        // a point before the start of the reference line has no perpendicular foot on it
+        assert_eq!(None, project(-5.0, 1.0, 1e-6));
+        let (s, _, _) = project(-5e-7, 1.0, 1e-6).unwrap();
+        assert_eq!(0.0, s);
+    }
+
+    #[test]
+    pub fn test_sample_reference_line() {
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        let sample = |geometry: &str, max_chord_error: f64| {
+            let source = format!(
+                r#"
+                    <?xml version="1.0" standalone="yes"?>
+                    <OpenDRIVE>
+                        <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                        </header>
+                        <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                            <planView>
+                                <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                    {geometry}
+                                </geometry>
+                            </planView>
+                            <lanes>
+                                <laneSection s="0.0000000000000000e+00">
+                                    <center>
+                                        <lane id="0" type="none" level="false"/>
+                                    </center>
+                                </laneSection>
+                            </lanes>
+                        </road>
+                    </OpenDRIVE>
+                "#
+            );
+            let drive = OpenDrive::from_xml_str(&source).unwrap();
+            drive.road[0]
+                .sample_reference_line(Length::new::<meter>(max_chord_error))
+                .into_iter()
+                .map(|(x, y)| (x.get::<meter>(), y.get::<meter>()))
+                .collect::<Vec<_>>()
+        };
+
+        let coarse = sample("<line/>", 0.1);
+        assert_eq!(vec![(0.0, 0.0), (100.0, 0.0)], coarse);
+        assert_eq!(coarse, sample("<line/>", 0.001));
+
+        // a left bend of radius 50m around the center (0, 50)
+        let arc = r#"<arc curvature="0.02"/>"#;
+        let coarse = sample(arc, 0.1);
+        let fine = sample(arc, 0.001);
+        assert!(coarse.len() > 2);
+        assert!(fine.len() > coarse.len());
+        for (points, max_chord_error) in [(&coarse, 0.1), (&fine, 0.001)] {
+            assert_eq!((0.0, 0.0), points[0]);
+            for pair in points.windows(2) {
+                let (x, y) = ((pair[0].0 + pair[1].0) / 2.0, (pair[0].1 + pair[1].1) / 2.0);
+                assert!(50.0 - x.hypot(y - 50.0) <= max_chord_error);
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_lane_boundaries() {
+        use uom::si::f64::Length;
+        use uom::si::length::meter;
+
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <left>
+                                    <lane id="2" type="sidewalk"><width sOffset="0" a="2" b="0" c="0" d="0"/></lane>
+                                    <lane id="1" type="driving"><width sOffset="0" a="3.5" b="0" c="0" d="0"/></lane>
+                                </left>
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                                <right>
+                                    <lane id="-1" type="driving"><width sOffset="0" a="3.5" b="0" c="0" d="0"/></lane>
+                                    <lane id="-2" type="sidewalk"><width sOffset="0" a="2" b="0" c="0" d="0"/></lane>
+                                </right>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let drive = OpenDrive::from_xml_str(source).unwrap();
+        let road = &drive.road[0];
+        let section = &road.lanes.lane_section[0];
+        let meters = |points: &[(Length, Length, Length)]| {
+            points
+                .iter()
+                .map(|(x, y, z)| (x.get::<meter>(), y.get::<meter>(), z.get::<meter>()))
+                .collect::<Vec<_>>()
+        };
+        let at_y = |y: f64| {
+            [0.0, 25.0, 50.0, 75.0, 100.0]
+                .into_iter()
+                .map(|x| (x, y, 0.0))
+                .collect::<Vec<_>>()
+        };
+
+        let boundaries = section.lane_boundaries(road, Length::new::<meter>(25.0));
+        let ids = boundaries.iter().map(|b| b.lane_id).collect::<Vec<_>>();
+        assert_eq!(vec![2, 1, 0, -1, -2], ids);
+        for (boundary, inner, outer) in [
+            (&boundaries[0], 3.5, 5.5),
+            (&boundaries[1], 0.0, 3.5),
+            (&boundaries[2], 0.0, 0.0),
+            (&boundaries[3], 0.0, -3.5),
+            (&boundaries[4], -3.5, -5.5),
+        ] {
+            assert_eq!(at_y(inner), meters(&boundary.inner));
+            assert_eq!(at_y(outer), meters(&boundary.outer));
+        }
+
+        // the section ends at the start of the next one
+        let mut drive = drive.clone();
+        let mut next = drive.road[0].lanes.lane_section[0].clone();
+        next.s = 50.0;
+        drive.road[0].lanes.lane_section.push(next);
+        let road = &drive.road[0];
+        let boundaries =
+            road.lanes.lane_section[0].lane_boundaries(road, Length::new::<meter>(30.0));
+        let xs = boundaries[0]
+            .inner
+            .iter()
+            .map(|p| p.0.get::<meter>())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![0.0, 25.0, 50.0], xs);
+
+        // a step of zero samples only the start and end of the section
+        let boundaries =
+            road.lanes.lane_section[0].lane_boundaries(road, Length::new::<meter>(0.0));
+        let xs = boundaries[0]
+            .inner
+            .iter()
+            .map(|p| p.0.get::<meter>())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![0.0, 50.0], xs);
+    }
+
+    #[test]
+    pub fn test_road_by_id() {
+        let source = r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                        <planView>
+                            <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                <line/>
+                            </geometry>
+                        </planView>
+                        <lanes>
+                            <laneSection s="0.0000000000000000e+00">
+                                <center>
+                                    <lane id="0" type="none" level="false"/>
+                                </center>
+                            </laneSection>
+                        </lanes>
+                    </road>
+                </OpenDRIVE>
+            "#;
+        let mut drive = OpenDrive::from_xml_str(source).unwrap();
+        let mut second = drive.road[0].clone();
+        second.id = "02".to_string();
+        second.name = Some("second".to_string());
+        let mut duplicate = drive.road[0].clone();
+        duplicate.name = Some("duplicate".to_string());
+        drive.road.extend([second, duplicate]);
+
+        assert_eq!(Some(&drive.road[0]), drive.road_by_id("1"));
+        assert_eq!(Some(&drive.road[1]), drive.road_by_id("02"));
+        // ids are not compared as numbers
+        assert_eq!(None, drive.road_by_id("2"));
+        assert_eq!(None, drive.road_by_id(""));
+
+        drive.road_by_id_mut("1").unwrap().name = Some("first".to_string());
+        assert_eq!(Some("first"), drive.road[0].name.as_deref());
+        assert_eq!(Some("duplicate"), drive.road[2].name.as_deref());
+        assert!(drive.road_by_id_mut("2").is_none());
+    }
+
+    #[test]
+    pub fn test_junction_connecting_roads() {
+        // a T-junction joining the arms 1, 2 and 3 with a connecting road for each turn
+        let road = |id: &str, junction: &str, link: &str| {
+            format!(
+                r#"<road rule="RHT" name="" length="1.0e+01" id="{id}" junction="{junction}">
+                    <link>{link}</link>
+                    <planView>
+                        <geometry s="0.0e+00" x="0.0e+00" y="0.0e+00" hdg="0.0e+00" length="1.0e+01">
+                            <line/>
+                        </geometry>
+                    </planView>
+                    <lanes>
+                        <laneSection s="0.0e+00">
+                            <center><lane id="0" type="none" level="false"/></center>
+                        </laneSection>
+                    </lanes>
+                </road>"#
+            )
+        };
+        let turns = [
+            ("10", "1", "2"),
+            ("11", "1", "3"),
+            ("12", "2", "1"),
+            ("13", "2", "3"),
+        ];
+        let roads = ["1", "2", "3"].map(|id| {
+            road(
+                id,
+                "-1",
+                r#"<successor elementType="junction" elementId="100"/>"#,
+            )
+        });
+        let mut connections = String::new();
+        let connecting = turns.map(|(id, from, to)| {
+            connections.push_str(&format!(
+                r#"<connection id="{id}" incomingRoad="{from}" connectingRoad="{id}" contactPoint="start"/>"#
+            ));
+            road(
+                id,
+                "100",
+                &format!(
+                    r#"<predecessor elementType="road" elementId="{from}" contactPoint="end"/>
+                    <successor elementType="road" elementId="{to}" contactPoint="end"/>"#
+                ),
+            )
+        });
+        let source = format!(
+            r#"
+                <?xml version="1.0" standalone="yes"?>
+                <OpenDRIVE>
+                    <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                    </header>
+                    {roads}{connecting}
+                    <junction id="100" name="T">
+                        {connections}
+                        <connection id="20" incomingRoad="3" linkedRoad="1"/>
+                    </junction>
+                </OpenDRIVE>
+            "#,
+            roads = roads.concat(),
+            connecting = connecting.concat(),
+        );
+        let drive = OpenDrive::from_xml_str(&source).unwrap();
+
+        let junction = drive.junction_by_id("100").unwrap();
+        assert_eq!(
+            vec!["10", "11", "12", "13"],
+            junction.connecting_road_ids().collect::<Vec<_>>()
+        );
+        assert!(drive.junction_by_id("1").is_none());
+
+        // walk from the incoming road 1 through the junction to the outgoing roads
+        let incoming = drive.road_by_id("1").unwrap();
+        let successor = incoming.link.as_ref().unwrap().successor.as_ref().unwrap();
+        let junction = drive.junction_by_id(&successor.element_id).unwrap();
+        let outgoing = junction
+            .connection
+            .iter()
+            .filter(|c| c.incoming_road.as_deref() == Some(incoming.id.as_str()))
+            .filter_map(|c| drive.road_by_id(c.connecting_road.as_deref()?))
+            .filter_map(|road| Some(road.link.as_ref()?.successor.as_ref()?.element_id.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["2", "3"], outgoing);
+    }
+
+    #[test]
+    pub fn test_build_index() {
+        use crate::core::network_index::NetworkIndexError;
+
+        let document = |second: &str| {
+            format!(
+                r#"
+                    <?xml version="1.0" standalone="yes"?>
+                    <OpenDRIVE>
+                        <header revMajor="1" revMinor="7" name="" version="1.00" date="Tue Feb 25 13:02:27 2020" north="0.0000000000000000e+00" south="0.0000000000000000e+00" east="0.0000000000000000e+00" west="0.0000000000000000e+00">
+                        </header>
+                        <road rule="RHT" name="" length="1.0000000000000000e+02" id="1" junction="-1">
+                            <planView>
+                                <geometry s="0.0000000000000000e+00" x="0.0000000000000000e+00" y="0.0000000000000000e+00" hdg="0.0000000000000000e+00" length="1.0000000000000000e+02">
+                                    <line/>
+                                </geometry>
+                            </planView>
+                            <lanes>
+                                <laneSection s="0.0000000000000000e+00">
+                                    <center>
+                                        <lane id="0" type="none" level="false"/>
+                                    </center>
+                                </laneSection>
+                            </lanes>
+                            <signals>
+                                <signal id="5" s="1.2e+01" t="-3.0e+00" zOffset="2.0e+00" dynamic="yes" orientation="-" type="1000001" subtype="-1" country="DE"/>
+                            </signals>
+                        </road>
+                        <road rule="RHT" name="" length="5.0e+01" id="{second}" junction="-1">
+                            <planView>
+                                <geometry s="0.0e+00" x="0.0e+00" y="1.0e+01" hdg="0.0e+00" length="5.0e+01">
+                                    <line/>
+                                </geometry>
+                            </planView>
+                            <lanes>
+                                <laneSection s="0.0e+00">
+                                    <center>
+                                        <lane id="0" type="none" level="false"/>
+                                    </center>
+                                    <right>
+                                        <lane id="-1" type="driving" level="false"/>
+                                        <lane id="-2" type="sidewalk" level="false"/>
+                                    </right>
+                                </laneSection>
+                                <laneSection s="2.5e+01">
+                                    <center>
+                                        <lane id="0" type="none" level="false"/>
+                                    </center>
+                                    <right>
+                                        <lane id="-1" type="driving" level="false"/>
+                                    </right>
+                                </laneSection>
+                            </lanes>
+                        </road>
+                    </OpenDRIVE>
+                "#
+            )
+        };
+        let source = document("2");
+        let drive = OpenDrive::from_xml_str(&source).unwrap();
+        let index = drive.build_index().unwrap();
+        assert_eq!(Some(1), index.road_position("2"));
+        assert!(std::ptr::eq(&drive.road[0], index.road("1").unwrap()));
+        assert_eq!(None, index.road("3"));
+        assert_eq!(None, index.junction_position("1"));
+        assert_eq!(Some((0, 0)), index.signal_position("5"));
+        let (road, signal) = index.signal("5").unwrap();
+        assert_eq!(("1", "5"), (road.id.as_str(), signal.id.as_str()));
+
+        // a second road `1` is reported instead of replacing the first one in the index
+        let drive = OpenDrive::from_xml_str(&document("1")).unwrap();
+        assert_eq!(
+            vec![NetworkIndexError::DuplicateId {
+                element: "road",
+                id: "1".to_string(),
+            }],
+            drive.build_index().unwrap_err()
+        );
+    }
 }
@@ -7,6 +7,7 @@ use uom::si::length::meter;
 
 /// Specifies a border along certain outline points.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Border {
     pub corner_reference: Vec<CornerReference>,
     /// ID of the outline to use
@@ -67,7 +68,7 @@ where
         Ok(Self {
             outline_id: read.attribute("outlineId")?,
             r#type: read.attribute("type")?,
-            use_complete_outline: read.attribute_opt("useCompleteOutline")?,
+            use_complete_outline: read.attribute_bool_opt("useCompleteOutline")?,
             width: read.attribute("width").map(Length::new::<meter>)?,
             corner_reference,
             additional_data,
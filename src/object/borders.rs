@@ -5,6 +5,7 @@ use vec1::Vec1;
 
 /// Objects may have a border, that is, a frame of a defined width. Different border types are available.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Borders {
     pub border: Vec1<Border>,
     pub additional_data: AdditionalData,
@@ -6,6 +6,7 @@ use uom::si::f64::Length;
 use uom::si::length::meter;
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Bridge {
     /// Unique ID within database
     pub id: String,
@@ -1,9 +1,22 @@
 use crate::object::corner_local::CornerLocal;
 use crate::object::corner_road::CornerRoad;
+use uom::si::length::meter;
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub enum Corner {
     Road(CornerRoad),
     Local(CornerLocal),
 }
+
+impl Corner {
+    /// The position of the corner in meters, which is `(s, t)` in road coordinates for
+    /// [`Corner::Road`] and `(u, v)` in local coordinates for [`Corner::Local`]
+    pub fn position_m(&self) -> (f64, f64) {
+        match self {
+            Corner::Road(road) => (road.s.get::<meter>(), road.t.get::<meter>()),
+            Corner::Local(local) => (local.u.get::<meter>(), local.v.get::<meter>()),
+        }
+    }
+}
@@ -6,6 +6,7 @@ use uom::si::length::meter;
 /// to the object pivot point in local u/v-coordinates. The insertion point and the orientation of
 /// the object are given by the @s, @t, @zOffset and @hdg attributes of the  element.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CornerLocal {
     /// Height of the object at this corner, along the z-axis
     pub height: Length,
@@ -4,6 +4,7 @@ use xml::reader::XmlEvent;
 
 /// Specifies a point by referencing an existing outline point.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct CornerReference {
     /// Identifier of the referenced outline point
@@ -4,6 +4,7 @@ use uom::si::length::meter;
 
 /// Defines a corner point on the object’s outline in road coordinates.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CornerRoad {
     /// dz of the corner relative to road reference line
     pub dz: Length,
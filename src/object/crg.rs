@@ -3,6 +3,7 @@ use std::borrow::Cow;
 /// Elevation data described in {GLO_VAR_STA_ASAM_OpenCRG} are represented by the `<CRG>` element
 /// within the `<surface>` element.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Crg {
     /// Name of the file containing the CRG data.
     pub file: Option<String>,
@@ -45,7 +46,7 @@ where
     fn try_from(mut read: crate::parser::ReadContext<'a, I>) -> Result<Self, Self::Error> {
         read.expecting_no_child_elements_for(Self {
             file: read.attribute_opt("file")?,
-            hide_road_surface_crg: read.attribute_opt("hideRoadSurfaceCRG")?,
+            hide_road_surface_crg: read.attribute_bool_opt("hideRoadSurfaceCRG")?,
             z_scale: read.attribute_opt("zScale")?,
         })
     }
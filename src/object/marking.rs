@@ -10,6 +10,7 @@ use uom::si::length::meter;
 /// Specifies a marking that is either attached to one side of the object bounding box or
 /// referencing outline points.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Marking {
     pub corner_reference: Vec<CornerReference>,
     /// Color of the marking
@@ -5,12 +5,19 @@ use vec1::Vec1;
 
 /// Describes the appearance of the parking space with multiple marking elements.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Markings {
     pub marking: Vec1<Marking>,
     pub additional_data: AdditionalData,
 }
 
 impl Markings {
+    /// The `<marking>` elements, of which there is at least one
+    #[inline]
+    pub fn markings(&self) -> &[Marking] {
+        self.marking.as_slice()
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
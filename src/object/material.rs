@@ -4,6 +4,7 @@ use std::borrow::Cow;
 /// surface but deviate from the standard road material. Supersedes the material specified in the
 /// `<road material>` element and is valid only within the outline of the parent road object.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Material {
     /// Friction value, depending on application
     pub friction: Option<f64>,
@@ -30,6 +30,7 @@ pub mod lane_validity;
 pub mod marking;
 pub mod markings;
 pub mod material;
+pub mod object_pose;
 pub mod objects;
 pub mod orientation;
 pub mod outline;
@@ -51,6 +52,7 @@ pub mod tunnel_type;
 ///   - For an angular object: definition of the width, length and height.
 ///   - For a circular object: definition of the radius and height.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Object {
     /// Indicates whether the object is dynamic or static, default value is “no” (static). Dynamic
     /// object cannot change its position.
@@ -70,6 +72,7 @@ pub struct Object {
     /// - "+" = valid in positive s-direction
     /// - "-" = valid in negative s-direction
     /// - "none" = valid in both directions
+    ///
     /// (does not affect the heading)
     pub orientation: Option<Orientation>,
     /// Alternative to @pitch and @roll. If true, the object is vertically perpendicular to the road
@@ -110,6 +113,48 @@ pub struct Object {
 }
 
 impl Object {
+    /// [`Object::s`] in meters
+    #[inline]
+    pub fn s_m(&self) -> f64 {
+        self.s.get::<meter>()
+    }
+
+    /// [`Object::t`] in meters
+    #[inline]
+    pub fn t_m(&self) -> f64 {
+        self.t.get::<meter>()
+    }
+
+    /// [`Object::z_offset`] in meters
+    #[inline]
+    pub fn z_offset_m(&self) -> f64 {
+        self.z_offset.get::<meter>()
+    }
+
+    /// [`Object::hdg`] in radians
+    #[inline]
+    pub fn hdg_rad(&self) -> Option<f64> {
+        self.hdg.map(|hdg| hdg.get::<radian>())
+    }
+
+    /// The parking space details of this object, if it is a parking space
+    #[inline]
+    pub fn parking(&self) -> Option<&ParkingSpace> {
+        self.parking_space.as_ref()
+    }
+
+    /// An object is either angular (described by @width, @length and @height) or circular
+    /// (described by @radius and @height), but not both.
+    pub fn validate_shape(&self) -> Result<(), ShapeError> {
+        if self.radius.is_some() && (self.width.is_some() || self.length.is_some()) {
+            Err(ShapeError::AngularAndCircular {
+                object: self.id.clone(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
@@ -183,6 +228,12 @@ impl Object {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ShapeError {
+    #[error("Object `{object}` specifies @radius together with @width or @length")]
+    AngularAndCircular { object: String },
+}
+
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Object
 where
     I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
@@ -227,7 +278,7 @@ where
                 .map(Length::new::<meter>),
             name: read.attribute_opt("name")?,
             orientation: read.attribute_opt("orientation")?,
-            perp_to_road: read.attribute_opt("perpToRoad")?,
+            perp_to_road: read.attribute_bool_opt("perpToRoad")?,
             pitch: read
                 .attribute_opt::<f64>("pitch")?
                 .map(Angle::new::<radian>),
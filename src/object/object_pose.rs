@@ -0,0 +1,43 @@
+use uom::si::angle::radian;
+use uom::si::f64::{Angle, Length};
+use uom::si::length::meter;
+
+/// The position and orientation of an object in the inertial system, see [`Road::object_pose`]
+///
+/// [`Road::object_pose`]: crate::road::Road::object_pose
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectPose {
+    /// Position of the object's origin (x inertial)
+    pub x: Length,
+    /// Position of the object's origin (y inertial)
+    pub y: Length,
+    /// Position of the object's origin (z inertial)
+    pub z: Length,
+    /// Heading around the z-axis of the inertial system
+    pub hdg: Angle,
+    /// Pitch around the y-axis after applying the heading
+    pub pitch: Angle,
+    /// Roll around the x-axis after applying heading and pitch
+    pub roll: Angle,
+}
+
+impl ObjectPose {
+    /// Transforms the local coordinates `(u, v, z)` of the object into the inertial system
+    pub fn local_to_world(&self, u: Length, v: Length, z: Length) -> (Length, Length, Length) {
+        let (u, v, z) = (u.get::<meter>(), v.get::<meter>(), z.get::<meter>());
+        let (sin_h, cos_h) = self.hdg.get::<radian>().sin_cos();
+        let (sin_p, cos_p) = self.pitch.get::<radian>().sin_cos();
+        let (sin_r, cos_r) = self.roll.get::<radian>().sin_cos();
+
+        // roll around x, then pitch around y, then heading around z
+        let (v, z) = (v * cos_r - z * sin_r, v * sin_r + z * cos_r);
+        let (u, z) = (u * cos_p + z * sin_p, z * cos_p - u * sin_p);
+        let (u, v) = (u * cos_h - v * sin_h, u * sin_h + v * cos_h);
+
+        (
+            self.x + Length::new::<meter>(u),
+            self.y + Length::new::<meter>(v),
+            self.z + Length::new::<meter>(z),
+        )
+    }
+}
@@ -7,6 +7,7 @@ use std::borrow::Cow;
 
 /// Container for all objects along a road
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Objects {
     pub object: Vec<Object>,
@@ -17,6 +18,14 @@ pub struct Objects {
 }
 
 impl Objects {
+    /// Iterates the objects in ascending order of their s-coordinate without reordering the
+    /// underlying `Vec`. Objects with equal s-coordinates keep their document order.
+    pub fn iter_sorted_by_s(&self) -> impl Iterator<Item = &Object> {
+        let mut objects = self.object.iter().collect::<Vec<_>>();
+        objects.sort_by(|a, b| a.s.value.total_cmp(&b.s.value));
+        objects.into_iter()
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
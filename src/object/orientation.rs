@@ -1,6 +1,7 @@
 pub use allow_deprecated::ObjectType;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub enum Orientation {
     Plus,
@@ -10,14 +11,15 @@ pub enum Orientation {
 
 impl_from_str_as_str!(
     Orientation,
-    "+" => Plus,
-    "-" => Minus,
-    "none" => None,
+    "+" | "plus" | "positive" => Plus,
+    "-" | "minus" | "negative" => Minus,
+    "none" | "both" => None,
 );
 
 #[allow(deprecated)]
 mod allow_deprecated {
     #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
     pub enum ObjectType {
         /// i.e. unknown
@@ -60,6 +62,37 @@ mod allow_deprecated {
         RoadMark,
     }
 
+    impl ObjectType {
+        /// Whether the type is deprecated by the standard, like the types of dynamic objects
+        /// such as `car` or `pedestrian`
+        pub fn is_deprecated(&self) -> bool {
+            matches!(
+                self,
+                Self::Car
+                    | Self::Van
+                    | Self::Bus
+                    | Self::Trailer
+                    | Self::Bike
+                    | Self::Motorbike
+                    | Self::Tram
+                    | Self::Train
+                    | Self::Pedestrian
+                    | Self::Wind
+            )
+        }
+
+        /// The type to use instead of a deprecated type. The standard offers no replacement for
+        /// the deprecated types, so this is [`ObjectType::Obstacle`] for them and the type itself
+        /// otherwise.
+        pub fn modernized(&self) -> Self {
+            if self.is_deprecated() {
+                Self::Obstacle
+            } else {
+                self.clone()
+            }
+        }
+    }
+
     impl_from_str_as_str!(
         ObjectType,
         "none" => None,
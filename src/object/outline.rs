@@ -14,6 +14,7 @@ use vec1::Vec1;
 /// ASAM OpenDRIVE 1.4 outline definitions (without `<outlines>` parent element) shall still be
 /// supported.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Outline {
     /// If true, the outline describes an area, not a linear feature
     pub closed: Option<bool>,
@@ -30,6 +31,47 @@ pub struct Outline {
 }
 
 impl Outline {
+    /// The signed area enclosed by the corners in square meters, computed with the shoelace
+    /// formula on [`Corner::position_m`]. The area is positive for counter-clockwise corners, as
+    /// recommended by the standard, and negative for clockwise corners. The outline is closed
+    /// implicitly from the last to the first corner.
+    pub fn area(&self) -> f64 {
+        self.edges()
+            .map(|((x0, y0), (x1, y1))| x0 * y1 - x1 * y0)
+            .sum::<f64>()
+            / 2.0
+    }
+
+    /// The centroid of the area enclosed by the corners, in the same coordinates as
+    /// [`Outline::area`]. Degenerate outlines without area yield the mean of the corners.
+    pub fn centroid(&self) -> (f64, f64) {
+        let area = self.area();
+        if area == 0.0 {
+            let count = self.choice.len() as f64;
+            let (x, y) = self
+                .choice
+                .iter()
+                .map(Corner::position_m)
+                .fold((0.0, 0.0), |(sx, sy), (x, y)| (sx + x, sy + y));
+            return (x / count, y / count);
+        }
+
+        let (x, y) = self
+            .edges()
+            .map(|((x0, y0), (x1, y1))| {
+                let cross = x0 * y1 - x1 * y0;
+                ((x0 + x1) * cross, (y0 + y1) * cross)
+            })
+            .fold((0.0, 0.0), |(sx, sy), (x, y)| (sx + x, sy + y));
+        (x / (6.0 * area), y / (6.0 * area))
+    }
+
+    /// Pairs of consecutive corner positions, including the edge from the last to the first
+    fn edges(&self) -> impl Iterator<Item = ((f64, f64), (f64, f64))> + '_ {
+        let positions = self.choice.iter().map(Corner::position_m);
+        positions.clone().zip(positions.cycle().skip(1))
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
@@ -79,11 +121,11 @@ where
         );
 
         Ok(Self {
-            closed: read.attribute_opt("closed")?,
+            closed: read.attribute_bool_opt("closed")?,
             fill_type: read.attribute_opt("fillType")?,
             id: read.attribute_opt("id")?,
             lane_type: read.attribute_opt("laneType")?,
-            outer: read.attribute_opt("outer")?,
+            outer: read.attribute_bool_opt("outer")?,
             choice: Vec1::try_from_vec(choice).map_err(|_| {
                 crate::parser::Error::missing_element(
                     read.path().to_string(),
@@ -7,6 +7,7 @@ use vec1::Vec1;
 /// road reference line. The inner area of the described outline may be filled with a filling type,
 /// such as grass, concrete, asphalt, or pavement.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Outlines {
     pub outline: Vec1<Outline>,
     pub additional_data: AdditionalData,
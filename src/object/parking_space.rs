@@ -1,8 +1,10 @@
 use crate::object::access::Access;
+use crate::object::marking::Marking;
 use std::borrow::Cow;
 
 /// Details for a parking space may be added to the `<object>` element.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct ParkingSpace {
     /// Access definitions for the parking space. Parking spaces tagged with "women" and
@@ -10,6 +12,8 @@ pub struct ParkingSpace {
     pub access: Access,
     /// Free text, depending on application
     pub restrictions: Option<String>,
+    /// Markings of the parking space, as written by OpenDRIVE 1.4 and tools keeping that layout
+    pub marking: Vec<Marking>,
 }
 
 impl ParkingSpace {
@@ -30,7 +34,9 @@ impl ParkingSpace {
         &self,
         mut visitor: impl FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
     ) -> xml::writer::Result<()> {
-        visit_children!(visitor);
+        for marking in &self.marking {
+            visit_children!(visitor, "marking" => marking);
+        }
         Ok(())
     }
 }
@@ -41,9 +47,17 @@ where
     type Error = Box<crate::parser::Error>;
 
     fn try_from(mut read: crate::parser::ReadContext<'a, I>) -> Result<Self, Self::Error> {
-        read.expecting_no_child_elements_for(Self {
+        let mut marking = Vec::new();
+
+        match_child_eq_ignore_ascii_case!(
+            read,
+            "marking" => Marking => |v| marking.push(v),
+        );
+
+        Ok(Self {
             access: read.attribute("access")?,
             restrictions: read.attribute_opt("restrictions")?,
+            marking,
         })
     }
 }
@@ -10,6 +10,7 @@ use uom::si::length::meter;
 /// element consists of a main element and an optional lane validity element. The rules for validity
 /// elements are the same as for objects.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectReference {
     /// Unique ID of the referred object within the database
     pub id: String,
@@ -1,3 +1,4 @@
+use crate::core::s_range::SRange;
 use std::borrow::Cow;
 use uom::si::f64::Length;
 use uom::si::length::meter;
@@ -6,6 +7,7 @@ use uom::si::length::meter;
 /// object shall overrule the attributes from the original object. If attributes are omitted in the
 /// repeated objects, the attributes from the original object apply.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Repeat {
     /// Distance between two instances of the object;
     /// If this value is zero, then the object is treated like a continuous feature, for example, a
@@ -43,6 +45,12 @@ pub struct Repeat {
 }
 
 impl Repeat {
+    /// The range `[s, s + length)` of the road covered by the repeated objects
+    #[inline]
+    pub fn s_range(&self) -> SRange {
+        SRange::new(self.s, self.s + self.length)
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
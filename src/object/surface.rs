@@ -4,6 +4,7 @@ use std::borrow::Cow;
 
 /// Used to describe the road surface elevation of an object.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Surface {
     pub crg: Option<Crg>,
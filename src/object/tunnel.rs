@@ -9,6 +9,7 @@ use uom::si::length::meter;
 /// the road within the given range unless a lane validity element with further restrictions is
 /// provided as child element.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tunnel {
     /// Degree of daylight intruding the tunnel. Depends on the application.
     pub daylight: Option<f64>,
@@ -1,9 +1,12 @@
 use backtrace::Backtrace;
+use std::cell::Cell;
 use std::convert::Infallible;
 use std::fmt::{Display, Formatter};
+use std::io::Read;
 use std::num::{ParseFloatError, ParseIntError};
 use std::str::{FromStr, ParseBoolError};
 use xml::attribute::OwnedAttribute;
+use xml::common::{Position, TextPosition};
 
 pub type Result<T> = std::result::Result<T, Box<Error>>;
 
@@ -23,6 +26,96 @@ impl Display for Path<'_> {
     }
 }
 
+/// Options to tweak the behaviour of the parser.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// The maximum nesting depth of XML elements, counted from the document root. Because
+    /// elements are parsed recursively, this bounds the stack usage for malicious or broken input.
+    /// `None` disables the limit.
+    pub max_depth: Option<usize>,
+    /// Rejects documents with issues that would otherwise only be reported as [`Warning`]s
+    pub strict: bool,
+    /// Reports road, junction and object ids that are used more than once
+    pub check_duplicate_ids: bool,
+    /// Reports features that were introduced after the revision declared in the header
+    pub check_version: bool,
+    /// Reports center lanes with an id other than 0
+    pub check_center_lane: bool,
+    /// Reports roads with a length that is not positive
+    pub check_road_length: bool,
+    /// Reports connecting roads whose `junction` attribute differs from the junction using them
+    pub check_junction_backrefs: bool,
+    /// Reports objects with a type that is deprecated by the standard
+    pub check_deprecated: bool,
+}
+
+impl ParseOptions {
+    pub const DEFAULT_MAX_DEPTH: usize = 256;
+
+    #[inline]
+    pub fn with_max_depth(mut self, max_depth: Option<usize>) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    #[inline]
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    #[inline]
+    pub fn with_check_duplicate_ids(mut self, check_duplicate_ids: bool) -> Self {
+        self.check_duplicate_ids = check_duplicate_ids;
+        self
+    }
+
+    #[inline]
+    pub fn with_check_version(mut self, check_version: bool) -> Self {
+        self.check_version = check_version;
+        self
+    }
+
+    #[inline]
+    pub fn with_check_center_lane(mut self, check_center_lane: bool) -> Self {
+        self.check_center_lane = check_center_lane;
+        self
+    }
+
+    #[inline]
+    pub fn with_check_road_length(mut self, check_road_length: bool) -> Self {
+        self.check_road_length = check_road_length;
+        self
+    }
+
+    #[inline]
+    pub fn with_check_junction_backrefs(mut self, check_junction_backrefs: bool) -> Self {
+        self.check_junction_backrefs = check_junction_backrefs;
+        self
+    }
+
+    #[inline]
+    pub fn with_check_deprecated(mut self, check_deprecated: bool) -> Self {
+        self.check_deprecated = check_deprecated;
+        self
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: Some(Self::DEFAULT_MAX_DEPTH),
+            strict: false,
+            check_duplicate_ids: false,
+            check_version: false,
+            check_center_lane: false,
+            check_road_length: false,
+            check_junction_backrefs: false,
+            check_deprecated: false,
+        }
+    }
+}
+
 pub struct ReadContext<'a, I>
 where
     I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
@@ -31,7 +124,12 @@ where
     path: Path<'a>,
     attributes: Vec<OwnedAttribute>,
     children_done: bool,
-    #[cfg(debug_assertions)]
+    depth: usize,
+    options: ParseOptions,
+    position_source: Option<&'a Cell<TextPosition>>,
+    position: Option<TextPosition>,
+    namespace: Option<String>,
+    #[cfg(any(feature = "trace-parse", feature = "tracing"))]
     read_attributes: std::cell::RefCell<Vec<String>>,
 }
 
@@ -59,7 +157,68 @@ where
         self
     }
 
-    pub fn path(&self) -> Path {
+    fn with_depth(mut self, depth: usize) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Reads the position of the element from `source`, see [`PositionedEvents`]. The position of
+    /// the current element is the current value of `source`. It is passed on to child elements.
+    pub fn with_position_source(mut self, source: Option<&'a Cell<TextPosition>>) -> Self {
+        self.position_source = source;
+        self.position = source.map(Cell::get);
+        self
+    }
+
+    /// Sets the namespace URI of the current element
+    pub fn with_namespace(mut self, namespace: Option<String>) -> Self {
+        self.namespace = namespace;
+        self
+    }
+
+    /// The namespace URI of the current element, regardless of the prefix used for it
+    #[inline]
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    /// The position of the start tag of the current element in the source document, if known
+    #[inline]
+    pub fn position(&self) -> Option<TextPosition> {
+        self.position
+    }
+
+    #[inline]
+    pub fn options(&self) -> &ParseOptions {
+        &self.options
+    }
+
+    /// The nesting depth of the current element, the document root has a depth of zero
+    #[inline]
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    fn check_child_depth(&self, name: &str) -> Result<()> {
+        match self.options.max_depth {
+            Some(max_depth) if self.depth >= max_depth => Err(Box::new(Error::MaxDepthExceeded {
+                path: Path {
+                    parent: Some(&self.path),
+                    name,
+                }
+                .to_string(),
+                max_depth,
+            })),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn path(&self) -> Path<'_> {
         self.path
     }
 
@@ -67,11 +226,23 @@ where
         self.path.name
     }
 
+    /// Enters a `tracing` span for the current element, identified by the value of its attribute
+    /// `id_attribute`. The attribute is not marked as read.
+    #[cfg(feature = "tracing")]
+    pub(crate) fn enter_span(&self, id_attribute: &str) -> tracing::span::EnteredSpan {
+        let id = self
+            .attributes
+            .iter()
+            .find(|a| a.name.local_name.eq_ignore_ascii_case(id_attribute))
+            .map(|a| a.value.as_str());
+        tracing::debug_span!("element", name = self.element_name(), id).entered()
+    }
+
     pub fn attribute<T: FromStr>(&self, name: &str) -> Result<T>
     where
         T::Err: Into<ParseError>,
     {
-        #[cfg(debug_assertions)]
+        #[cfg(any(feature = "trace-parse", feature = "tracing"))]
         self.read_attributes.borrow_mut().push(name.to_string());
         for attribute in &self.attributes {
             if attribute.name.local_name.eq_ignore_ascii_case(name) {
@@ -82,23 +253,30 @@ where
                         field: name.to_string(),
                         ty: core::any::type_name::<T>().to_string(),
                         error: e.into(),
+                        position: self.position,
                         bt: Box::new(Backtrace::new()),
                     })),
                 };
             }
         }
-        Err(Box::new(Error::missing_attribute(
-            self.path.to_string(),
-            name,
-            core::any::type_name::<T>(),
-        )))
+        Err(Box::new(
+            Error::missing_attribute(self.path.to_string(), name, core::any::type_name::<T>())
+                .with_position(self.position),
+        ))
+    }
+
+    /// Reads an optional `bool` attribute, accepting `true`/`false`, `1`/`0` and `yes`/`no` in any
+    /// case, as found in files of various tools
+    pub fn attribute_bool_opt(&self, name: &str) -> Result<Option<bool>> {
+        self.attribute_opt::<TolerantBool>(name)
+            .map(|value| value.map(|TolerantBool(value)| value))
     }
 
     pub fn attribute_opt<T: FromStr>(&self, name: &str) -> Result<Option<T>>
     where
         T::Err: Into<ParseError>,
     {
-        #[cfg(debug_assertions)]
+        #[cfg(any(feature = "trace-parse", feature = "tracing"))]
         self.read_attributes.borrow_mut().push(name.to_string());
         for attribute in &self.attributes {
             if attribute.name.local_name.eq_ignore_ascii_case(name) {
@@ -109,6 +287,7 @@ where
                         field: name.to_string(),
                         ty: core::any::type_name::<T>().to_string(),
                         error: e.into(),
+                        position: self.position,
                         bt: Box::new(Backtrace::new()),
                     })),
                 };
@@ -118,13 +297,12 @@ where
     }
 
     pub fn attributes(&self) -> impl Iterator<Item = &OwnedAttribute> {
-        #[allow(clippy::map_identity)] // because of debug assertions cfg flag
-        self.attributes.iter().map(|a| {
-            #[cfg(debug_assertions)]
+        // the closure is a no-op without the trace-parse or tracing feature
+        self.attributes.iter().inspect(|_a| {
+            #[cfg(any(feature = "trace-parse", feature = "tracing"))]
             self.read_attributes
                 .borrow_mut()
-                .push(a.name.local_name.clone());
-            a
+                .push(_a.name.local_name.clone());
         })
     }
 
@@ -143,6 +321,10 @@ where
                     attributes,
                     namespace: _,
                 } => {
+                    if let Err(e) = self.check_child_depth(&name.local_name) {
+                        self.children_done = true;
+                        return Err(e);
+                    }
                     let mut context = ReadContext::from_parent(
                         &mut *self.iterator,
                         Path {
@@ -150,7 +332,9 @@ where
                             name: &name.local_name,
                         },
                         attributes,
-                    );
+                    )
+                    .with_depth(self.depth + 1)
+                    .with_options(self.options);
                     for (mapper_name, mapper_fn) in mapper.iter_mut() {
                         if name.local_name.eq_ignore_ascii_case(mapper_name) {
                             mapper_fn(&mut context)?;
@@ -190,17 +374,23 @@ where
                     attributes,
                     namespace: _,
                 } => {
-                    if let Err(e) = mapper(
-                        &name.local_name,
-                        ReadContext::from_parent(
-                            &mut *self.iterator,
-                            Path {
-                                parent: Some(&self.path),
-                                name: &name.local_name,
-                            },
-                            attributes,
-                        ),
-                    ) {
+                    if let Err(e) = self.check_child_depth(&name.local_name).and_then(|_| {
+                        mapper(
+                            &name.local_name,
+                            ReadContext::from_parent(
+                                &mut *self.iterator,
+                                Path {
+                                    parent: Some(&self.path),
+                                    name: &name.local_name,
+                                },
+                                attributes,
+                            )
+                            .with_depth(self.depth + 1)
+                            .with_options(self.options)
+                            .with_position_source(self.position_source)
+                            .with_namespace(name.namespace.clone()),
+                        )
+                    }) {
                         // dont walk any more elements on an error, just drop them
                         self.children_done = true;
                         return Err(e);
@@ -230,8 +420,8 @@ where
 
     #[inline]
     pub fn expecting_no_child_elements(&mut self) -> Result<()> {
-        self.children(|name, mut read| {
-            dbg!(name);
+        self.children(|_name, mut read| {
+            trace_skipped_element(&read.path().to_string(), &read.attributes);
             read.expecting_no_child_elements()
         })
     }
@@ -239,13 +429,57 @@ where
     #[inline]
     pub fn expecting_no_child_elements_for<T>(&mut self, value: T) -> Result<T> {
         self.children(|_name, mut read| {
-            dbg!(read.path().to_string());
+            trace_skipped_element(&read.path().to_string(), &read.attributes);
             read.expecting_no_child_elements()
         })?;
         Ok(value)
     }
 }
 
+/// Parses a standalone element, such as `<road>...</road>`, without the surrounding `<OpenDRIVE>`
+/// document. Useful to process fragments of a document in isolation.
+pub trait FromXmlStr: Sized {
+    /// The (case insensitive) name of the XML element
+    const ELEMENT_NAME: &'static str;
+
+    fn from_xml_str(s: &str) -> Result<Self>
+    where
+        Self: for<'a, 'b, 'p> TryFrom<
+            ReadContext<'a, PositionedEvents<'p, &'b [u8]>>,
+            Error = Box<Error>,
+        >,
+    {
+        let position = Cell::new(TextPosition::new());
+        let mut events = PositionedEvents::new(xml::EventReader::from_str(s.trim()), &position);
+        let mut read = ReadContext::from(&mut events).with_position_source(Some(&position));
+        Self::from_children(&mut read)
+    }
+
+    /// Reads the child element of `read` named [`FromXmlStr::ELEMENT_NAME`], ignoring all other
+    /// children. The path, depth and options of the element are derived from `read`.
+    fn from_children<I>(read: &mut ReadContext<'_, I>) -> Result<Self>
+    where
+        I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
+        Self: for<'a> TryFrom<ReadContext<'a, I>, Error = Box<Error>>,
+    {
+        let mut value = None;
+        read.children(|name, context| {
+            if name.eq_ignore_ascii_case(Self::ELEMENT_NAME) {
+                value = Some(Self::try_from(context)?);
+            }
+            Ok(())
+        })?;
+
+        value.ok_or_else(|| {
+            Box::new(Error::missing_element(
+                read.path().to_string(),
+                Self::ELEMENT_NAME,
+                core::any::type_name::<Self>(),
+            ))
+        })
+    }
+}
+
 impl<'a, I> From<&'a mut I> for ReadContext<'a, I>
 where
     I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
@@ -259,7 +493,12 @@ where
             },
             attributes: Vec::new(),
             children_done: false,
-            #[cfg(debug_assertions)]
+            depth: 0,
+            options: ParseOptions::default(),
+            position_source: None,
+            position: None,
+            namespace: None,
+            #[cfg(any(feature = "trace-parse", feature = "tracing"))]
             read_attributes: std::cell::RefCell::new(Vec::new()),
         }
     }
@@ -278,17 +517,28 @@ where
             });
         }
 
-        #[cfg(debug_assertions)]
+        #[cfg(feature = "trace-parse")]
+        dbg!(self.path().to_string());
+
+        #[cfg(any(feature = "trace-parse", feature = "tracing"))]
         {
-            dbg!(self.path().to_string());
             let attributes = self
                 .attributes
                 .iter()
                 .filter(|a| !self.read_attributes.borrow().contains(&a.name.local_name))
                 .collect::<Vec<_>>();
             if !attributes.is_empty() {
-                dbg!(self.path().to_string());
-                dbg!(attributes);
+                #[cfg(feature = "trace-parse")]
+                {
+                    dbg!(self.path().to_string());
+                    dbg!(&attributes);
+                }
+                #[cfg(feature = "tracing")]
+                tracing::debug!(
+                    path = %self.path(),
+                    attributes = ?attributes.iter().map(|a| &a.name.local_name).collect::<Vec<_>>(),
+                    "unread attributes"
+                );
             }
         }
     }
@@ -307,22 +557,64 @@ pub enum Error {
     },
     #[error("A child element in `{0} is missing")]
     ChildElementIsMissing(String, Box<Backtrace>),
-    #[error("Failed to parse `{path}`.`{field}` as `{ty}`: {error}")]
+    #[error("Failed to parse `{path}`.`{field}` as `{ty}`{}: {error}", DisplayPosition(.position))]
     ParseError {
         path: String,
         field: String,
         ty: String,
         error: ParseError,
+        /// Position of the start tag of the element, if known
+        position: Option<TextPosition>,
         bt: Box<Backtrace>,
     },
-    #[error("Missing attribute at `{path}`.`{field}` of type `{ty}`")]
+    #[error("Missing attribute at `{path}`.`{field}` of type `{ty}`{}", DisplayPosition(.position))]
     MissingAttribute {
         path: String,
         field: String,
         ty: String,
+        /// Position of the start tag of the element, if known
+        position: Option<TextPosition>,
     },
     #[error("Invalid value for `{name}`: {value}")]
     InvalidValueFor { name: String, value: String },
+    #[error("The maximum nesting depth of {max_depth} is exceeded at `{path}`")]
+    MaxDepthExceeded { path: String, max_depth: usize },
+    #[error("The document is rejected in strict mode: {0}")]
+    Rejected(Warning),
+}
+
+/// Issues in a parsed document, that do not prevent it from being parsed. See [`ParseOptions`]
+/// for the checks that are available.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Warning {
+    #[error("The id `{id}` is used by more than one `{element}`")]
+    DuplicateId { element: &'static str, id: String },
+    #[error("The document declares revision {declared}, but uses {feature} of revision {since}")]
+    VersionMismatch {
+        feature: &'static str,
+        since: crate::core::version::Version,
+        declared: crate::core::version::Version,
+    },
+    #[error("The center lane of the lane section {section_index} of road `{road}` has the id {id} instead of 0")]
+    InvalidCenterLaneId {
+        road: String,
+        section_index: usize,
+        id: i64,
+    },
+    #[error("The road `{road}` has a length that is not positive")]
+    NonPositiveRoadLength { road: String },
+    #[error("The connecting road `{road}` of the junction `{junction}` belongs to the junction `{declared}`")]
+    JunctionBackReference {
+        road: String,
+        junction: String,
+        declared: String,
+    },
+    #[error("The object `{object}` of road `{road}` has the deprecated type `{r#type}`")]
+    DeprecatedObjectType {
+        road: String,
+        object: String,
+        r#type: &'static str,
+    },
 }
 
 impl Error {
@@ -336,6 +628,30 @@ impl Error {
             path: path.into(),
             field: field.into(),
             ty: ty.into(),
+            position: None,
+        }
+    }
+
+    /// Sets the position in the source document of errors that carry one
+    pub fn with_position(mut self, position: Option<TextPosition>) -> Self {
+        match &mut self {
+            Self::ParseError { position: p, .. } | Self::MissingAttribute { position: p, .. } => {
+                *p = position
+            }
+            _ => {}
+        }
+        self
+    }
+
+    /// The position in the source document the error refers to, if known. For attribute errors,
+    /// this is the position of the start tag of the element.
+    pub fn position(&self) -> Option<TextPosition> {
+        match self {
+            Self::XmlError(e) => Some(e.position()),
+            Self::ParseError { position, .. } | Self::MissingAttribute { position, .. } => {
+                *position
+            }
+            _ => None,
         }
     }
 
@@ -379,6 +695,7 @@ impl Error {
             field: field.into(),
             ty: ty.into(),
             error: error.into(),
+            position: None,
             bt: Box::new(Backtrace::new()),
         }
     }
@@ -401,6 +718,79 @@ where
     }
 }
 
+/// See [`ReadContext::attribute_bool_opt`]
+struct TolerantBool(bool);
+
+impl FromStr for TolerantBool {
+    type Err = ParseBoolError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let s = s.trim();
+        if ["true", "1", "yes"]
+            .iter()
+            .any(|v| v.eq_ignore_ascii_case(s))
+        {
+            Ok(Self(true))
+        } else if ["false", "0", "no"]
+            .iter()
+            .any(|v| v.eq_ignore_ascii_case(s))
+        {
+            Ok(Self(false))
+        } else {
+            bool::from_str(s).map(Self)
+        }
+    }
+}
+
+/// Formats an optional position as ` at line <row>, column <column>`, both starting at one
+struct DisplayPosition<'a>(&'a Option<TextPosition>);
+
+impl Display for DisplayPosition<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(position) => write!(
+                f,
+                " at line {}, column {}",
+                position.row + 1,
+                position.column + 1
+            ),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Iterates the events of an [`xml::EventReader`], like [`xml::reader::Events`], and stores the
+/// position of the last event in a [`Cell`], see [`ReadContext::with_position_source`]
+pub struct PositionedEvents<'p, R: Read> {
+    reader: xml::EventReader<R>,
+    position: &'p Cell<TextPosition>,
+    finished: bool,
+}
+
+impl<'p, R: Read> PositionedEvents<'p, R> {
+    pub fn new(reader: xml::EventReader<R>, position: &'p Cell<TextPosition>) -> Self {
+        Self {
+            reader,
+            position,
+            finished: false,
+        }
+    }
+}
+
+impl<R: Read> Iterator for PositionedEvents<'_, R> {
+    type Item = xml::reader::Result<xml::reader::XmlEvent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let event = self.reader.next();
+        self.position.set(self.reader.position());
+        self.finished = matches!(event, Ok(xml::reader::XmlEvent::EndDocument) | Err(_));
+        Some(event)
+    }
+}
+
 #[derive(Debug, derive_more::From, derive_more::Display)]
 pub enum ParseError {
     Int(ParseIntError),
@@ -417,13 +807,56 @@ impl From<Infallible> for ParseError {
 
 pub trait ToScientificString {
     fn to_scientific_string(&self) -> String;
+
+    /// Appends the same text as [`ToScientificString::to_scientific_string`] to `out`, which
+    /// does not allocate if `out` has enough capacity left
+    fn write_scientific_string(&self, out: &mut String) {
+        out.push_str(&self.to_scientific_string());
+    }
 }
 
 impl ToScientificString for f64 {
     fn to_scientific_string(&self) -> String {
+        // sign, 18 digits, the point and an exponent like e-308
+        let mut out = String::with_capacity(25);
+        self.write_scientific_string(&mut out);
+        out
+    }
+
+    fn write_scientific_string(&self, out: &mut String) {
+        use std::fmt::Write as _;
         // TODO {:.17e+02} does not work
-        format!("{self:.17e}")
+        let _ = write!(out, "{self:.17e}");
+    }
+}
+
+thread_local! {
+    static SCIENTIFIC_SCRATCH: Cell<String> = const { Cell::new(String::new()) };
+}
+
+/// Formats the `values` like [`ToScientificString::to_scientific_string`] into a scratch buffer
+/// that is reused by all calls on the same thread and passes the formatted values to `f`. Once
+/// the buffer has grown, this does not allocate, see `visit_scientific_attributes!`.
+pub fn with_scientific_strs<const N: usize, R>(
+    values: [f64; N],
+    f: impl FnOnce([&str; N]) -> R,
+) -> R {
+    // taking the buffer keeps nested calls sound, they merely allocate a buffer of their own
+    let mut buffer = SCIENTIFIC_SCRATCH.take();
+    buffer.clear();
+    let mut ends = [0; N];
+    for (value, end) in values.iter().zip(&mut ends) {
+        value.write_scientific_string(&mut buffer);
+        *end = buffer.len();
     }
+    let mut start = 0;
+    let result = f(ends.map(|end| {
+        let value = &buffer[start..end];
+        start = end;
+        value
+    }));
+    SCIENTIFIC_SCRATCH.set(buffer);
+    result
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -468,6 +901,17 @@ macro_rules! find_map_parse_attr {
     };
 }
 
+/// Reports an element that is skipped while parsing, by printing it with the `trace-parse` feature
+/// and as a `tracing` event with the `tracing` feature. Does nothing otherwise.
+#[doc(hidden)]
+#[inline]
+pub fn trace_skipped_element(_name: &str, _attributes: &[OwnedAttribute]) {
+    #[cfg(feature = "trace-parse")]
+    dbg!(_name, _attributes);
+    #[cfg(feature = "tracing")]
+    tracing::debug!(element = _name, "skipped element");
+}
+
 #[macro_export]
 macro_rules! find_map_parse_elem {
     ($events:ident $(, $name:literal $($req:literal)? => $body:expr)* $(, _ => $alt:expr)? $(,)?) => {
@@ -502,7 +946,7 @@ macro_rules! find_map_parse_elem {
                     )*
 
                     // none captured, need to skip to element end
-                    dbg!(&name.local_name, &attributes);
+                    $crate::parser::trace_skipped_element(&name.local_name, &attributes);
                     let mut depth = 1_usize;
                     while let Some(event) = $events.next() {
                         match event.map_err($crate::parser::Error::from).map_err(Box::new)? {
@@ -620,6 +1064,23 @@ macro_rules! visit_attributes {
     }
 }
 
+/// Like [`visit_attributes!`] for attributes of `f64` values, which are formatted into a reused
+/// buffer instead of allocating a `String` each, see [`with_scientific_strs`]. Further attributes
+/// that are already strings may follow after a `;`.
+#[macro_export]
+macro_rules! visit_scientific_attributes {
+    ($visitor:ident $(, $name:literal => $value:expr)* $(,)? $(; $($str_name:literal => $str:expr),* $(,)?)?) => {
+        $crate::parser::with_scientific_strs([$($value),*], |values| {
+            let mut values = values.into_iter();
+            $crate::visit_attributes!(
+                $visitor,
+                $($name => values.next().unwrap_or_default(),)*
+                $($($str_name => $str,)*)?
+            )
+        })
+    }
+}
+
 #[macro_export]
 macro_rules! visit_attributes_flatten {
     ($visitor:ident$(, $name:literal => $attr:expr)* $(,)?) => {
@@ -662,8 +1123,14 @@ macro_rules! visit_children {
 
 #[macro_export]
 macro_rules! impl_from_str_as_str {
-    ($ty:ty $(, $name:literal => $value:ident)* $(,)?) => {
+    ($ty:ty $(, $name:literal $(| $alias:literal)* => $value:ident)* $(,)?) => {
         impl $ty {
+            /// All variants, in the order they are listed here
+            #[allow(deprecated)]
+            pub fn all_variants() -> &'static [Self] {
+                &[$(<$ty>::$value,)*]
+            }
+
             pub fn as_str(&self) -> &'static str {
                 match self {
                     $(<$ty>::$value => $name,)*
@@ -674,13 +1141,19 @@ macro_rules! impl_from_str_as_str {
         impl core::str::FromStr for $ty {
             type Err = $crate::parser::InvalidEnumValue;
 
+            /// Matches case insensitive and ignores surrounding whitespace, as written by some
+            /// exporters. Besides the name of a variant, its aliases are accepted as well.
             #[allow(deprecated)]
-            fn from_str(s: &str) -> Result<Self, Self::Err> {
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                let s = value.trim();
                 match s {
-                    $(_ if s.eq_ignore_ascii_case(Self::$value.as_str()) => Ok(Self::$value),)*
+                    $(
+                        _ if s.eq_ignore_ascii_case(Self::$value.as_str())
+                            $(|| s.eq_ignore_ascii_case($alias))* => Ok(Self::$value),
+                    )*
                     _ => Err($crate::parser::InvalidEnumValue {
                         r#type: stringify!(Self).to_string(),
-                        value: s.to_string(),
+                        value: value.to_string(),
                     }),
                 }
             }
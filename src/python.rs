@@ -0,0 +1,196 @@
+//! Read-only Python bindings, built with [`pyo3`]. The `opendrive` module exposes
+//! `parse(xml: str) -> OpenDrive`, whose roads, geometries and lanes are accessible as Python
+//! objects with values in SI units (meters and radians).
+
+use crate::core::OpenDrive;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::sync::Arc;
+use uom::si::angle::radian;
+use uom::si::length::meter;
+
+/// A parsed OpenDRIVE document
+#[pyclass(name = "OpenDrive", module = "opendrive", frozen)]
+#[derive(Clone)]
+pub struct PyOpenDrive {
+    drive: Arc<OpenDrive>,
+}
+
+impl PyOpenDrive {
+    #[inline]
+    pub fn new(drive: OpenDrive) -> Self {
+        Self {
+            drive: Arc::new(drive),
+        }
+    }
+
+    #[inline]
+    pub fn inner(&self) -> &OpenDrive {
+        &self.drive
+    }
+
+    fn road_at(&self, index: usize) -> PyRoad {
+        PyRoad {
+            drive: Arc::clone(&self.drive),
+            index,
+        }
+    }
+}
+
+#[pymethods]
+impl PyOpenDrive {
+    #[getter]
+    fn roads(&self) -> Vec<PyRoad> {
+        (0..self.drive.road.len())
+            .map(|i| self.road_at(i))
+            .collect()
+    }
+
+    /// The road with the given id, or `None`
+    fn road(&self, id: &str) -> Option<PyRoad> {
+        self.drive
+            .road
+            .iter()
+            .position(|r| r.id == id)
+            .map(|i| self.road_at(i))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("OpenDrive(roads={})", self.drive.road.len())
+    }
+}
+
+/// A road of an [`PyOpenDrive`] document
+#[pyclass(name = "Road", module = "opendrive", frozen)]
+#[derive(Clone)]
+pub struct PyRoad {
+    drive: Arc<OpenDrive>,
+    index: usize,
+}
+
+impl PyRoad {
+    fn road(&self) -> &crate::road::Road {
+        &self.drive.road[self.index]
+    }
+}
+
+#[pymethods]
+impl PyRoad {
+    #[getter]
+    fn id(&self) -> &str {
+        &self.road().id
+    }
+
+    #[getter]
+    fn name(&self) -> Option<&str> {
+        self.road().name.as_deref()
+    }
+
+    /// Length in meters
+    #[getter]
+    fn length(&self) -> f64 {
+        self.road().length_m()
+    }
+
+    /// The id of the junction the road belongs to, or `None`
+    #[getter]
+    fn junction(&self) -> Option<&str> {
+        self.road().junction.as_junction()
+    }
+
+    #[getter]
+    fn geometries(&self) -> Vec<PyGeometry> {
+        self.road()
+            .plan_view
+            .geometry
+            .iter()
+            .map(|g| PyGeometry {
+                s: g.s.get::<meter>(),
+                x: g.x.get::<meter>(),
+                y: g.y.get::<meter>(),
+                hdg: g.hdg.get::<radian>(),
+                length: g.length.get::<meter>(),
+                kind: g.r#type.kind().as_str(),
+            })
+            .collect()
+    }
+
+    #[getter]
+    fn lane_sections(&self) -> Vec<PyLaneSection> {
+        self.road()
+            .lanes
+            .lane_section
+            .iter()
+            .map(|section| PyLaneSection {
+                s: section.s,
+                lanes: section
+                    .lanes()
+                    .map(|(id, lane)| PyLane {
+                        id,
+                        r#type: lane.r#type.as_str(),
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Road(id={:?})", self.road().id)
+    }
+}
+
+/// A geometry element of the plan view of a [`PyRoad`]
+#[pyclass(name = "Geometry", module = "opendrive", frozen, get_all)]
+#[derive(Debug, Clone)]
+pub struct PyGeometry {
+    /// s-coordinate of the start position in meters
+    pub s: f64,
+    /// Start position (x inertial) in meters
+    pub x: f64,
+    /// Start position (y inertial) in meters
+    pub y: f64,
+    /// Start orientation (inertial heading) in radians
+    pub hdg: f64,
+    /// Length of the element in meters
+    pub length: f64,
+    /// The name of the element, like `line` or `arc`
+    pub kind: &'static str,
+}
+
+/// A lane section of a [`PyRoad`]
+#[pyclass(name = "LaneSection", module = "opendrive", frozen, get_all)]
+#[derive(Debug, Clone)]
+pub struct PyLaneSection {
+    /// s-coordinate of the start position in meters
+    pub s: f64,
+    /// The left, center and right lanes
+    pub lanes: Vec<PyLane>,
+}
+
+/// A lane of a [`PyLaneSection`]
+#[pyclass(name = "Lane", module = "opendrive", frozen, get_all)]
+#[derive(Debug, Clone)]
+pub struct PyLane {
+    pub id: i64,
+    #[pyo3(name = "type")]
+    pub r#type: &'static str,
+}
+
+/// Parses an OpenDRIVE document, raising a `ValueError` if it is malformed
+#[pyfunction]
+pub fn parse(xml: &str) -> PyResult<PyOpenDrive> {
+    OpenDrive::from_xml_str(xml)
+        .map(PyOpenDrive::new)
+        .map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+#[pymodule]
+pub fn opendrive(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add_class::<PyOpenDrive>()?;
+    m.add_class::<PyRoad>()?;
+    m.add_class::<PyGeometry>()?;
+    m.add_class::<PyLaneSection>()?;
+    m.add_class::<PyLane>()?;
+    Ok(())
+}
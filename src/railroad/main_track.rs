@@ -4,6 +4,7 @@ use uom::si::f64::Length;
 use uom::si::length::meter;
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MainTrack {
     /// direction, relative to the s-direction, on the main track for entering the side track via
     /// the switch
@@ -19,6 +19,7 @@ pub mod switch_position;
 /// `<road>`, signal definition by `<signal>`, etc. Railroad-specific elements are defined against
 /// the background of streetcar applications.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Railroad {
     pub switch: Vec<Switch>,
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 
 /// Indicates the switch that leads out of a side track after it has been entered.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Partner {
     /// Unique ID of the partner switch
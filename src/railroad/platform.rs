@@ -6,6 +6,7 @@ use vec1::Vec1;
 /// Each `<station>` element must contain at least one `<platform>` element. Each `<platform>`
 /// element must contain at least one reference to a valid track segment.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Platform {
     pub segment: Vec1<Segment>,
     /// Unique ID within database
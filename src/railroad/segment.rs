@@ -6,6 +6,7 @@ use uom::si::length::meter;
 /// Each `<platform>` element is valid on one or more track segments. The `<segment>` element must
 /// be specified.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Segment {
     /// Unique ID of the `<road>` element (track) that accompanies the platform
     pub road_id: String,
@@ -7,6 +7,7 @@ use vec1::Vec1;
 /// Defines stations for tram and railroad applications and for automotive environments. May refer
 /// to multiple tracks and is therefore defined on the same level as junctions.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Station {
     pub platform: Vec1<Platform>,
     /// Unique ID within database
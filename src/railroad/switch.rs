@@ -6,6 +6,7 @@ use crate::railroad::switch_position::SwitchPosition;
 use std::borrow::Cow;
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Switch {
     pub main_track: MainTrack,
@@ -1,9 +1,13 @@
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CountryCode {
     CountryCodeDeprecated(CountryCodeDeprecated),
     Iso3166alpha2(String),
     #[deprecated]
     Iso3166alpha3(String),
+    /// A value matching none of the formats above, as found in some (older) files. The value is
+    /// kept as is, see [`CountryCode::is_valid_iso`].
+    Other(String),
 }
 
 impl CountryCode {
@@ -13,6 +17,16 @@ impl CountryCode {
             Self::CountryCodeDeprecated(code) => code.as_str(),
             Self::Iso3166alpha2(v) => v,
             Self::Iso3166alpha3(v) => v,
+            Self::Other(v) => v,
+        }
+    }
+
+    /// Whether this is an officially assigned ISO 3166-1 alpha-2 code, such as `DE`. Any two
+    /// letters are parsed as [`CountryCode::Iso3166alpha2`], but not all of them name a country.
+    pub fn is_valid_iso(&self) -> bool {
+        match self {
+            Self::Iso3166alpha2(code) => ISO_3166_ALPHA_2.binary_search(&code.as_str()).is_ok(),
+            _ => false,
         }
     }
 }
@@ -29,14 +43,31 @@ impl core::str::FromStr for CountryCode {
         } else if let Ok(cc) = CountryCodeDeprecated::from_str(s) {
             Ok(Self::CountryCodeDeprecated(cc))
         } else {
-            Err(crate::parser::InvalidEnumValue {
-                r#type: core::any::type_name::<Self>().to_string(),
-                value: s.to_string(),
-            })
+            Ok(Self::Other(s.to_string()))
         }
     }
 }
 
+/// The officially assigned ISO 3166-1 alpha-2 codes, in ascending order
+const ISO_3166_ALPHA_2: [&str; 249] = [
+    "AD", "AE", "AF", "AG", "AI", "AL", "AM", "AO", "AQ", "AR", "AS", "AT", "AU", "AW", "AX", "AZ",
+    "BA", "BB", "BD", "BE", "BF", "BG", "BH", "BI", "BJ", "BL", "BM", "BN", "BO", "BQ", "BR", "BS",
+    "BT", "BV", "BW", "BY", "BZ", "CA", "CC", "CD", "CF", "CG", "CH", "CI", "CK", "CL", "CM", "CN",
+    "CO", "CR", "CU", "CV", "CW", "CX", "CY", "CZ", "DE", "DJ", "DK", "DM", "DO", "DZ", "EC", "EE",
+    "EG", "EH", "ER", "ES", "ET", "FI", "FJ", "FK", "FM", "FO", "FR", "GA", "GB", "GD", "GE", "GF",
+    "GG", "GH", "GI", "GL", "GM", "GN", "GP", "GQ", "GR", "GS", "GT", "GU", "GW", "GY", "HK", "HM",
+    "HN", "HR", "HT", "HU", "ID", "IE", "IL", "IM", "IN", "IO", "IQ", "IR", "IS", "IT", "JE", "JM",
+    "JO", "JP", "KE", "KG", "KH", "KI", "KM", "KN", "KP", "KR", "KW", "KY", "KZ", "LA", "LB", "LC",
+    "LI", "LK", "LR", "LS", "LT", "LU", "LV", "LY", "MA", "MC", "MD", "ME", "MF", "MG", "MH", "MK",
+    "ML", "MM", "MN", "MO", "MP", "MQ", "MR", "MS", "MT", "MU", "MV", "MW", "MX", "MY", "MZ", "NA",
+    "NC", "NE", "NF", "NG", "NI", "NL", "NO", "NP", "NR", "NU", "NZ", "OM", "PA", "PE", "PF", "PG",
+    "PH", "PK", "PL", "PM", "PN", "PR", "PS", "PT", "PW", "PY", "QA", "RE", "RO", "RS", "RU", "RW",
+    "SA", "SB", "SC", "SD", "SE", "SG", "SH", "SI", "SJ", "SK", "SL", "SM", "SN", "SO", "SR", "SS",
+    "ST", "SV", "SX", "SY", "SZ", "TC", "TD", "TF", "TG", "TH", "TJ", "TK", "TL", "TM", "TN", "TO",
+    "TR", "TT", "TV", "TW", "TZ", "UA", "UG", "UM", "US", "UY", "UZ", "VA", "VC", "VE", "VG", "VI",
+    "VN", "VU", "WF", "WS", "YE", "YT", "ZA", "ZM", "ZW",
+];
+
 #[cfg(feature = "fuzzing")]
 impl arbitrary::Arbitrary<'_> for CountryCode {
     fn arbitrary(u: &mut arbitrary::Unstructured) -> arbitrary::Result<Self> {
@@ -58,6 +89,7 @@ impl arbitrary::Arbitrary<'_> for CountryCode {
 
 #[deprecated]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub enum CountryCodeDeprecated {
     OpenDRIVE,
@@ -1,6 +1,7 @@
-use crate::junction::crg_mode::CrgMode;
+use crate::core::s_range::SRange;
 use crate::junction::crg_purpose::CrgPurpose;
-use crate::object::orientation::Orientation;
+use crate::road::crg_mode::CrgMode;
+use crate::road::direction::Direction;
 use std::borrow::Cow;
 use uom::si::angle::radian;
 use uom::si::f64::Angle;
@@ -9,6 +10,7 @@ use uom::si::length::meter;
 
 /// Data described in OpenCRG is represented by the `<CRG>` element within the `<surface>` element.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Crg {
     /// Name of the file containing the CRG data
     pub file: String,
@@ -19,7 +21,7 @@ pub struct Crg {
     pub mode: CrgMode,
     /// Orientation of the CRG data set relative to the parent `<road>` element. Only allowed for
     /// mode attached and attached0.
-    pub orientation: Orientation,
+    pub orientation: Direction,
     /// Physical purpose of the data contained in the CRG file; if the attribute is missing, data
     /// will be interpreted as elevation data.
     pub purpose: Option<CrgPurpose>,
@@ -39,6 +41,12 @@ pub struct Crg {
 }
 
 impl Crg {
+    /// The range `[sStart, sEnd)` of the road this surface applies to
+    #[inline]
+    pub fn s_range(&self) -> SRange {
+        SRange::new(self.s_start, self.s_end)
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
@@ -0,0 +1,18 @@
+/// Attachment mode of a `<CRG>` road surface, relative to the road reference line
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
+pub enum CrgMode {
+    Attached,
+    Attached0,
+    Genuine,
+    Global,
+}
+
+impl_from_str_as_str!(
+    CrgMode,
+    "attached" => Attached,
+    "attached0" => Attached0,
+    "genuine" => Genuine,
+    "global" => Global,
+);
@@ -0,0 +1,14 @@
+/// Orientation of data relative to the road reference line
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
+pub enum Direction {
+    Same,
+    Opposite,
+}
+
+impl_from_str_as_str!(
+    Direction,
+    "same" => Same,
+    "opposite" => Opposite,
+);
@@ -1,16 +1,37 @@
 use std::borrow::Cow;
 use uom::si::curvature::radian_per_meter;
-use uom::si::f64::Curvature;
+use uom::si::f64::{Curvature, Length};
+use uom::si::length::meter;
 
 /// An arc describes a road reference line with constant curvature. In ASAM OpenDRIVE, an arc is
 /// represented by an `<arc>` element within the `<geometry>` element.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Arc {
     /// Constant curvature throughout the element
     pub curvature: Curvature,
 }
 
 impl Arc {
+    /// Arcs with a curvature of a smaller magnitude, in radians per meter, are evaluated as lines
+    pub const MIN_CURVATURE: f64 = 1e-12;
+
+    /// The position `(u, v)` and heading (in radians) at `ds` along the arc, in the local frame of
+    /// the arc: it starts at the origin heading along the u-axis and circles around the center at
+    /// `(0, 1/k)`, turning left for a positive curvature k.
+    pub fn local_pose_at(&self, ds: Length) -> (f64, f64, f64) {
+        let ds = ds.get::<meter>();
+        let k = self.curvature.get::<radian_per_meter>();
+        if k.abs() < Self::MIN_CURVATURE {
+            (ds, 0.0, 0.0)
+        } else {
+            let heading = k * ds;
+            // 1 - cos(a) = 2 sin²(a/2), which does not cancel out for small angles
+            let half_sin = (heading / 2.0).sin();
+            (heading.sin() / k, 2.0 * half_sin * half_sin / k, heading)
+        }
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
@@ -0,0 +1,45 @@
+/// Lookup table mapping the arc length along a [`ParamPoly3`] to its curve parameter p. Built by
+/// numerically integrating the curve with [`ParamPoly3::build_arclength_table`] and evaluated
+/// with [`ParamPoly3::point_at_arclength`].
+///
+/// [`ParamPoly3`]: crate::road::geometry::param_poly_3::ParamPoly3
+/// [`ParamPoly3::build_arclength_table`]: crate::road::geometry::param_poly_3::ParamPoly3::build_arclength_table
+/// [`ParamPoly3::point_at_arclength`]: crate::road::geometry::param_poly_3::ParamPoly3::point_at_arclength
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArcLengthLUT {
+    /// Sampled values of the curve parameter p, strictly ascending
+    pub p: Vec<f64>,
+    /// Accumulated arc length from p=0 to the parameter at the same index
+    pub s: Vec<f64>,
+}
+
+impl ArcLengthLUT {
+    /// The total arc length of the curve covered by this table
+    #[inline]
+    pub fn total_length(&self) -> f64 {
+        self.s.last().copied().unwrap_or(0.0)
+    }
+
+    /// Linearly interpolates the curve parameter p at the given arc length, which is clamped to
+    /// `[0, total_length]`.
+    pub fn p_at(&self, s: f64) -> f64 {
+        if self.p.is_empty() {
+            return 0.0;
+        }
+        let s = s.clamp(0.0, self.total_length());
+        let index = self.s.partition_point(|v| *v < s);
+        if index == 0 {
+            self.p[0]
+        } else if index >= self.s.len() {
+            self.p[self.p.len() - 1]
+        } else {
+            let (s0, s1) = (self.s[index - 1], self.s[index]);
+            let (p0, p1) = (self.p[index - 1], self.p[index]);
+            if s1 - s0 > 0.0 {
+                p0 + (p1 - p0) * (s - s0) / (s1 - s0)
+            } else {
+                p0
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::road::geometry::plan_view::PlanView;
+use uom::si::angle::radian;
+use uom::si::length::meter;
+
+/// The geometry elements of a [`PlanView`] in a columnar layout, with one row per element and
+/// all values in SI units (meters and radians), see [`PlanView::as_geometry_table`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GeometryTable {
+    /// s-coordinates of the start positions
+    pub s: Vec<f64>,
+    /// Start positions (x inertial)
+    pub x: Vec<f64>,
+    /// Start positions (y inertial)
+    pub y: Vec<f64>,
+    /// Start orientations (inertial heading)
+    pub hdg: Vec<f64>,
+    /// Lengths of the elements
+    pub length: Vec<f64>,
+    /// Kinds of the elements
+    pub kind: Vec<GeometryKind>,
+}
+
+impl GeometryTable {
+    /// The number of rows
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.s.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.s.is_empty()
+    }
+}
+
+impl From<&PlanView> for GeometryTable {
+    fn from(plan_view: &PlanView) -> Self {
+        let geometry = &plan_view.geometry;
+        Self {
+            s: geometry.iter().map(|g| g.s.get::<meter>()).collect(),
+            x: geometry.iter().map(|g| g.x.get::<meter>()).collect(),
+            y: geometry.iter().map(|g| g.y.get::<meter>()).collect(),
+            hdg: geometry.iter().map(|g| g.hdg.get::<radian>()).collect(),
+            length: geometry.iter().map(|g| g.length.get::<meter>()).collect(),
+            kind: geometry.iter().map(|g| g.r#type.kind()).collect(),
+        }
+    }
+}
+
+/// The kind of a geometry element, see [`GeometryType::kind`]. The discriminant is stable and
+/// may be used as numeric tag.
+///
+/// [`GeometryType::kind`]: crate::road::geometry::geometry_type::GeometryType::kind
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum GeometryKind {
+    Line = 0,
+    Spiral = 1,
+    Arc = 2,
+    Poly3 = 3,
+    ParamPoly3 = 4,
+}
+
+impl GeometryKind {
+    /// The name of the XML element describing this kind of geometry
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Line => "line",
+            Self::Spiral => "spiral",
+            Self::Arc => "arc",
+            Self::Poly3 => "poly3",
+            Self::ParamPoly3 => "paramPoly3",
+        }
+    }
+}
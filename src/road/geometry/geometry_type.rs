@@ -1,10 +1,12 @@
 use crate::road::geometry::arc::Arc;
+use crate::road::geometry::geometry_table::GeometryKind;
 use crate::road::geometry::line::Line;
 use crate::road::geometry::param_poly_3::ParamPoly3;
 use crate::road::geometry::poly_3::Poly3;
 use crate::road::geometry::spiral::Spiral;
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub enum GeometryType {
     Line(Line),
@@ -13,3 +15,16 @@ pub enum GeometryType {
     Poly3(Poly3),
     ParamPoly3(ParamPoly3),
 }
+
+impl GeometryType {
+    /// The kind of this geometry element, without its parameters
+    pub fn kind(&self) -> GeometryKind {
+        match self {
+            Self::Line(_) => GeometryKind::Line,
+            Self::Spiral(_) => GeometryKind::Spiral,
+            Self::Arc(_) => GeometryKind::Arc,
+            Self::Poly3(_) => GeometryKind::Poly3,
+            Self::ParamPoly3(_) => GeometryKind::ParamPoly3,
+        }
+    }
+}
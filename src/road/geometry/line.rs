@@ -1,15 +1,25 @@
 use std::borrow::Cow;
+use uom::si::f64::Length;
+use uom::si::length::meter;
 
 /// A straight line is the simplest geometry element. It contains no further attributes.
 /// In ASAM OpenDRIVE, a straight line is represented by a `<line>` element within the `<geometry>`
 /// element.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Line {
     // lol
 }
 
 impl Line {
+    /// The position `(u, v)` and heading (in radians) at `ds` along the line, in the local frame
+    /// of the line: it starts at the origin heading along the u-axis, which it never leaves
+    #[inline]
+    pub fn local_pose_at(&self, ds: Length) -> (f64, f64, f64) {
+        (ds.get::<meter>(), 0.0, 0.0)
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
@@ -1,5 +1,6 @@
 use crate::core::additional_data::AdditionalData;
 use arc::Arc;
+use geometry_table::GeometryKind;
 use geometry_type::GeometryType;
 use line::Line;
 use param_poly_3::ParamPoly3;
@@ -7,10 +8,13 @@ use poly_3::Poly3;
 use spiral::Spiral;
 use std::borrow::Cow;
 use uom::si::angle::radian;
-use uom::si::f64::{Angle, Length};
+use uom::si::curvature::radian_per_meter;
+use uom::si::f64::{Angle, Curvature, Length};
 use uom::si::length::meter;
 
 pub mod arc;
+pub mod arc_length_lut;
+pub mod geometry_table;
 pub mod geometry_type;
 pub mod line;
 pub mod param_poly_3;
@@ -20,6 +24,7 @@ pub mod poly_3;
 pub mod spiral;
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Geometry {
     /// Start orientation (inertial heading)
     pub hdg: Angle,
@@ -36,19 +41,108 @@ pub struct Geometry {
 }
 
 impl Geometry {
+    /// [`Geometry::hdg`] in radians
+    #[inline]
+    pub fn hdg_rad(&self) -> f64 {
+        self.hdg.get::<radian>()
+    }
+
+    /// [`Geometry::length`] in meters
+    #[inline]
+    pub fn length_m(&self) -> f64 {
+        self.length.get::<meter>()
+    }
+
+    /// [`Geometry::s`] in meters
+    #[inline]
+    pub fn s_m(&self) -> f64 {
+        self.s.get::<meter>()
+    }
+
+    /// [`Geometry::x`] in meters
+    #[inline]
+    pub fn x_m(&self) -> f64 {
+        self.x.get::<meter>()
+    }
+
+    /// [`Geometry::y`] in meters
+    #[inline]
+    pub fn y_m(&self) -> f64 {
+        self.y.get::<meter>()
+    }
+
+    /// The kind of the geometry element, see [`GeometryType::kind`]
+    #[inline]
+    pub fn kind(&self) -> GeometryKind {
+        self.r#type.kind()
+    }
+
+    /// The inertial position and heading of the reference line at the road s-coordinate `s`,
+    /// with `s` being clamped to `[self.s, self.s + length]`.
+    ///
+    /// The local coordinates of `<poly3>` and `<paramPoly3>` elements are approximated like in
+    /// [`Geometry::curvature_at`].
+    pub fn position_at(&self, s: Length) -> (Length, Length, Angle) {
+        let ds = (s - self.s).max(Length::new::<meter>(0.0)).min(self.length);
+        let (u, v, heading) = self.local_pose_at(ds);
+        let (sin, cos) = self.hdg_rad().sin_cos();
+        (
+            Length::new::<meter>(self.x_m() + u * cos - v * sin),
+            Length::new::<meter>(self.y_m() + u * sin + v * cos),
+            Angle::new::<radian>(self.hdg_rad() + heading),
+        )
+    }
+
+    /// The position `(u, v)` and heading at `ds` in the local frame of this element, which starts
+    /// at the origin heading along the u-axis
+    fn local_pose_at(&self, ds: Length) -> (f64, f64, f64) {
+        match &self.r#type {
+            GeometryType::Line(line) => line.local_pose_at(ds),
+            GeometryType::Arc(arc) => arc.local_pose_at(ds),
+            GeometryType::Spiral(spiral) => spiral.local_pose_at(ds, self.length),
+            GeometryType::Poly3(poly3) => poly3.local_pose_at(ds),
+            GeometryType::ParamPoly3(poly3) => poly3.local_pose_at(ds, self.length),
+        }
+    }
+
+    /// The curvature of the reference line at `ds` from the start of this element, with `ds`
+    /// being clamped to `[0, length]`. Lines have no curvature, arcs a constant curvature and the
+    /// curvature of spirals is interpolated linearly.
+    ///
+    /// The curvature of `<poly3>` and `<paramPoly3>` elements is derived analytically from the
+    /// first and second derivative of the polynoms. Their parameter is approximated locally from
+    /// `ds`: for `<poly3>` u is taken as `ds`, for `<paramPoly3>` p is `ds` (arcLength) or
+    /// `ds / length` (normalized). Use [`ParamPoly3::build_arclength_table`] for exact arc length
+    /// parameterization.
+    pub fn curvature_at(&self, ds: Length) -> Curvature {
+        let ds = ds.max(Length::new::<meter>(0.0)).min(self.length);
+        match &self.r#type {
+            GeometryType::Line(_) => Curvature::new::<radian_per_meter>(0.0),
+            GeometryType::Spiral(spiral) => spiral.curvature_at(ds, self.length),
+            GeometryType::Arc(arc) => arc.curvature,
+            GeometryType::Poly3(poly3) => {
+                Curvature::new::<radian_per_meter>(poly3.curvature(ds.get::<meter>()))
+            }
+            GeometryType::ParamPoly3(poly3) => {
+                let p = poly3.p_at(ds, self.length);
+                Curvature::new::<radian_per_meter>(poly3.curvature(p))
+            }
+        }
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
         ) -> xml::writer::Result<()>,
     ) -> xml::writer::Result<()> {
-        visit_attributes!(
+        visit_scientific_attributes!(
             visitor,
-            "hdg" => &self.hdg.value.to_scientific_string(),
-            "length" => &self.length.value.to_scientific_string(),
-            "s" => &self.s.value.to_scientific_string(),
-            "x" => &self.x.value.to_scientific_string(),
-            "y" => &self.y.value.to_scientific_string(),
+            "s" => self.s.value,
+            "x" => self.x.value,
+            "y" => self.y.value,
+            "hdg" => self.hdg.value,
+            "length" => self.length.value,
         )
     }
 
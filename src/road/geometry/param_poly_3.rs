@@ -1,9 +1,13 @@
+use crate::road::geometry::arc_length_lut::ArcLengthLUT;
 use crate::road::geometry::param_poly_3_p_range::ParamPoly3pRange;
 use std::borrow::Cow;
+use uom::si::f64::Length;
+use uom::si::length::meter;
 
 /// In ASAM OpenDRIVE, parametric cubic curves are represented by `<paramPoly3>` elements within the
 /// `<geometry>` element.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ParamPoly3 {
     /// Polynom parameter a for u
     // https://github.com/RReverser/serde-xml-rs/issues/137
@@ -46,22 +50,117 @@ impl ParamPoly3 {
         self.a_v + (self.b_v * p) + (self.c_v * p * p) + (self.d_v * p * p * p)
     }
 
+    /// First derivative of [`ParamPoly3::u`] with respect to p
+    #[inline]
+    pub fn du(&self, p: f64) -> f64 {
+        self.b_u + (2.0 * self.c_u * p) + (3.0 * self.d_u * p * p)
+    }
+
+    /// First derivative of [`ParamPoly3::v`] with respect to p
+    #[inline]
+    pub fn dv(&self, p: f64) -> f64 {
+        self.b_v + (2.0 * self.c_v * p) + (3.0 * self.d_v * p * p)
+    }
+
+    /// Second derivative of [`ParamPoly3::u`] with respect to p
+    #[inline]
+    pub fn ddu(&self, p: f64) -> f64 {
+        (2.0 * self.c_u) + (6.0 * self.d_u * p)
+    }
+
+    /// Second derivative of [`ParamPoly3::v`] with respect to p
+    #[inline]
+    pub fn ddv(&self, p: f64) -> f64 {
+        (2.0 * self.c_v) + (6.0 * self.d_v * p)
+    }
+
+    /// The signed curvature of the curve `(u(p), v(p))` at `p`, in radians per meter
+    #[inline]
+    pub fn curvature(&self, p: f64) -> f64 {
+        let (du, dv) = (self.du(p), self.dv(p));
+        (du * self.ddv(p) - dv * self.ddu(p)) / du.hypot(dv).powi(3)
+    }
+
+    /// The upper bound of the parameter p, which is either 1 for a normalized range or the
+    /// `length` of the `<geometry>` element
+    #[inline]
+    pub fn p_end(&self, length: Length) -> f64 {
+        match self.p_range {
+            ParamPoly3pRange::ArcLength => length.value,
+            ParamPoly3pRange::Normalized => 1.0,
+        }
+    }
+
+    /// Approximates the parameter p at `ds` along an element of the given `length`: p is `ds` for
+    /// [`ParamPoly3pRange::ArcLength`] and `ds / length` for [`ParamPoly3pRange::Normalized`]. Use
+    /// [`ParamPoly3::build_arclength_table`] for exact arc length parameterization.
+    pub fn p_at(&self, ds: Length, length: Length) -> f64 {
+        match self.p_range {
+            ParamPoly3pRange::ArcLength => ds.get::<meter>(),
+            ParamPoly3pRange::Normalized if length.value > 0.0 => (ds / length).value,
+            ParamPoly3pRange::Normalized => 0.0,
+        }
+    }
+
+    /// The position `(u, v)` and heading (in radians) at `ds` along an element of the given
+    /// `length`, in the local frame of the `<geometry>` element. The curve starts at
+    /// `(a_u, a_v)`, which is off the origin if those are not zero, and p is approximated like
+    /// in [`ParamPoly3::p_at`].
+    pub fn local_pose_at(&self, ds: Length, length: Length) -> (f64, f64, f64) {
+        let p = self.p_at(ds, length);
+        (self.u(p), self.v(p), self.dv(p).atan2(self.du(p)))
+    }
+
+    /// Integrates the arc length of the curve over `samples` equidistant intervals of p (using
+    /// Simpson's rule per interval). The `length` of the `<geometry>` element determines the range
+    /// of p for [`ParamPoly3pRange::ArcLength`].
+    pub fn build_arclength_table(&self, length: Length, samples: usize) -> ArcLengthLUT {
+        let samples = samples.max(1);
+        let p_end = self.p_end(length);
+        let speed = |p: f64| self.du(p).hypot(self.dv(p));
+
+        let mut lut = ArcLengthLUT {
+            p: Vec::with_capacity(samples + 1),
+            s: Vec::with_capacity(samples + 1),
+        };
+        lut.p.push(0.0);
+        lut.s.push(0.0);
+
+        let mut s = 0.0;
+        for i in 0..samples {
+            let p0 = p_end * i as f64 / samples as f64;
+            let p1 = p_end * (i + 1) as f64 / samples as f64;
+            s += (p1 - p0) / 6.0 * (speed(p0) + 4.0 * speed((p0 + p1) / 2.0) + speed(p1));
+            lut.p.push(p1);
+            lut.s.push(s);
+        }
+
+        lut
+    }
+
+    /// Evaluates the local (u, v) coordinates at the arc length `s` along the curve, using a
+    /// table created by [`ParamPoly3::build_arclength_table`].
+    pub fn point_at_arclength(&self, lut: &ArcLengthLUT, s: f64) -> (f64, f64) {
+        let p = lut.p_at(s);
+        (self.u(p), self.v(p))
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
         ) -> xml::writer::Result<()>,
     ) -> xml::writer::Result<()> {
-        visit_attributes!(
+        visit_scientific_attributes!(
             visitor,
-            "aU" => &self.a_u.to_scientific_string(),
-            "aV" => &self.a_v.to_scientific_string(),
-            "bU" => &self.b_u.to_scientific_string(),
-            "bV" => &self.b_v.to_scientific_string(),
-            "cU" => &self.c_u.to_scientific_string(),
-            "cV" => &self.c_v.to_scientific_string(),
-            "dU" => &self.d_u.to_scientific_string(),
-            "dV" => &self.d_v.to_scientific_string(),
+            "aU" => self.a_u,
+            "bU" => self.b_u,
+            "cU" => self.c_u,
+            "dU" => self.d_u,
+            "aV" => self.a_v,
+            "bV" => self.b_v,
+            "cV" => self.c_v,
+            "dV" => self.d_v;
             "pRange" => self.p_range.as_str(),
         )
     }
@@ -1,17 +1,213 @@
 use crate::core::additional_data::AdditionalData;
+use crate::road::geometry::geometry_table::GeometryTable;
+use crate::road::geometry::geometry_type::GeometryType;
+use crate::road::geometry::line::Line;
 use crate::road::geometry::Geometry;
 use std::borrow::Cow;
+use uom::si::angle::radian;
+use uom::si::curvature::radian_per_meter;
+use uom::si::f64::{Angle, Length};
+use uom::si::length::meter;
 use vec1::Vec1;
 
 /// Contains geometry elements that define the layout of the road reference line in the x/y-plane
 /// (plan view).
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PlanView {
     pub geometry: Vec1<Geometry>,
     pub additional_data: AdditionalData,
 }
 
 impl PlanView {
+    /// The geometry element the road s-coordinate `s` belongs to, which is the last element
+    /// starting at or before `s` (or the first element, if `s` lies before all of them).
+    pub fn geometry_at(&self, s: Length) -> &Geometry {
+        let index = self.geometry.partition_point(|geometry| geometry.s <= s);
+        &self.geometry[index.saturating_sub(1)]
+    }
+
+    /// The inertial position and heading of the reference line at the road s-coordinate `s`. The
+    /// geometry elements are sorted by s, so the element whose `[s, s + length)` contains `s` is
+    /// found by binary search. The end of the last element still belongs to it, but `None` is
+    /// returned for an `s` before the first element, beyond the last one or in a gap between two
+    /// elements.
+    pub fn pose_at(&self, s: Length) -> Option<(Length, Length, Angle)> {
+        let index = self.geometry.partition_point(|geometry| geometry.s <= s);
+        let geometry = &self.geometry[index.checked_sub(1)?];
+        (s <= geometry.s + geometry.length).then(|| geometry.position_at(s))
+    }
+
+    /// The geometry elements as columns of SI values, for numeric consumers
+    pub fn as_geometry_table(&self) -> GeometryTable {
+        GeometryTable::from(self)
+    }
+
+    /// Approximates the reference line by a chain of `<line>` elements, each replacing a part of
+    /// at most `max_segment_len` of the curve. Each geometry element is split into parts of equal
+    /// length, whose start and end points on the curve are joined by a line, so each line ends
+    /// exactly where the next one starts. A `max_segment_len` that is not positive replaces each
+    /// element by a single line.
+    ///
+    /// The lines are as long as the chords of the parts they replace and are placed one after
+    /// another along s. On curved elements, the s-coordinates therefore drift from the original
+    /// ones by the difference between the arc and the chord length, which is about
+    /// `l³ * k² / 24` for a part of length `l` and curvature `k`.
+    pub fn to_lines(&self, max_segment_len: Length) -> PlanView {
+        let mut lines = Vec::new();
+        let mut s = self.geometry.first().s;
+        for geometry in &self.geometry {
+            let count = if max_segment_len.value > 0.0 {
+                ((geometry.length / max_segment_len).value.ceil() as usize).max(1)
+            } else {
+                1
+            };
+            let length = geometry.length / count as f64;
+            for index in 0..count {
+                let start = geometry.s + length * index as f64;
+                let (x, y, _) = geometry.position_at(start);
+                let (next_x, next_y, _) = geometry.position_at(start + length);
+                let (dx, dy) = ((next_x - x).get::<meter>(), (next_y - y).get::<meter>());
+                let chord = Length::new::<meter>(dx.hypot(dy));
+                lines.push(Geometry {
+                    hdg: Angle::new::<radian>(dy.atan2(dx)),
+                    length: chord,
+                    s,
+                    x,
+                    y,
+                    r#type: GeometryType::Line(Line {}),
+                    additional_data: AdditionalData::default(),
+                });
+                s += chord;
+            }
+        }
+
+        PlanView {
+            geometry: Vec1::try_from_vec(lines).unwrap(),
+            additional_data: self.additional_data.clone(),
+        }
+    }
+
+    /// Road s-coordinates along the reference line: the start and end of each geometry element
+    /// and, except for `<line>` elements, equidistant samples at most `step` apart in between.
+    pub fn sample_s(&self, step: Length) -> Vec<Length> {
+        let mut samples = Vec::new();
+        for geometry in &self.geometry {
+            let count = match geometry.r#type {
+                GeometryType::Line(_) => 1,
+                _ if step.value > 0.0 => ((geometry.length / step).value.ceil() as usize).max(1),
+                _ => 1,
+            };
+            let skip = usize::from(!samples.is_empty());
+            samples.extend(
+                (skip..=count).map(|i| geometry.s + geometry.length * (i as f64 / count as f64)),
+            );
+        }
+        samples
+    }
+
+    /// The number of times a geometry element is bisected at most by
+    /// [`PlanView::sample_s_adaptive`], which bounds it to `2^16` chords per element
+    pub const MAX_SUBDIVISION_DEPTH: u32 = 16;
+
+    /// Road s-coordinates along the reference line, such that the chords between consecutive
+    /// samples deviate at most `max_chord_error` from the curve. Each geometry element is bisected
+    /// until the sagitta `k * l² / 8` of every chord of length `l` stays within the tolerance,
+    /// with `k` being the largest curvature at the ends and the middle of the chord. `<line>`
+    /// elements are therefore never subdivided, while arcs and spirals get denser with increasing
+    /// curvature.
+    pub fn sample_s_adaptive(&self, max_chord_error: Length) -> Vec<Length> {
+        fn subdivide(
+            geometry: &Geometry,
+            start: Length,
+            end: Length,
+            max_chord_error: Length,
+            depth: u32,
+            samples: &mut Vec<Length>,
+        ) {
+            let middle = (start + end) / 2.0;
+            let curvature = [start, middle, end]
+                .into_iter()
+                .map(|ds| geometry.curvature_at(ds).get::<radian_per_meter>().abs())
+                .fold(0.0, f64::max);
+            let length = (end - start).get::<meter>();
+            let sagitta = curvature * length * length / 8.0;
+            if depth < PlanView::MAX_SUBDIVISION_DEPTH && sagitta > max_chord_error.get::<meter>() {
+                subdivide(geometry, start, middle, max_chord_error, depth + 1, samples);
+                subdivide(geometry, middle, end, max_chord_error, depth + 1, samples);
+            } else {
+                samples.push(geometry.s + end);
+            }
+        }
+
+        let mut samples = Vec::new();
+        for geometry in &self.geometry {
+            if samples.is_empty() {
+                samples.push(geometry.s);
+            }
+            let start = Length::new::<meter>(0.0);
+            subdivide(
+                geometry,
+                start,
+                geometry.length,
+                max_chord_error,
+                0,
+                &mut samples,
+            );
+        }
+        samples
+    }
+
+    /// Samples the reference line at the s-coordinates of [`PlanView::sample_s`] and returns the
+    /// road s-coordinate of each sample together with its inertial x, y (in meters) and heading
+    /// (in radians)
+    pub fn sample_with_s(&self, step: Length) -> Vec<(Length, f64, f64, f64)> {
+        self.sample_s(step)
+            .into_iter()
+            .map(|s| {
+                let (x, y, hdg) = self.geometry_at(s).position_at(s);
+                (s, x.get::<meter>(), y.get::<meter>(), hdg.get::<radian>())
+            })
+            .collect()
+    }
+
+    /// Checks that the geometry elements cover the road `[0, road_length]` without gaps or
+    /// overlaps: the first element starts at s=0, each element starts where the previous one ends
+    /// and the last one ends at `road_length`, all within `tolerance`.
+    pub fn validate_coverage(
+        &self,
+        road_length: Length,
+        tolerance: Length,
+    ) -> Result<(), CoverageError> {
+        let first = self.geometry.first();
+        if first.s.abs() > tolerance {
+            return Err(CoverageError::Start {
+                s: first.s.get::<meter>(),
+            });
+        }
+
+        for pair in self.geometry.windows(2) {
+            let end = pair[0].s + pair[0].length;
+            if (pair[1].s - end).abs() > tolerance {
+                return Err(CoverageError::Gap {
+                    end: end.get::<meter>(),
+                    s: pair[1].s.get::<meter>(),
+                });
+            }
+        }
+
+        let last = self.geometry.last();
+        let end = last.s + last.length;
+        if (end - road_length).abs() > tolerance {
+            return Err(CoverageError::Length {
+                end: end.get::<meter>(),
+                road_length: road_length.get::<meter>(),
+            });
+        }
+
+        Ok(())
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
@@ -33,6 +229,16 @@ impl PlanView {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum CoverageError {
+    #[error("The first geometry element starts at s={s} instead of s=0")]
+    Start { s: f64 },
+    #[error("The geometry element at s={s} does not start where the previous one ends at s={end}")]
+    Gap { end: f64, s: f64 },
+    #[error("The geometry elements end at s={end} instead of the road length {road_length}")]
+    Length { end: f64, road_length: f64 },
+}
+
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for PlanView
 where
     I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
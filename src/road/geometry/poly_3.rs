@@ -1,8 +1,11 @@
 use std::borrow::Cow;
+use uom::si::f64::Length;
+use uom::si::length::meter;
 
 /// In ASAM OpenDRIVE, a cubic polynom is represented by a `<poly3>` element within the `<geometry>`
 /// element.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Poly3 {
     /// Polynom parameter a
     // https://github.com/RReverser/serde-xml-rs/issues/137
@@ -23,18 +26,45 @@ impl Poly3 {
         self.a + (self.b * u) + (self.c * u * u) + (self.d * u * u * u)
     }
 
+    /// First derivative of [`Poly3::v`] with respect to u
+    #[inline]
+    pub fn dv(&self, u: f64) -> f64 {
+        self.b + (2.0 * self.c * u) + (3.0 * self.d * u * u)
+    }
+
+    /// Second derivative of [`Poly3::v`] with respect to u
+    #[inline]
+    pub fn ddv(&self, u: f64) -> f64 {
+        (2.0 * self.c) + (6.0 * self.d * u)
+    }
+
+    /// The signed curvature of the curve `v(u)` at `u`, in radians per meter
+    #[inline]
+    pub fn curvature(&self, u: f64) -> f64 {
+        self.ddv(u) / (1.0 + self.dv(u).powi(2)).powf(1.5)
+    }
+
+    /// The position `(u, v)` and heading (in radians) at `ds` along the curve, in the local frame
+    /// of the `<geometry>` element: the curve `v(u)` starts at `u = 0` and its heading is relative
+    /// to the u-axis. The parameter u is approximated by `ds`, which is exact as long as the curve
+    /// barely leaves the u-axis.
+    pub fn local_pose_at(&self, ds: Length) -> (f64, f64, f64) {
+        let u = ds.get::<meter>();
+        (u, self.v(u), self.dv(u).atan())
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
         ) -> xml::writer::Result<()>,
     ) -> xml::writer::Result<()> {
-        visit_attributes!(
+        visit_scientific_attributes!(
             visitor,
-            "a" => &self.a.to_scientific_string(),
-            "b" => &self.b.to_scientific_string(),
-            "c" => &self.c.to_scientific_string(),
-            "d" => &self.d.to_scientific_string(),
+            "a" => self.a,
+            "b" => self.b,
+            "c" => self.c,
+            "d" => self.d,
         )
     }
 
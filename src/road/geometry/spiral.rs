@@ -1,10 +1,13 @@
 use std::borrow::Cow;
+use uom::si::angle::radian;
 use uom::si::curvature::radian_per_meter;
-use uom::si::f64::Curvature;
+use uom::si::f64::{Angle, Curvature, Length};
+use uom::si::length::meter;
 
 /// In ASAM OpenDRIVE, a spiral is represented by a `<spiral>` element within the `<geometry>`
 /// element.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Spiral {
     /// Curvature at the start of the element
     // https://github.com/RReverser/serde-xml-rs/issues/137
@@ -15,6 +18,107 @@ pub struct Spiral {
 }
 
 impl Spiral {
+    /// Spirals with a smaller change of curvature, in radians per square meter, are integrated
+    /// numerically instead of with Fresnel integrals, see [`Spiral::local_pose_at`]
+    pub const MIN_CURVATURE_CHANGE: f64 = 1e-9;
+
+    /// The curvature at `ds` along a spiral of the given `length`, which changes linearly from
+    /// [`Spiral::curvature_start`] to [`Spiral::curvature_end`]
+    pub fn curvature_at(&self, ds: Length, length: Length) -> Curvature {
+        if length.value > 0.0 {
+            self.curvature_start + (self.curvature_end - self.curvature_start) * (ds / length).value
+        } else {
+            self.curvature_start
+        }
+    }
+
+    /// The position `(u, v)` and heading (in radians) at `ds` along a spiral of the given
+    /// `length`, in the local frame of the spiral: it starts at the origin heading along the
+    /// u-axis. As everywhere in ASAM OpenDRIVE, a positive curvature turns to the left (towards
+    /// positive v) and a negative curvature to the right.
+    ///
+    /// The clothoid is evaluated in closed form with Fresnel integrals. Spirals whose curvature
+    /// hardly changes are integrated numerically instead, because the Fresnel integrals lose
+    /// precision for them.
+    pub fn local_pose_at(&self, ds: Length, length: Length) -> (f64, f64, f64) {
+        let ds = ds.get::<meter>();
+        let k0 = self.curvature_start.get::<radian_per_meter>();
+        let dk = if length.value > 0.0 {
+            (self.curvature_end - self.curvature_start).get::<radian_per_meter>()
+                / length.get::<meter>()
+        } else {
+            0.0
+        };
+        let heading = k0 * ds + dk * ds * ds / 2.0;
+
+        let (u, v) = if dk.abs() < Self::MIN_CURVATURE_CHANGE {
+            Self::integrate(k0, dk, ds)
+        } else {
+            // The clothoid is a section of the normalized clothoid, whose curvature is zero at the
+            // origin and changes by dk along its arc length sigma. This section starts at
+            // sigma0 = k0 / dk and is rotated back by the heading of the normalized clothoid there.
+            let scale = (std::f64::consts::PI / dk.abs()).sqrt();
+            let normalized = |sigma: f64| {
+                let (s, c) = fresnel(sigma / scale);
+                (scale * c, dk.signum() * scale * s)
+            };
+            let sigma0 = k0 / dk;
+            let (x0, y0) = normalized(sigma0);
+            let (x1, y1) = normalized(sigma0 + ds);
+            let (sin, cos) = (dk * sigma0 * sigma0 / 2.0).sin_cos();
+            let (dx, dy) = (x1 - x0, y1 - y0);
+            (dx * cos + dy * sin, dy * cos - dx * sin)
+        };
+
+        (u, v, heading)
+    }
+
+    /// The inertial position and heading at `ds` along a spiral of the given `length` that starts
+    /// at `start_pose`, given as position and heading like [`Geometry::position_at`] returns it
+    ///
+    /// [`Geometry::position_at`]: crate::road::geometry::Geometry::position_at
+    pub fn sample(
+        &self,
+        ds: Length,
+        length: Length,
+        start_pose: (Length, Length, Angle),
+    ) -> (Length, Length, Angle) {
+        let (u, v, heading) = self.local_pose_at(ds, length);
+        let (x, y, hdg) = start_pose;
+        let (sin, cos) = hdg.get::<radian>().sin_cos();
+        (
+            x + Length::new::<meter>(u * cos - v * sin),
+            y + Length::new::<meter>(u * sin + v * cos),
+            hdg + Angle::new::<radian>(heading),
+        )
+    }
+
+    /// Integrates the position along the spiral with Simpson's rule, using an even number of
+    /// intervals that are short enough for the heading to change little within each of them
+    fn integrate(k0: f64, dk: f64, ds: f64) -> (f64, f64) {
+        let heading = |s: f64| k0 * s + dk * s * s / 2.0;
+        let max_curvature = k0.abs().max((k0 + dk * ds).abs());
+        let intervals = (ds.abs() / 0.5)
+            .max(ds.abs() * max_curvature / 0.01)
+            .ceil()
+            .max(1.0) as usize
+            * 2;
+        let h = ds / intervals as f64;
+        let (mut u, mut v) = (0.0, 0.0);
+        for i in 0..=intervals {
+            let weight = match i {
+                0 => 1.0,
+                i if i == intervals => 1.0,
+                i if i % 2 == 1 => 4.0,
+                _ => 2.0,
+            };
+            let (sin, cos) = heading(i as f64 * h).sin_cos();
+            u += weight * cos;
+            v += weight * sin;
+        }
+        (u * h / 3.0, v * h / 3.0)
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
@@ -37,6 +141,86 @@ impl Spiral {
     }
 }
 
+/// The normalized Fresnel integrals `(S(x), C(x))`, the integrals of `sin(pi t^2 / 2)` and
+/// `cos(pi t^2 / 2)` from 0 to `x`. Evaluated by their power series for small arguments and by
+/// the continued fraction of the complementary error function otherwise, see Numerical Recipes,
+/// section 6.9.
+fn fresnel(x: f64) -> (f64, f64) {
+    const MAX_ITERATIONS: usize = 200;
+    const SERIES_LIMIT: f64 = 1.5;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    let ax = x.abs();
+    let (s, c) = if ax < 1e-150 {
+        (0.0, ax)
+    } else if ax <= SERIES_LIMIT {
+        // the terms alternate between the series of S and C
+        let factor = FRAC_PI_2 * ax * ax;
+        let (mut sum, mut sum_s, mut sum_c) = (0.0, 0.0, ax);
+        let (mut sign, mut odd, mut term, mut n) = (1.0, true, ax, 3.0);
+        for k in 1..MAX_ITERATIONS {
+            term *= factor / k as f64;
+            sum += sign * term / n;
+            let tolerance = sum.abs() * f64::EPSILON;
+            if odd {
+                sign = -sign;
+                sum_s = sum;
+                sum = sum_c;
+            } else {
+                sum_c = sum;
+                sum = sum_s;
+            }
+            if term < tolerance {
+                break;
+            }
+            odd = !odd;
+            n += 2.0;
+        }
+        (sum_s, sum_c)
+    } else {
+        // complex numbers as (re, im)
+        let mul = |a: (f64, f64), b: (f64, f64)| (a.0 * b.0 - a.1 * b.1, a.0 * b.1 + a.1 * b.0);
+        let div = |a: (f64, f64), b: (f64, f64)| {
+            let norm = b.0 * b.0 + b.1 * b.1;
+            (
+                (a.0 * b.0 + a.1 * b.1) / norm,
+                (a.1 * b.0 - a.0 * b.1) / norm,
+            )
+        };
+
+        let pix2 = PI * ax * ax;
+        let mut b = (1.0, -pix2);
+        let mut cc = (1.0 / f64::MIN_POSITIVE, 0.0);
+        let mut d = div((1.0, 0.0), b);
+        let mut h = d;
+        let mut n = -1.0;
+        for _ in 2..MAX_ITERATIONS {
+            n += 2.0;
+            let a = -n * (n + 1.0);
+            b.0 += 4.0;
+            d = div((1.0, 0.0), (a * d.0 + b.0, a * d.1 + b.1));
+            let a_cc = div((a, 0.0), cc);
+            cc = (b.0 + a_cc.0, b.1 + a_cc.1);
+            let delta = mul(cc, d);
+            h = mul(h, delta);
+            if (delta.0 - 1.0).abs() + delta.1.abs() < f64::EPSILON {
+                break;
+            }
+        }
+        h = mul((ax, -ax), h);
+        let (sin, cos) = (0.5 * pix2).sin_cos();
+        let e = mul((cos, sin), h);
+        let cs = mul((0.5, 0.5), (1.0 - e.0, -e.1));
+        (cs.1, cs.0)
+    };
+
+    if x < 0.0 {
+        (-s, -c)
+    } else {
+        (s, c)
+    }
+}
+
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Spiral
 where
     I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
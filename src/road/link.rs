@@ -5,6 +5,7 @@ use std::borrow::Cow;
 /// Follows the road header if the road is linked to a successor or a predecessor. Isolated roads
 /// may omit this element.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Link {
     pub predecessor: Option<PredecessorSuccessor>,
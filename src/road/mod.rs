@@ -1,25 +1,48 @@
 use crate::core::additional_data::AdditionalData;
+use crate::core::s_range::SRange;
+use crate::core::OpenDrive;
+use crate::junction::junction_id::JunctionId;
+use crate::lane::lane_section::LaneSection;
+use crate::lane::lane_slice::LaneSlice;
+use crate::lane::lane_type::LaneType;
 use crate::lane::lanes::Lanes;
+use crate::lane::material::Material;
+use crate::lane::Lane;
+use crate::object::corner::Corner;
+use crate::object::object_pose::ObjectPose;
 use crate::object::objects::Objects;
+use crate::object::Object;
 use crate::railroad::Railroad;
+use crate::road::element_type::ElementType;
 use crate::road::profile::ElevationProfile;
 use crate::road::road_type::RoadType;
+use crate::road::road_type_e::RoadTypeE;
 use crate::road::surface::Surface;
+use crate::signal::signal_reference::SignalReference;
 use crate::signal::signals::Signals;
+use crate::signal::Signal;
 use geometry::plan_view::PlanView;
 use link::Link;
+use patch::Patch;
 use profile::lateral_profile::LateralProfile;
 use rule::Rule;
 use std::borrow::Cow;
-use uom::si::f64::Length;
+use std::collections::HashMap;
+use uom::si::angle::radian;
+use uom::si::curvature::radian_per_meter;
+use uom::si::f64::{Angle, Curvature, Length};
 use uom::si::length::meter;
+use xml::{EmitterConfig, EventWriter};
 
 #[allow(deprecated)]
 pub mod country_code;
 pub mod crg;
+pub mod crg_mode;
+pub mod direction;
 pub mod element_type;
 pub mod geometry;
 pub mod link;
+pub mod patch;
 pub mod predecessor_successor;
 pub mod profile;
 pub mod road_type;
@@ -40,12 +63,13 @@ pub mod unit;
 /// roads. A new `<road>` element should only start if the properties of the road cannot be
 /// described within the previous `<road>` element or if a junction is required.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Road {
     /// Unique ID within the database. If it represents an integer number, it should comply to
     /// `uint32_t` and stay within the given range.
     pub id: String,
     /// ID of the junction to which the road belongs as a connecting road (= -1 for none)
-    pub junction: String,
+    pub junction: JunctionId,
     /// Total length of the reference line in the xy-plane. Change in length due to elevation is not
     /// considered.
     /// Only positive values are valid.
@@ -58,6 +82,8 @@ pub struct Road {
     pub link: Option<Link>,
     pub r#type: Vec<RoadType>,
     pub plan_view: PlanView,
+    /// `None` if the road has no `<elevationProfile>`. A present but empty `<elevationProfile/>`
+    /// is kept as a profile without elevations and written back as such.
     pub elevation_profile: Option<ElevationProfile>,
     pub lateral_profile: Option<LateralProfile>,
     pub lanes: Lanes,
@@ -69,6 +95,557 @@ pub struct Road {
 }
 
 impl Road {
+    const PROJECTION_SAMPLE_DISTANCE_M: f64 = 1.0;
+    const PROJECTION_TOLERANCE_M: f64 = 1e-9;
+    const PROJECTION_MAX_ITERATIONS: usize = 16;
+
+    /// [`Road::length`] in meters
+    #[inline]
+    pub fn length_m(&self) -> f64 {
+        self.length.get::<meter>()
+    }
+
+    /// Sets the length of the road, which must be positive
+    pub fn set_length(&mut self, length: Length) -> Result<(), RoadError> {
+        Self::check_length(&self.id, length)?;
+        self.length = length;
+        Ok(())
+    }
+
+    /// Checks that the length of the road is positive, as required by the standard
+    #[inline]
+    pub fn validate_length(&self) -> Result<(), RoadError> {
+        Self::check_length(&self.id, self.length)
+    }
+
+    fn check_length(id: &str, length: Length) -> Result<(), RoadError> {
+        if length.get::<meter>() > 0.0 {
+            Ok(())
+        } else {
+            Err(RoadError::NonPositiveLength {
+                road: id.to_string(),
+                length: length.get::<meter>(),
+            })
+        }
+    }
+
+    /// The length of the reference line in 3D, taking the elevation profile into account, while
+    /// [`Road::length`] is the length of its projection onto the x/y-plane. The arc length is
+    /// integrated with Simpson's rule over intervals of at most `step`.
+    pub fn length_3d(&self, step: Length) -> Length {
+        let Some(elevation) = &self.elevation_profile else {
+            return self.length;
+        };
+        let range = SRange::new(Length::new::<meter>(0.0), self.length);
+        let count = if step.value > 0.0 {
+            ((range.length() / step).value.ceil() as usize).max(1)
+        } else {
+            1
+        };
+        let h = range.length() / count as f64;
+        let integrand = |s: Length| (1.0 + elevation.slope_at(s).powi(2)).sqrt();
+
+        (0..count)
+            .map(|i| {
+                let start = range.start + h * i as f64;
+                h * (integrand(start) + 4.0 * integrand(start + h / 2.0) + integrand(start + h))
+                    / 6.0
+            })
+            .fold(Length::new::<meter>(0.0), |sum, length| sum + length)
+    }
+
+    /// The ids of the junctions this road belongs to or is linked to
+    pub fn referenced_junctions(&self) -> impl Iterator<Item = &str> {
+        let links = self
+            .link
+            .iter()
+            .flat_map(|link| link.predecessor.iter().chain(link.successor.iter()))
+            .filter(|link| matches!(link.element_type, Some(ElementType::Junction)))
+            .map(|link| link.element_id.as_str());
+        self.junction.as_junction().into_iter().chain(links)
+    }
+
+    /// The `<type>` element valid at the road s-coordinate `s`, which is the last element starting
+    /// at or before `s`. `None` if there is no such element.
+    pub fn road_type_at(&self, s: Length) -> Option<&RoadType> {
+        let index = self.r#type.partition_point(|road_type| road_type.s <= s);
+        index.checked_sub(1).map(|index| &self.r#type[index])
+    }
+
+    /// The classification of the road at the road s-coordinate `s`, see [`Road::road_type_at`]
+    pub fn road_class_at(&self, s: Length) -> Option<&RoadTypeE> {
+        self.road_type_at(s).map(|road_type| &road_type.r#type)
+    }
+
+    /// The lane sections together with the range of s-coordinates each of them is valid for. A
+    /// lane section ends where the next one starts, the last one at the end of the road.
+    pub fn lane_section_ranges(&self) -> impl Iterator<Item = (SRange, &LaneSection)> {
+        let sections = &self.lanes.lane_section;
+        sections.iter().enumerate().map(move |(index, section)| {
+            let end = sections
+                .get(index + 1)
+                .map(LaneSection::s_length)
+                .unwrap_or(self.length);
+            (SRange::new(section.s_length(), end), section)
+        })
+    }
+
+    /// All lanes of the road keyed by the index of their lane section and their id, for constant
+    /// time lookups while traversing lane links
+    pub fn lane_map(&self) -> HashMap<(usize, i64), &Lane> {
+        self.lanes
+            .lane_section
+            .iter()
+            .enumerate()
+            .flat_map(|(index, section)| section.lanes().map(move |(id, lane)| ((index, id), lane)))
+            .collect()
+    }
+
+    /// The lanes of the lane section valid at the road s-coordinate `s`, from left to right, with
+    /// the t-coordinates of their borders. The center lane is included with zero width at the
+    /// lane offset. Widths are evaluated from the inner lanes outwards, see [`Lane::outer_at`].
+    ///
+    /// [`Lane::outer_at`]: crate::lane::Lane::outer_at
+    pub fn cross_section(&self, s: Length) -> Vec<LaneSlice> {
+        let section = &self.lanes.lane_section[self.lanes.section_index_at(s.get::<meter>())];
+        self.section_cross_section(section, s)
+    }
+
+    /// The inertial position `(x, y, z)`, in meters, and heading of the lateral center of the lane
+    /// `lane_id` of the lane section at `section_idx` at the road s-coordinate `s`. The lane is
+    /// evaluated as in [`Road::cross_section`], `z` is the elevation of the reference line and
+    /// superelevation is not applied. Returns `None` if there is no such lane section or lane, or
+    /// if `s` lies outside the reference line.
+    pub fn lane_center_pose(
+        &self,
+        section_idx: usize,
+        lane_id: i64,
+        s: Length,
+    ) -> Option<(f64, f64, f64, Angle)> {
+        let section = self.lanes.lane_section.get(section_idx)?;
+        let slice = self
+            .section_cross_section(section, s)
+            .into_iter()
+            .find(|slice| slice.lane_id == lane_id)?;
+        self.inertial_at(s, (slice.inner_t + slice.outer_t) / 2.0)
+    }
+
+    /// Quad strips covering the lanes whose type matches `filter`, such as
+    /// [`LaneType::is_drivable`], one per lane and lane section. Each lane section is sampled at
+    /// equidistant s-coordinates at most `step` apart, including its start and end. A `step` that
+    /// is not positive samples only the start and end. See [`Patch::vertices`] for the layout of
+    /// the vertices.
+    pub fn drivable_patches(
+        &self,
+        step: Length,
+        filter: impl Fn(&LaneType) -> bool,
+    ) -> Vec<Patch<'_>> {
+        let mut patches = Vec::new();
+        for (section_index, (range, section)) in self.lane_section_ranges().enumerate() {
+            if range.is_empty() {
+                continue;
+            }
+
+            let count = if step.value > 0.0 {
+                ((range.length() / step).value.ceil() as usize).max(1)
+            } else {
+                1
+            };
+            let mut section_patches = section
+                .lanes()
+                .filter(|(_, lane)| filter(&lane.r#type))
+                .map(|(lane_id, _)| Patch {
+                    road_id: &self.id,
+                    section_index,
+                    lane_id,
+                    vertices: Vec::with_capacity(2 * (count + 1)),
+                })
+                .collect::<Vec<_>>();
+            if section_patches.is_empty() {
+                continue;
+            }
+
+            for index in 0..=count {
+                let s = range.start + range.length() * (index as f64 / count as f64);
+                let slices = self.section_cross_section(section, s);
+                for patch in &mut section_patches {
+                    let Some(slice) = slices.iter().find(|slice| slice.lane_id == patch.lane_id)
+                    else {
+                        continue;
+                    };
+                    for t in [slice.inner_t, slice.outer_t] {
+                        if let Some((x, y, z, _)) = self.inertial_at(s, t) {
+                            patch.vertices.push([x, y, z]);
+                        }
+                    }
+                }
+            }
+            patches.extend(section_patches);
+        }
+        patches
+    }
+
+    /// The pose of the `object` of this road in the inertial system. The heading of the object is
+    /// relative to the road direction, its pitch and roll are relative to the x/y-plane. If
+    /// [`Object::perp_to_road`] is set, pitch and roll are ignored and the object stands
+    /// perpendicular to the road surface instead, whose normal follows from the slope of the
+    /// elevation and the superelevation at the s-coordinate of the object.
+    pub fn object_pose(&self, object: &Object) -> Option<ObjectPose> {
+        let (x, y, z, road_hdg) = self.inertial_at(object.s, object.t)?;
+        let hdg = road_hdg + object.hdg.unwrap_or_default();
+        let (pitch, roll) = if object.perp_to_road.unwrap_or(false) {
+            self.surface_pitch_roll(object.s, hdg - road_hdg)
+        } else {
+            (
+                object.pitch.unwrap_or_default(),
+                object.roll.unwrap_or_default(),
+            )
+        };
+        Some(ObjectPose {
+            x: Length::new::<meter>(x),
+            y: Length::new::<meter>(y),
+            z: Length::new::<meter>(z) + object.z_offset,
+            hdg,
+            pitch,
+            roll,
+        })
+    }
+
+    /// The objects of this road together with their pose in the inertial system, see
+    /// [`Road::object_pose`]
+    pub fn objects_world(&self) -> Vec<(&Object, ObjectPose)> {
+        self.objects
+            .iter()
+            .flat_map(|objects| &objects.object)
+            .filter_map(|object| Some((object, self.object_pose(object)?)))
+            .collect()
+    }
+
+    /// The inertial position of a `corner` of an outline of the `object`. Corners in road
+    /// coordinates lie at the elevation of the reference line raised by their `dz`, local corners
+    /// are transformed by the [`Road::object_pose`] of the object.
+    pub fn corner_world(
+        &self,
+        object: &Object,
+        corner: &Corner,
+    ) -> Option<(Length, Length, Length)> {
+        match corner {
+            Corner::Road(corner) => {
+                let (x, y, z, _) = self.inertial_at(corner.s, corner.t)?;
+                Some((
+                    Length::new::<meter>(x),
+                    Length::new::<meter>(y),
+                    Length::new::<meter>(z) + corner.dz,
+                ))
+            }
+            Corner::Local(corner) => Some(
+                self.object_pose(object)?
+                    .local_to_world(corner.u, corner.v, corner.z),
+            ),
+        }
+    }
+
+    /// The pitch and roll of an object standing perpendicular to the road surface at the road
+    /// s-coordinate `s`, with its heading rotated by `relative_hdg` against the road direction
+    fn surface_pitch_roll(&self, s: Length, relative_hdg: Angle) -> (Angle, Angle) {
+        let slope = self
+            .elevation_profile
+            .as_ref()
+            .map(|profile| profile.slope_at(s))
+            .unwrap_or_default();
+        let superelevation = self
+            .lateral_profile
+            .as_ref()
+            .map(|profile| profile.superelevation_at(s))
+            .unwrap_or_default();
+
+        // normal of the surface spanned by the tangent (1, 0, slope) and the banked lateral
+        // direction (0, cos, sin) in road direction, rotated into the heading of the object
+        let (sin_e, cos_e) = superelevation.get::<radian>().sin_cos();
+        let (nx, ny, nz) = (-slope * cos_e, -sin_e, cos_e);
+        let (sin_h, cos_h) = relative_hdg.get::<radian>().sin_cos();
+        let (nx, ny) = (nx * cos_h + ny * sin_h, ny * cos_h - nx * sin_h);
+        let length = (nx * nx + ny * ny + nz * nz).sqrt();
+
+        (
+            Angle::new::<radian>(nx.atan2(nz)),
+            Angle::new::<radian>((-ny / length).asin()),
+        )
+    }
+
+    /// The inertial position of the road coordinates `(s, t)`: `t` is applied perpendicular to
+    /// the heading of the reference line, positive to the left, and `z` is the elevation of the
+    /// reference line (superelevation is not applied). `None` if `s` lies outside of the plan view.
+    pub fn to_inertial(&self, s: Length, t: Length) -> Option<(Length, Length, Length)> {
+        self.inertial_at(s, t).map(|(x, y, z, _)| {
+            (
+                Length::new::<meter>(x),
+                Length::new::<meter>(y),
+                Length::new::<meter>(z),
+            )
+        })
+    }
+
+    /// The inertial position `(x, y, z)`, in meters, and heading of the road coordinates `(s, t)`.
+    /// `z` is the elevation of the reference line, superelevation is not applied.
+    pub(crate) fn inertial_at(&self, s: Length, t: Length) -> Option<(f64, f64, f64, Angle)> {
+        let t = t.get::<meter>();
+        let (x, y, hdg) = self.plan_view.pose_at(s)?;
+        let (sin, cos) = hdg.get::<radian>().sin_cos();
+        let z = self
+            .elevation_profile
+            .as_ref()
+            .map(|profile| profile.elevation_at(s.get::<meter>()))
+            .unwrap_or_default();
+        Some((
+            x.get::<meter>() - t * sin,
+            y.get::<meter>() + t * cos,
+            z,
+            hdg,
+        ))
+    }
+
+    pub(crate) fn section_cross_section(&self, section: &LaneSection, s: Length) -> Vec<LaneSlice> {
+        let ds = s - section.s_length();
+        let offset = Length::new::<meter>(self.lanes.offset_at(s.get::<meter>()));
+        let center = section.center.lane0();
+
+        let side = |lanes: Vec<(i64, &Lane)>, sign: f64| {
+            let mut inner = Length::default();
+            lanes
+                .into_iter()
+                .map(|(lane_id, lane)| {
+                    let outer = lane.outer_at(inner, ds);
+                    let slice = LaneSlice {
+                        lane_id,
+                        inner_t: offset + inner * sign,
+                        outer_t: offset + outer * sign,
+                        r#type: lane.r#type.clone(),
+                    };
+                    inner = outer;
+                    slice
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut left = section
+            .left
+            .iter()
+            .flat_map(|left| left.lane.iter().map(|l| (l.id, &l.base)))
+            .collect::<Vec<_>>();
+        left.sort_by_key(|(id, _)| *id);
+        let mut right = section
+            .right
+            .iter()
+            .flat_map(|right| right.lane.iter().map(|l| (l.id, &l.base)))
+            .collect::<Vec<_>>();
+        right.sort_by_key(|(id, _)| -id);
+
+        let mut slices = side(left, 1.0);
+        slices.reverse();
+        slices.push(LaneSlice {
+            lane_id: center.id,
+            inner_t: offset,
+            outer_t: offset,
+            r#type: center.base.r#type.clone(),
+        });
+        slices.extend(side(right, -1.0));
+        slices
+    }
+
+    /// Replaces the reference line by `<line>` elements, each replacing at most `max_segment_len`
+    /// of it, for tools that support no other geometries, see [`PlanView::to_lines`]. The road
+    /// length is set to the end of the new reference line, which is shorter than the original one
+    /// on curved elements. Other s-coordinates of the road are kept.
+    pub fn resample_reference_line(&mut self, max_segment_len: Length) {
+        self.plan_view = self.plan_view.to_lines(max_segment_len);
+        let last = self.plan_view.geometry.last();
+        self.length = last.s + last.length;
+    }
+
+    /// The signals of this road paired with their s-coordinate, in ascending order of s, see
+    /// [`Signals::iter_sorted_by_s`]. The lanes each signal applies to are given by its
+    /// [`Signal::validity`].
+    pub fn signals_by_s(&self) -> Vec<(Length, &Signal)> {
+        self.signals
+            .iter()
+            .flat_map(Signals::iter_sorted_by_s)
+            .map(|signal| (signal.s, signal))
+            .collect()
+    }
+
+    /// The signal a `<signalReference>` of this road refers to, which may be placed on any road
+    /// of `drive`. See [`SignalReference::apply`] to view the signal from this road.
+    pub fn resolve_signal_reference<'a>(
+        &'a self,
+        reference: &SignalReference,
+        drive: &'a OpenDrive,
+    ) -> Option<&'a Signal> {
+        std::iter::once(self)
+            .chain(drive.road.iter())
+            .filter_map(|road| road.signals.as_ref())
+            .flat_map(|signals| &signals.signal)
+            .find(|signal| signal.id == reference.id)
+    }
+
+    /// The friction coefficient at the road s-coordinate `s` on the lane `lane_id` of the lane
+    /// section at `section_idx`, which is the first of:
+    /// 1. the friction of the lane's `<material>` valid at `s`
+    /// 2. the friction of the road `<surface>`: ASAM OpenDRIVE gives a road no friction value of
+    ///    its own, its `<CRG>` surfaces with purpose friction refer to external files, which are
+    ///    not read. This step therefore never yields a value and the lane inherits
+    /// 3. [`Material::DEFAULT_FRICTION`]
+    ///
+    /// A lane section or lane that does not exist has no material and inherits as well.
+    pub fn effective_friction(&self, section_idx: usize, lane_id: i64, s: Length) -> f64 {
+        self.lanes
+            .lane_section
+            .get(section_idx)
+            .and_then(|section| {
+                let lane = section.lane(lane_id)?;
+                lane.material_at(s - section.s_length())
+            })
+            .map(|material| material.friction)
+            .unwrap_or(Material::DEFAULT_FRICTION)
+    }
+
+    /// The curvature of the reference line at the road s-coordinate `s`, see
+    /// [`geometry::Geometry::curvature_at`] for how each kind of geometry element is handled.
+    pub fn curvature_at(&self, s: Length) -> Curvature {
+        let geometry = self.plan_view.geometry_at(s);
+        geometry.curvature_at(s - geometry.s)
+    }
+
+    /// Discretizes the reference line into a polyline of inertial `(x, y)` points at the
+    /// s-coordinates of [`PlanView::sample_s_adaptive`], so that no chord deviates more than
+    /// `max_chord_error` from the reference line. A `<line>` element contributes just its start
+    /// and end point.
+    pub fn sample_reference_line(&self, max_chord_error: Length) -> Vec<(Length, Length)> {
+        self.plan_view
+            .sample_s_adaptive(max_chord_error)
+            .into_iter()
+            .map(|s| {
+                let (x, y, _) = self.plan_view.geometry_at(s).position_at(s);
+                (x, y)
+            })
+            .collect()
+    }
+
+    /// Projects the inertial point `(x, y)` onto the reference line and returns the road
+    /// coordinates `(s, t)` of the closest point together with the distance to it, in meters. `t`
+    /// is positive to the left of the reference line. The closest sample of the reference line
+    /// (see [`PlanView::sample_s`]) is refined by Newton iterations.
+    pub fn project_point(&self, x: Length, y: Length) -> (Length, Length, f64) {
+        let tolerance = Length::new::<meter>(Self::PROJECTION_TOLERANCE_M);
+        let (s, t, distance, _) = self.project_with_tolerance(x, y, tolerance);
+        (s, t, distance.get::<meter>())
+    }
+
+    /// Like [`Road::project_point`], with the Newton iterations stopping once the closest point
+    /// is found within `tolerance` along the reference line. Returns `None` if the point lies
+    /// before the start or beyond the end of the reference line by more than `tolerance`, so that
+    /// it has no perpendicular foot on it.
+    pub fn project(
+        &self,
+        x: Length,
+        y: Length,
+        tolerance: Length,
+    ) -> Option<(Length, Length, Length)> {
+        let (s, t, distance, along) = self.project_with_tolerance(x, y, tolerance);
+        (along.abs() <= tolerance).then_some((s, t, distance))
+    }
+
+    /// The road coordinates `(s, t)` of the closest point, the distance to it and the remaining
+    /// offset of the point along the reference line there
+    fn project_with_tolerance(
+        &self,
+        x: Length,
+        y: Length,
+        tolerance: Length,
+    ) -> (Length, Length, Length, Length) {
+        let (px, py) = (x.get::<meter>(), y.get::<meter>());
+        let tolerance = tolerance.get::<meter>();
+        let offset = |s: Length| {
+            let (x, y, hdg) = self.plan_view.geometry_at(s).position_at(s);
+            let (dx, dy) = (px - x.get::<meter>(), py - y.get::<meter>());
+            let (sin, cos) = hdg.get::<radian>().sin_cos();
+            // (along the reference line, perpendicular to the left)
+            (dx * cos + dy * sin, dy * cos - dx * sin)
+        };
+        let distance_2 = |(u, v): (f64, f64)| u * u + v * v;
+
+        let mut s = self
+            .plan_view
+            .sample_s(Length::new::<meter>(Self::PROJECTION_SAMPLE_DISTANCE_M))
+            .into_iter()
+            .map(|s| (s, distance_2(offset(s))))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(s, _)| s)
+            .unwrap_or_default();
+
+        let range = self.reference_line_range();
+        let (mut u, mut v) = offset(s);
+        for _ in 0..Self::PROJECTION_MAX_ITERATIONS {
+            // d/ds of the offset along the reference line is -1 + curvature * v
+            let derivative = self.curvature_at(s).get::<radian_per_meter>() * v - 1.0;
+            if u.abs() < tolerance || derivative >= 0.0 {
+                break;
+            }
+            let next = range.clamp(s - Length::new::<meter>(u / derivative));
+            let (next_u, next_v) = offset(next);
+            if distance_2((next_u, next_v)) > distance_2((u, v)) {
+                break;
+            }
+            (s, u, v) = (next, next_u, next_v);
+        }
+
+        (
+            s,
+            Length::new::<meter>(v),
+            Length::new::<meter>(distance_2((u, v)).sqrt()),
+            Length::new::<meter>(u),
+        )
+    }
+
+    /// The road s-coordinates of the start and end of the reference line
+    fn reference_line_range(&self) -> SRange {
+        let last = self.plan_view.geometry.last();
+        SRange::new(self.plan_view.geometry.first().s, last.s + last.length)
+    }
+
+    /// Writes this road as a standalone `<road>` element, without the XML document declaration
+    /// and the `<OpenDRIVE>` root element.
+    pub fn to_xml_string(&self) -> crate::writer::Result<String> {
+        let mut writer = EmitterConfig::new()
+            .write_document_declaration(false)
+            .create_writer(Vec::new());
+        self.append_to_writer(&mut writer)
+            .map_err(crate::writer::Error::from)
+            .map_err(Box::new)?;
+        String::from_utf8(writer.into_inner())
+            .map_err(crate::writer::Error::from)
+            .map_err(Box::new)
+    }
+
+    pub fn append_to_writer<'b, T: std::io::Write + 'b>(
+        &self,
+        writer: &'b mut EventWriter<T>,
+    ) -> xml::writer::Result<()> {
+        self.visit_attributes(|attributes| {
+            writer.write(xml::writer::XmlEvent::StartElement {
+                name: xml::name::Name::local("road"),
+                attributes,
+                namespace: std::borrow::Cow::Owned(xml::namespace::Namespace::empty()),
+            })
+        })?;
+        self.visit_children(|event| writer.write(event))?;
+        writer.write(xml::writer::XmlEvent::EndElement { name: None })?;
+        writer.inner_mut().flush()?;
+        Ok(())
+    }
+
+    /// Visits the attributes in the order declared by the XSD (`name`, `length`, `id`,
+    /// `junction`, `rule`).
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
@@ -77,10 +654,10 @@ impl Road {
     ) -> xml::writer::Result<()> {
         visit_attributes_flatten!(
             visitor,
+            "name" => self.name.as_deref(),
+            "length" => Some(self.length.value.to_scientific_string()).as_deref(),
             "id" => Some(self.id.as_str()),
             "junction" => Some(self.junction.as_str()),
-            "length" => Some(self.length.value.to_scientific_string()).as_deref(),
-            "name" => self.name.as_deref(),
             "rule" => self.rule.as_ref().map(Rule::as_str),
         )
     }
@@ -131,6 +708,16 @@ impl Road {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum RoadError {
+    #[error("Road `{road}` has the length {length}, which is not positive")]
+    NonPositiveLength { road: String, length: f64 },
+}
+
+impl crate::parser::FromXmlStr for Road {
+    const ELEMENT_NAME: &'static str = "road";
+}
+
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Road
 where
     I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
@@ -138,6 +725,8 @@ where
     type Error = Box<crate::parser::Error>;
 
     fn try_from(mut read: crate::parser::ReadContext<'a, I>) -> Result<Self, Self::Error> {
+        #[cfg(feature = "tracing")]
+        let _span = read.enter_span("id");
         let mut link = None;
         let mut r#type = Vec::new();
         let mut plan_view = None;
@@ -0,0 +1,25 @@
+/// A strip of quads covering a single lane of a lane section, see [`Road::drivable_patches`]
+///
+/// [`Road::drivable_patches`]: crate::road::Road::drivable_patches
+#[derive(Debug, Clone, PartialEq)]
+pub struct Patch<'a> {
+    /// ID of the road the lane belongs to
+    pub road_id: &'a str,
+    /// Index of the lane section within [`Lanes::lane_section`]
+    ///
+    /// [`Lanes::lane_section`]: crate::lane::lanes::Lanes::lane_section
+    pub section_index: usize,
+    /// ID of the lane
+    pub lane_id: i64,
+    /// Inertial `[x, y, z]` positions in meters, as a triangle strip: for each sampled
+    /// s-coordinate the point on the inner border of the lane followed by the point on its outer
+    /// border. Consecutive pairs of these points form the quads of the strip.
+    pub vertices: Vec<[f64; 3]>,
+}
+
+impl Patch<'_> {
+    /// The number of quads of this strip
+    pub fn quad_count(&self) -> usize {
+        (self.vertices.len() / 2).saturating_sub(1)
+    }
+}
@@ -8,6 +8,7 @@ use uom::si::length::meter;
 /// Successors and predecessors can be junctions or roads. For each, different attribute sets shall
 /// be used.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PredecessorSuccessor {
     /// Contact point of link on the linked element
     pub contact_point: Option<ContactPoint>,
@@ -25,6 +26,13 @@ pub struct PredecessorSuccessor {
 }
 
 impl PredecessorSuccessor {
+    /// Whether this link describes a connection within the linked road through a virtual junction,
+    /// meaning `elementS` is used instead of `contactPoint`.
+    #[inline]
+    pub fn is_virtual_connection(&self) -> bool {
+        self.element_s.is_some()
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
@@ -1,9 +1,12 @@
 use std::borrow::Cow;
+use uom::si::f64::Length;
+use uom::si::length::meter;
 
 /// Defines an elevation element at a given position on the reference line. Elements shall be
 /// defined in ascending order along the reference line. The s length does not change with the
 /// elevation.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Elevation {
     /// Polynom parameter a, elevation at @s (ds=0)
     pub a: f64,
@@ -18,19 +21,37 @@ pub struct Elevation {
 }
 
 impl Elevation {
+    /// [`Elevation::s`] as [`Length`], the value is stored in meters
+    #[inline]
+    pub fn s_length(&self) -> Length {
+        Length::new::<meter>(self.s)
+    }
+
+    /// The elevation at the road s-coordinate `s`
+    pub fn elevation_at(&self, s: Length) -> Length {
+        let ds = s.get::<meter>() - self.s;
+        Length::new::<meter>(self.a + ds * (self.b + ds * (self.c + ds * self.d)))
+    }
+
+    /// The slope, that is the derivative of the elevation along s, at the road s-coordinate `s`
+    pub fn slope_at(&self, s: Length) -> f64 {
+        let ds = s.get::<meter>() - self.s;
+        self.b + ds * (2.0 * self.c + ds * 3.0 * self.d)
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
         ) -> xml::writer::Result<()>,
     ) -> xml::writer::Result<()> {
-        visit_attributes!(
+        visit_scientific_attributes!(
             visitor,
-            "a" => &self.a.to_scientific_string(),
-            "b" => &self.b.to_scientific_string(),
-            "c" => &self.c.to_scientific_string(),
-            "d" => &self.d.to_scientific_string(),
-            "s" => &self.s.to_scientific_string(),
+            "s" => self.s,
+            "a" => self.a,
+            "b" => self.b,
+            "c" => self.c,
+            "d" => self.d,
         )
     }
 
@@ -2,10 +2,12 @@ use crate::core::additional_data::AdditionalData;
 use crate::road::profile::shape::Shape;
 use crate::road::profile::super_elevation::SuperElevation;
 use std::borrow::Cow;
+use uom::si::f64::{Angle, Length};
 
 /// Contains a series of superelevation elements that define the characteristics of the road
 /// surface's banking along the reference line.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct LateralProfile {
     pub super_elevation: Vec<SuperElevation>,
@@ -14,6 +16,23 @@ pub struct LateralProfile {
 }
 
 impl LateralProfile {
+    /// The `<superelevation>` element valid at the road s-coordinate `s`, which is the last
+    /// element starting at or before `s` (or the first element, if `s` lies before all of them)
+    pub fn super_elevation_record_at(&self, s: Length) -> Option<&SuperElevation> {
+        let index = self
+            .super_elevation
+            .partition_point(|super_elevation| super_elevation.s_length() <= s);
+        self.super_elevation.get(index.saturating_sub(1))
+    }
+
+    /// The roll angle of the road cross section at the road s-coordinate `s`, zero without any
+    /// `<superelevation>` element
+    pub fn superelevation_at(&self, s: Length) -> Angle {
+        self.super_elevation_record_at(s)
+            .map(|super_elevation| super_elevation.superelevation_at(s))
+            .unwrap_or_default()
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
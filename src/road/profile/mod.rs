@@ -1,6 +1,8 @@
 use crate::core::additional_data::AdditionalData;
 use elevation::Elevation;
 use std::borrow::Cow;
+use uom::si::f64::Length;
+use uom::si::length::meter;
 
 pub mod elevation;
 pub mod lateral_profile;
@@ -9,6 +11,7 @@ pub mod super_elevation;
 
 /// Defines the characteristics of the road elevation along the reference line.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct ElevationProfile {
     pub elevation: Vec<Elevation>,
@@ -16,6 +19,34 @@ pub struct ElevationProfile {
 }
 
 impl ElevationProfile {
+    /// The `<elevation>` element valid at the road s-coordinate `s`, which is the last element
+    /// starting at or before `s` (or the first element, if `s` lies before all of them)
+    pub fn elevation_record_at(&self, s: Length) -> Option<&Elevation> {
+        let index = self
+            .elevation
+            .partition_point(|elevation| elevation.s_length() <= s);
+        self.elevation.get(index.saturating_sub(1))
+    }
+
+    /// The elevation of the reference line at the road s-coordinate `s`, both in meters. The cubic
+    /// polynom of the last `<elevation>` element starting at or before `s` is evaluated, so an `s`
+    /// on the boundary of two elements is evaluated by the latter one. Zero without any
+    /// `<elevation>` element.
+    pub fn elevation_at(&self, s: f64) -> f64 {
+        let s = Length::new::<meter>(s);
+        self.elevation_record_at(s)
+            .map(|elevation| elevation.elevation_at(s).get::<meter>())
+            .unwrap_or_default()
+    }
+
+    /// The slope of the reference line at the road s-coordinate `s`, zero without any
+    /// `<elevation>` element
+    pub fn slope_at(&self, s: Length) -> f64 {
+        self.elevation_record_at(s)
+            .map(|elevation| elevation.slope_at(s))
+            .unwrap_or_default()
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
@@ -1,9 +1,12 @@
 use std::borrow::Cow;
+use uom::si::f64::Length;
+use uom::si::length::meter;
 
 /// Defined as the road section’s surface relative to the reference plane. There may be several
 /// shape definitions at one s-position that have different t-values, thereby describing the curvy
 /// shape of the road.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Shape {
     /// Polynom parameter a, relative height at @t (dt=0)
     pub a: f64,
@@ -20,20 +23,32 @@ pub struct Shape {
 }
 
 impl Shape {
+    /// [`Shape::s`] as [`Length`], the value is stored in meters
+    #[inline]
+    pub fn s_length(&self) -> Length {
+        Length::new::<meter>(self.s)
+    }
+
+    /// [`Shape::t`] as [`Length`], the value is stored in meters
+    #[inline]
+    pub fn t_length(&self) -> Length {
+        Length::new::<meter>(self.t)
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
         ) -> xml::writer::Result<()>,
     ) -> xml::writer::Result<()> {
-        visit_attributes!(
+        visit_scientific_attributes!(
             visitor,
-            "a" => &self.a.to_scientific_string(),
-            "b" => &self.b.to_scientific_string(),
-            "c" => &self.c.to_scientific_string(),
-            "d" => &self.d.to_scientific_string(),
-            "s" => &self.s.to_scientific_string(),
-            "t" => &self.t.to_scientific_string(),
+            "s" => self.s,
+            "t" => self.t,
+            "a" => self.a,
+            "b" => self.b,
+            "c" => self.c,
+            "d" => self.d,
         )
     }
 
@@ -1,10 +1,14 @@
 use std::borrow::Cow;
+use uom::si::angle::radian;
+use uom::si::f64::{Angle, Length};
+use uom::si::length::meter;
 
 /// Defined as the road section’s roll angle around the s-axis. Elements must be defined in
 /// ascending order along the reference line. The parameters of an element are valid until the next
 /// element starts or the road reference line ends. Per default, the superelevation of a road is
 /// zero.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SuperElevation {
     /// Polynom parameter a, superelevation at @s (ds=0)
     pub a: f64,
@@ -19,19 +23,31 @@ pub struct SuperElevation {
 }
 
 impl SuperElevation {
+    /// [`SuperElevation::s`] as [`Length`], the value is stored in meters
+    #[inline]
+    pub fn s_length(&self) -> Length {
+        Length::new::<meter>(self.s)
+    }
+
+    /// The roll angle of the road cross section at the road s-coordinate `s`
+    pub fn superelevation_at(&self, s: Length) -> Angle {
+        let ds = s.get::<meter>() - self.s;
+        Angle::new::<radian>(self.a + ds * (self.b + ds * (self.c + ds * self.d)))
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
             Cow<'b, [xml::attribute::Attribute<'b>]>,
         ) -> xml::writer::Result<()>,
     ) -> xml::writer::Result<()> {
-        visit_attributes!(
+        visit_scientific_attributes!(
             visitor,
-            "a" => &self.a.to_scientific_string(),
-            "b" => &self.b.to_scientific_string(),
-            "c" => &self.c.to_scientific_string(),
-            "d" => &self.d.to_scientific_string(),
-            "s" => &self.s.to_scientific_string(),
+            "s" => self.s,
+            "a" => self.a,
+            "b" => self.b,
+            "c" => self.c,
+            "d" => self.d,
         )
     }
 
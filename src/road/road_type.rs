@@ -9,6 +9,7 @@ use uom::si::length::meter;
 /// A road type element is valid for the entire cross section of a road. It is valid until a new
 /// road type element is provided or until the road ends.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RoadType {
     pub speed: Option<Speed>,
     /// Country code of the road, see ISO 3166-1, alpha-2 codes.
@@ -3,6 +3,7 @@ use std::borrow::Cow;
 
 /// Defines the default maximum speed allowed in conjunction with the specified road type.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Speed {
     /// Maximum allowed speed. Given as string (only "no limit" / "undefined") or numerical value in
@@ -51,6 +52,7 @@ where
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MaxSpeed {
     Limit(f64),
     NoLimit,
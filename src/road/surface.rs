@@ -1,10 +1,12 @@
 use crate::core::additional_data::AdditionalData;
 use crate::road::crg::Crg;
 use std::borrow::Cow;
+use uom::si::f64::Length;
 
 /// In ASAM OpenDRIVE, the road surface is represented by the `<surface>` element within the
 /// `<road>` element.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Surface {
     pub crg: Vec<Crg>,
@@ -12,6 +14,11 @@ pub struct Surface {
 }
 
 impl Surface {
+    /// The first `<CRG>` surface whose range `[sStart, sEnd)` contains the s-coordinate `s`
+    pub fn crg_at(&self, s: Length) -> Option<&Crg> {
+        self.crg.iter().find(|crg| crg.s_range().contains(s))
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
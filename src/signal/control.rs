@@ -2,6 +2,7 @@ use std::borrow::Cow;
 
 /// Provides information about a single signal controlled by the corresponding controller.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Control {
     /// ID of the controlled signal
@@ -7,6 +7,7 @@ use vec1::Vec1;
 /// wrappers for the behaviour of a group of signals. Controllers are used for dynamic speed control
 /// on motorways, and to control traffic light switching phases.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Controller {
     pub control: Vec1<Control>,
     /// Unique ID within database
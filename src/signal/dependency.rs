@@ -3,6 +3,7 @@ use std::borrow::Cow;
 /// Signal dependency means that one signal controls the output of another signal. A signal may have
 /// multiple dependency elements.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Dependency {
     /// ID of the controlling signal
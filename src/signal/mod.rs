@@ -24,6 +24,7 @@ pub mod signals;
 /// Used to provide information about signals along a road. Consists of a main element and an
 /// optional lane validity element. The element for a signal is `<signal>`.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Signal {
     pub validity: Vec<LaneValidity>,
     pub dependency: Vec<Dependency>,
@@ -77,6 +78,24 @@ pub struct Signal {
 }
 
 impl Signal {
+    /// [`Signal::s`] in meters
+    #[inline]
+    pub fn s_m(&self) -> f64 {
+        self.s.get::<meter>()
+    }
+
+    /// [`Signal::t`] in meters
+    #[inline]
+    pub fn t_m(&self) -> f64 {
+        self.t.get::<meter>()
+    }
+
+    /// [`Signal::z_offset`] in meters
+    #[inline]
+    pub fn z_offset_m(&self) -> f64 {
+        self.z_offset.get::<meter>()
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
@@ -133,6 +152,10 @@ impl Signal {
     }
 }
 
+impl crate::parser::FromXmlStr for Signal {
+    const ELEMENT_NAME: &'static str = "signal";
+}
+
 impl<'a, I> TryFrom<crate::parser::ReadContext<'a, I>> for Signal
 where
     I: Iterator<Item = xml::reader::Result<xml::reader::XmlEvent>>,
@@ -6,6 +6,7 @@ use uom::si::length::meter;
 /// Describes the reference point of the physical position in inertial coordinates in cases where it
 /// deviates from the logical position. Defines the inertial position.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PositionInertial {
     /// Heading of the signal, relative to the inertial system
     pub hdg: Angle,
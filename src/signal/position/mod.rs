@@ -5,6 +5,7 @@ pub mod inertial;
 pub mod road;
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub enum Position {
     Inertial(PositionInertial),
@@ -6,6 +6,7 @@ use uom::si::length::meter;
 /// Describes the reference point of the physical position road coordinates in cases where it
 /// deviates from the logical position. Defines the position on the road.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PositionRoad {
     /// Heading offset of the signal (relative to @orientation)
     pub h_offset: Angle,
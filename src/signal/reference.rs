@@ -3,6 +3,7 @@ use std::borrow::Cow;
 /// Provides a means to link a signal to a series of other elements (for example, objects and
 /// signals).
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Reference {
     /// Unique ID of the linked element
@@ -54,6 +55,7 @@ where
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub enum ElementType {
     Object,
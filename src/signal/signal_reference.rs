@@ -1,6 +1,7 @@
 use crate::core::additional_data::AdditionalData;
 use crate::object::lane_validity::LaneValidity;
 use crate::object::orientation::Orientation;
+use crate::signal::Signal;
 use std::borrow::Cow;
 use uom::si::f64::Length;
 use uom::si::length::meter;
@@ -9,6 +10,7 @@ use uom::si::length::meter;
 /// require a unique ID. The `<signalReference>` element consists of a main element and an optional
 /// lane validity element.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SignalReference {
     pub validity: Vec<LaneValidity>,
     /// Unique ID of the referenced signal within the database
@@ -25,6 +27,18 @@ pub struct SignalReference {
 }
 
 impl SignalReference {
+    /// The referenced `signal` as seen from the road of this reference: its position, orientation
+    /// and lane validity are replaced by those of this reference
+    pub fn apply(&self, signal: &Signal) -> Signal {
+        Signal {
+            validity: self.validity.clone(),
+            orientation: self.orientation.clone(),
+            s: self.s,
+            t: self.t,
+            ..signal.clone()
+        }
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
@@ -5,6 +5,7 @@ use std::borrow::Cow;
 
 /// The `<signals>` element is the container for all signals along a road.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Signals {
     pub signal: Vec<Signal>,
@@ -13,6 +14,14 @@ pub struct Signals {
 }
 
 impl Signals {
+    /// Iterates the signals in ascending order of their s-coordinate without reordering the
+    /// underlying `Vec`. Signals with equal s-coordinates keep their document order.
+    pub fn iter_sorted_by_s(&self) -> impl Iterator<Item = &Signal> {
+        let mut signals = self.signal.iter().collect::<Vec<_>>();
+        signals.sort_by(|a, b| a.s.value.total_cmp(&b.s.value));
+        signals.into_iter()
+    }
+
     pub fn visit_attributes(
         &self,
         visitor: impl for<'b> FnOnce(
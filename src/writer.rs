@@ -1,11 +1,202 @@
+use crate::core::header::Header;
+use crate::junction::junction_group::JunctionGroup;
+use crate::junction::Junction;
+use crate::railroad::station::Station;
+use crate::road::Road;
+use crate::signal::controller::Controller;
+use std::borrow::Cow;
+use xml::EventWriter;
+
 pub type Result<T> = std::result::Result<T, Box<Error>>;
 
+/// The ways writing a document can fail. Writing never panics, every failure is returned as one
+/// of these errors.
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
+    /// The output of [`OpenDrive::to_xml_string`] is not valid UTF-8. The writer only emits
+    /// UTF-8, so this indicates a bug rather than invalid input.
+    ///
+    /// [`OpenDrive::to_xml_string`]: crate::core::OpenDrive::to_xml_string
     #[error("The written output is no valid UTF8-String: {0}")]
     FromUtf8Error(#[from] std::string::FromUtf8Error),
+    /// The XML emitter rejected an event, for example because of an invalid element or attribute
+    /// name, or failed to write to the underlying writer. IO errors of the underlying writer are
+    /// usually reported as this variant by the emitter.
     #[error("The xml write process failed because of an internal error: {0}")]
     XmlError(#[from] xml::writer::Error),
+    /// Writing to or flushing the underlying writer failed
     #[error("The xml write process failed because of an io-error: {0}")]
     IoError(#[from] std::io::Error),
 }
+
+/// Options to tweak the output of the writer.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct WriteOptions {
+    /// Declares the `xsi` namespace on the `<OpenDRIVE>` root element and points its
+    /// `xsi:noNamespaceSchemaLocation` attribute to this location of the XSD, as some validators
+    /// require
+    pub schema_location: Option<String>,
+    /// Omits junctions from a filtered export whose connections only reference roads that are not
+    /// exported and that are not referenced by any exported road, see
+    /// [`OpenDrive::write_filtered`]
+    ///
+    /// [`OpenDrive::write_filtered`]: crate::core::OpenDrive::write_filtered
+    pub prune_junctions: bool,
+}
+
+impl WriteOptions {
+    pub const XSI_NAMESPACE: &'static str = "http://www.w3.org/2001/XMLSchema-instance";
+
+    #[inline]
+    pub fn with_schema_location(mut self, schema_location: Option<String>) -> Self {
+        self.schema_location = schema_location;
+        self
+    }
+
+    #[inline]
+    pub fn with_prune_junctions(mut self, prune_junctions: bool) -> Self {
+        self.prune_junctions = prune_junctions;
+        self
+    }
+
+    /// The namespace declarations of the `<OpenDRIVE>` root element, with `default` as the
+    /// default namespace of the document
+    pub(crate) fn root_namespace(&self, default: Option<&str>) -> xml::namespace::Namespace {
+        let mut namespace = xml::namespace::Namespace::empty();
+        if let Some(default) = default {
+            namespace.put(xml::namespace::NS_NO_PREFIX, default);
+        }
+        if self.schema_location.is_some() {
+            namespace.put("xsi", Self::XSI_NAMESPACE);
+        }
+        namespace
+    }
+
+    /// Extends the attributes of the `<OpenDRIVE>` root element
+    pub(crate) fn root_attributes<'a>(
+        &'a self,
+        attributes: &'a [xml::attribute::Attribute<'a>],
+    ) -> Cow<'a, [xml::attribute::Attribute<'a>]> {
+        match &self.schema_location {
+            None => Cow::Borrowed(attributes),
+            Some(location) => {
+                let mut attributes = attributes.to_vec();
+                attributes.push(xml::attribute::Attribute::new(
+                    xml::name::Name::prefixed("noNamespaceSchemaLocation", "xsi"),
+                    location,
+                ));
+                Cow::Owned(attributes)
+            }
+        }
+    }
+}
+
+/// Writes an `<OpenDRIVE>` document element by element, without building the whole
+/// [`OpenDrive`] tree in memory. The elements should be written in the order of the schema: the
+/// header first, followed by the roads, controllers, junctions, junction groups and stations.
+///
+/// ```
+/// # use opendrive::writer::OpenDriveStreamWriter;
+/// # fn write(header: &opendrive::core::header::Header, roads: impl Iterator<Item = opendrive::road::Road>) -> opendrive::writer::Result<Vec<u8>> {
+/// let mut writer = OpenDriveStreamWriter::new(Vec::new())?;
+/// writer.write_header(header)?;
+/// for road in roads {
+///     writer.write_road(&road)?;
+/// }
+/// writer.finish()
+/// # }
+/// ```
+///
+/// [`OpenDrive`]: crate::core::OpenDrive
+pub struct OpenDriveStreamWriter<W: std::io::Write> {
+    writer: EventWriter<W>,
+}
+
+impl<W: std::io::Write> OpenDriveStreamWriter<W> {
+    /// Writes the XML document declaration and opens the `<OpenDRIVE>` root element
+    pub fn new(w: W) -> Result<Self> {
+        Self::from_writer(EventWriter::new(w))
+    }
+
+    /// Opens the `<OpenDRIVE>` root element on the given writer, which is expected to be at the
+    /// start of the document
+    pub fn from_writer(writer: EventWriter<W>) -> Result<Self> {
+        let mut this = Self { writer };
+        this.write(|visitor| {
+            visitor(xml::writer::XmlEvent::StartDocument {
+                version: xml::common::XmlVersion::Version10,
+                encoding: None,
+                standalone: Some(true),
+            })?;
+            visitor(xml::writer::XmlEvent::StartElement {
+                name: xml::name::Name::local("OpenDRIVE"),
+                attributes: Cow::Borrowed(&[]),
+                namespace: Cow::Owned(xml::namespace::Namespace::empty()),
+            })
+        })?;
+        Ok(this)
+    }
+
+    pub fn write_header(&mut self, header: &Header) -> Result<()> {
+        self.write(|mut visitor| {
+            visit_children!(visitor, "header" => header);
+            Ok(())
+        })
+    }
+
+    pub fn write_road(&mut self, road: &Road) -> Result<()> {
+        self.write(|mut visitor| {
+            visit_children!(visitor, "road" => road);
+            Ok(())
+        })
+    }
+
+    pub fn write_controller(&mut self, controller: &Controller) -> Result<()> {
+        self.write(|mut visitor| {
+            visit_children!(visitor, "controller" => controller);
+            Ok(())
+        })
+    }
+
+    pub fn write_junction(&mut self, junction: &Junction) -> Result<()> {
+        self.write(|mut visitor| {
+            visit_children!(visitor, "junction" => junction);
+            Ok(())
+        })
+    }
+
+    pub fn write_junction_group(&mut self, junction_group: &JunctionGroup) -> Result<()> {
+        self.write(|mut visitor| {
+            visit_children!(visitor, "junctionGroup" => junction_group);
+            Ok(())
+        })
+    }
+
+    pub fn write_station(&mut self, station: &Station) -> Result<()> {
+        self.write(|mut visitor| {
+            visit_children!(visitor, "station" => station);
+            Ok(())
+        })
+    }
+
+    /// Closes the `<OpenDRIVE>` root element and returns the underlying writer after it has been
+    /// flushed
+    pub fn finish(mut self) -> Result<W> {
+        self.write(|visitor| visitor(xml::writer::XmlEvent::EndElement { name: None }))?;
+        let mut w = self.writer.into_inner();
+        w.flush().map_err(Error::from).map_err(Box::new)?;
+        Ok(w)
+    }
+
+    fn write(
+        &mut self,
+        f: impl FnOnce(
+            &mut dyn FnMut(xml::writer::XmlEvent) -> xml::writer::Result<()>,
+        ) -> xml::writer::Result<()>,
+    ) -> Result<()> {
+        let writer = &mut self.writer;
+        f(&mut |event| writer.write(event))
+            .map_err(Error::from)
+            .map_err(Box::new)
+    }
+}
@@ -0,0 +1,118 @@
+//! Counts the allocations of writing attributes. This is an integration test of its own, so that
+//! the counting allocator is only installed for this test binary.
+
+use opendrive::core::OpenDrive;
+use opendrive::parser::ToScientificString;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn count_allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(|count| count.get());
+    f();
+    ALLOCATIONS.with(|count| count.get()) - before
+}
+
+/// A document with a single road of `count` geometry elements of length 1
+fn document(count: usize) -> String {
+    let geometries = (0..count)
+        .map(|i| {
+            format!(
+                r#"<geometry s="{s}" x="{s}" y="1.5" hdg="0.25" length="1.0e+00"><line/></geometry>"#,
+                s = i as f64
+            )
+        })
+        .collect::<String>();
+    format!(
+        r#"<?xml version="1.0" standalone="yes"?>
+        <OpenDRIVE>
+            <header revMajor="1" revMinor="7"/>
+            <road length="{count}" id="1" junction="-1">
+                <planView>{geometries}</planView>
+                <lanes>
+                    <laneSection s="0.0e+00">
+                        <center><lane id="0" type="none"/></center>
+                    </laneSection>
+                </lanes>
+            </road>
+        </OpenDRIVE>"#
+    )
+}
+
+#[test]
+pub fn test_scientific_attributes_reuse_buffer() {
+    let drive = OpenDrive::from_xml_str(&document(1000)).unwrap();
+    let geometry = &drive.road[0].plan_view.geometry;
+    assert_eq!(1000, geometry.len());
+
+    let mut written = String::new();
+    let mut visit_all = || {
+        for g in geometry.iter() {
+            g.visit_attributes(|attributes| {
+                for attribute in attributes.iter() {
+                    written.clear();
+                    written.push_str(attribute.value);
+                }
+                Ok(())
+            })
+            .unwrap();
+        }
+    };
+    // warm up the scratch buffer
+    visit_all();
+    let reused = count_allocations(&mut visit_all);
+    let allocating = count_allocations(|| {
+        for g in geometry.iter() {
+            for value in [g.s.value, g.x.value, g.y.value, g.hdg.value, g.length.value] {
+                written = value.to_scientific_string();
+            }
+        }
+    });
+    assert_eq!(0, reused);
+    assert_eq!(5 * geometry.len(), allocating);
+    assert_eq!("1.00000000000000000e0", written);
+}
+
+#[test]
+pub fn test_to_xml_string_allocations() {
+    let small = OpenDrive::from_xml_str(&document(1000)).unwrap();
+    let large = OpenDrive::from_xml_str(&document(2000)).unwrap();
+    // warm up the scratch buffer
+    let _ = small.to_xml_string().unwrap();
+
+    let mut output = String::new();
+    let small_allocations = count_allocations(|| output = small.to_xml_string().unwrap());
+    assert!(output.contains(
+        r#"s="9.99000000000000000e2" x="9.99000000000000000e2" y="1.50000000000000000e0" hdg="2.50000000000000000e-1" length="1.00000000000000000e0""#
+    ));
+    assert_eq!(small, OpenDrive::from_xml_str(&output).unwrap());
+
+    // formatting the five numeric attributes of each additional geometry element does not
+    // allocate, the remaining allocations are made by the XML writer. Formatting them one by one
+    // would take five allocations on its own.
+    let large_allocations = count_allocations(|| output = large.to_xml_string().unwrap());
+    let per_geometry = (large_allocations - small_allocations) as f64 / 1000.0;
+    assert!(
+        per_geometry < 5.0,
+        "{per_geometry} allocations per geometry"
+    );
+}